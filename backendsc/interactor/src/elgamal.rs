@@ -0,0 +1,334 @@
+//! ElGamal encryption and the zero-knowledge proofs of encrypted elections, computed exactly as the contract verifies them
+//! (see `voteEncrypted`, `registerTrusteeKey`, `postDecryptionShares` and `publishTally` in the contract).
+
+use num_bigint::{BigUint, RandBigInt};
+use sha2::{Digest, Sha256};
+
+/// The ElGamal group, as `ELGAMAL_PRIME`, `ELGAMAL_ORDER` and `ELGAMAL_GENERATOR` in the contract: a 2048-bit prime `p`
+/// and the generator `g` of its subgroup of 256-bit prime order `q`.
+const PRIME_HEX: &str = concat!(
+    "B67CED99588E71FB10767B93AE2BC07A2680C58E20C61CA33DDE14C74A66C628",
+    "9C9518B8CDD6B9F32A5B5B7389217567F756327080855D92E4E80CF04FD6D14B",
+    "934EEB127F542B0D1F8784FBA500A6B2E57B70DC34F4E088AD01968E46830300",
+    "8E99C423B418B7FF7ADAB0255C42BFFF4A8D137DF40D3739DF7ED15AC524B040",
+    "B50237DFC34977A5F7F1964A03621E5A4F4D8393E6EE303E0AA6F1A0B4A89ABE",
+    "EE1F9A5B3E278E6CFFB581B40CCC48420293AE961DC83D0F20C32D5B1BD6D080",
+    "284F03AB0EA615F3796F6F70E0855BB114B1C29085044B24DFEA137A97318DE3",
+    "C605EE397DF329AACA8F27867069131A99E1C3E6089C825F57861DAB4E08BBEF",
+);
+const ORDER_HEX: &str = "86DE5888E9DE45E620103794CF910E231C854D096297935A4590BC0901E0057B";
+const GENERATOR_HEX: &str = concat!(
+    "2CBA8887F670FCD614841CD6683F84CB6C8326E829435AF1BF70B7531220ED1D",
+    "66B8A1F9AA633CD1BEB062AB23504F92ABC1AB05F68C609F6DDA3F0BA620CE03",
+    "1A6EF36037844E7111B7B5307E9345B666A74ADCA8CD4D4905140867D5505D2B",
+    "5B089A2F6BF0FB8715F2E46D369406414353E75C7CA6175C54332E08BE9A1F67",
+    "F03D4826DA7DD8358BFEC8E3D62678D9B82F270930A91C24DBA922129614CA3E",
+    "A5C503E2C738126282337F623263F2AA68AF74D2C596D109EB88DF1EDEB16E2A",
+    "D8D5AA5DBEE7ABD2D490310908C0F8E83D99469CCB16A1E7AFB5E5F5AD6F2BA8",
+    "630EF3C611053918B9E669EAFBE16D42CB3963315F54E15B610545F7B91A4B5A",
+);
+
+/// Size in bytes of the values hashed by `challenge`.
+const VALUE_SIZE: usize = 256;
+
+pub fn prime() -> BigUint {
+    BigUint::parse_bytes(PRIME_HEX.as_bytes(), 16).unwrap()
+}
+
+/// The order `q` of the subgroup votes are encrypted in.
+pub fn order() -> BigUint {
+    BigUint::parse_bytes(ORDER_HEX.as_bytes(), 16).unwrap()
+}
+
+pub fn generator() -> BigUint {
+    BigUint::parse_bytes(GENERATOR_HEX.as_bytes(), 16).unwrap()
+}
+
+/// A random exponent in `[0, q)`.
+pub fn random_exponent() -> BigUint {
+    rand::thread_rng().gen_biguint_below(&order())
+}
+
+fn inverse(x: &BigUint) -> BigUint {
+    let p = prime();
+    x.modpow(&(&p - 2u32), &p)
+}
+
+/// The Fiat-Shamir challenge of a proof: `sha256(election_id || address || values) mod q`, where the election ID
+/// is 8 bytes big-endian, the address is the prover's and each value is 256 bytes big-endian.
+pub fn challenge(election_id: u64, address: &[u8; 32], values: &[&BigUint]) -> BigUint {
+    let mut hasher = Sha256::new();
+    hasher.update(election_id.to_be_bytes());
+    hasher.update(address);
+    for value in values {
+        let bytes = value.to_bytes_be();
+        hasher.update(vec![0u8; VALUE_SIZE - bytes.len()]);
+        hasher.update(bytes);
+    }
+    BigUint::from_bytes_be(&hasher.finalize()) % order()
+}
+
+/// Checks that a number belongs to the subgroup of order `q`.
+pub fn is_group_element(x: &BigUint) -> bool {
+    let p = prime();
+    *x > BigUint::ZERO && *x < p && x.modpow(&order(), &p) == BigUint::from(1u32)
+}
+
+#[derive(Clone, Debug)]
+pub struct TrusteeKey {
+    // the coefficients `a_0, ..., a_(t-1)` of the secret polynomial of the trustee, `a_0` being their secret key
+    pub coefficients: Vec<BigUint>,
+    pub public_key: BigUint,
+    // Schnorr proof of knowledge of the secret key
+    pub commitment: BigUint,
+    pub response: BigUint,
+    // the commitments `g^a_1, ..., g^a_(t-1)` to the other coefficients
+    pub coefficient_commitments: Vec<BigUint>,
+}
+
+/// Generates a new trustee key for an election whose tally is decrypted by `threshold` trustees:
+/// a random secret polynomial of degree `threshold - 1`.
+pub fn generate_key(election_id: u64, trustee: &[u8; 32], threshold: usize) -> TrusteeKey {
+    let mut secret = random_exponent();
+    while secret == BigUint::ZERO {
+        secret = random_exponent();
+    }
+    let mut coefficients = vec![secret];
+    coefficients.extend((1..threshold).map(|_| random_exponent()));
+    trustee_key(election_id, trustee, coefficients)
+}
+
+/// Computes the public key and coefficient commitments of a secret polynomial and the proof that the trustee knows its secret key.
+pub fn trustee_key(election_id: u64, trustee: &[u8; 32], coefficients: Vec<BigUint>) -> TrusteeKey {
+    let (p, q, g) = (prime(), order(), generator());
+    let public_key = g.modpow(&coefficients[0], &p);
+    let w = random_exponent();
+    let commitment = g.modpow(&w, &p);
+    let c = challenge(election_id, trustee, &[&public_key, &commitment]);
+    let response = (w + c * &coefficients[0]) % q;
+    let coefficient_commitments = coefficients[1..].iter().map(|coefficient| g.modpow(coefficient, &p)).collect();
+    TrusteeKey { coefficients, public_key, commitment, response, coefficient_commitments }
+}
+
+/// The value `f(index) (mod q)` of a secret polynomial, sent privately to the trustee at `index` (their position in `getTrustees`, from 1).
+pub fn key_share(coefficients: &[BigUint], index: u64) -> BigUint {
+    let q = order();
+    coefficients.iter().rev().fold(BigUint::ZERO, |value, coefficient| (value * index + coefficient) % &q)
+}
+
+/// `h * C_1^index * ... * C_(t-1)^(index^(t-1)) (mod p)`: `g^f(index)` for the polynomial of the public key `h` and the commitments `C_k`.
+fn committed_value(public_key: &BigUint, coefficient_commitments: &[BigUint], index: u64) -> BigUint {
+    let (p, q) = (prime(), order());
+    let mut value = public_key.clone();
+    let mut power = BigUint::from(index);
+    for commitment in coefficient_commitments {
+        value = value * commitment.modpow(&power, &p) % &p;
+        power = power * index % &q;
+    }
+    value
+}
+
+/// Checks a key share received from a trustee against their registered public key and coefficient commitments.
+pub fn verify_key_share(public_key: &BigUint, coefficient_commitments: &[BigUint], index: u64, share: &BigUint) -> bool {
+    generator().modpow(share, &prime()) == committed_value(public_key, coefficient_commitments, index)
+}
+
+/// The share of the joint secret key of a trustee: the sum of the key shares they received (their own included).
+pub fn combine_key_shares(shares: &[BigUint]) -> BigUint {
+    let q = order();
+    shares.iter().fold(BigUint::ZERO, |sum, share| (sum + share) % &q)
+}
+
+/// The verification key `g^s_index` of the trustee at `index`, from the public keys and coefficient commitments of all the trustees.
+pub fn verification_key(keys: &[(BigUint, Vec<BigUint>)], index: u64) -> BigUint {
+    let p = prime();
+    keys.iter().fold(BigUint::from(1u32), |key, (public_key, coefficient_commitments)| key * committed_value(public_key, coefficient_commitments, index) % &p)
+}
+
+pub fn verify_trustee_key(election_id: u64, trustee: &[u8; 32], public_key: &BigUint, commitment: &BigUint, response: &BigUint) -> bool {
+    let (p, g) = (prime(), generator());
+    let c = challenge(election_id, trustee, &[public_key, commitment]);
+    is_group_element(public_key) && g.modpow(response, &p) == commitment * public_key.modpow(&c, &p) % &p
+}
+
+/// The joint public key of the trustees: the product of their public keys.
+pub fn joint_public_key(public_keys: &[BigUint]) -> BigUint {
+    let p = prime();
+    public_keys.iter().fold(BigUint::from(1u32), |key, public_key| key * public_key % &p)
+}
+
+#[derive(Clone, Debug)]
+pub struct Ciphertext {
+    pub a: BigUint,
+    pub b: BigUint,
+}
+
+impl Ciphertext {
+    /// The product of two ciphertexts: an encryption of the sum of their values.
+    pub fn multiply(&self, other: &Ciphertext) -> Ciphertext {
+        let p = prime();
+        Ciphertext { a: &self.a * &other.a % &p, b: &self.b * &other.b % &p }
+    }
+}
+
+/// Encrypts `m` with randomness `r`: `(g^r, g^m * h^r)`.
+pub fn encrypt(public_key: &BigUint, m: u64, r: &BigUint) -> Ciphertext {
+    let (p, g) = (prime(), generator());
+    Ciphertext {
+        a: g.modpow(r, &p),
+        b: g.modpow(&BigUint::from(m), &p) * public_key.modpow(r, &p) % &p,
+    }
+}
+
+/// Disjunctive Chaum-Pedersen proof that a ciphertext encrypts 0 or 1.
+#[derive(Clone, Debug)]
+pub struct BallotProof {
+    pub t_a0: BigUint,
+    pub t_b0: BigUint,
+    pub t_a1: BigUint,
+    pub t_b1: BigUint,
+    pub c0: BigUint,
+    pub c1: BigUint,
+    pub s0: BigUint,
+    pub s1: BigUint,
+}
+
+/// Proves that `ciphertext`, encrypting `selected as u64` with randomness `r`, encrypts 0 or 1: the proof for the
+/// encrypted value is real, the proof for the other value is simulated from a random challenge and response.
+pub fn prove_zero_or_one(election_id: u64, voter: &[u8; 32], public_key: &BigUint, ciphertext: &Ciphertext, selected: bool, r: &BigUint) -> BallotProof {
+    let (p, q, g) = (prime(), order(), generator());
+    let (a, b) = (&ciphertext.a, &ciphertext.b);
+    let w = random_exponent();
+    let (simulated_c, simulated_s) = (random_exponent(), random_exponent());
+    // the commitments of the simulated proof are derived from its challenge and response
+    let a_c = a.modpow(&simulated_c, &p);
+    let b_c = b.modpow(&simulated_c, &p);
+
+    let (t_a0, t_b0, t_a1, t_b1) = if selected {
+        (
+            g.modpow(&simulated_s, &p) * inverse(&a_c) % &p,
+            public_key.modpow(&simulated_s, &p) * inverse(&b_c) % &p,
+            g.modpow(&w, &p),
+            public_key.modpow(&w, &p),
+        )
+    } else {
+        let g_c = g.modpow(&simulated_c, &p);
+        (
+            g.modpow(&w, &p),
+            public_key.modpow(&w, &p),
+            g.modpow(&simulated_s, &p) * inverse(&a_c) % &p,
+            public_key.modpow(&simulated_s, &p) * &g_c * inverse(&b_c) % &p,
+        )
+    };
+    let c = challenge(election_id, voter, &[a, b, &t_a0, &t_b0, &t_a1, &t_b1]);
+    let real_c = (&c + &q - &simulated_c) % &q;
+    let real_s = (w + &real_c * r) % &q;
+
+    if selected {
+        BallotProof { t_a0, t_b0, t_a1, t_b1, c0: simulated_c, c1: real_c, s0: simulated_s, s1: real_s }
+    } else {
+        BallotProof { t_a0, t_b0, t_a1, t_b1, c0: real_c, c1: simulated_c, s0: real_s, s1: simulated_s }
+    }
+}
+
+pub fn verify_zero_or_one(election_id: u64, voter: &[u8; 32], public_key: &BigUint, ciphertext: &Ciphertext, proof: &BallotProof) -> bool {
+    let (p, q, g) = (prime(), order(), generator());
+    let (a, b) = (&ciphertext.a, &ciphertext.b);
+    let c = challenge(election_id, voter, &[a, b, &proof.t_a0, &proof.t_b0, &proof.t_a1, &proof.t_b1]);
+    is_group_element(a)
+        && is_group_element(b)
+        && proof.c0 < q
+        && proof.c1 < q
+        && (&proof.c0 + &proof.c1) % &q == c
+        && g.modpow(&proof.s0, &p) == &proof.t_a0 * a.modpow(&proof.c0, &p) % &p
+        && public_key.modpow(&proof.s0, &p) == &proof.t_b0 * b.modpow(&proof.c0, &p) % &p
+        && g.modpow(&proof.s1, &p) == &proof.t_a1 * a.modpow(&proof.c1, &p) % &p
+        && public_key.modpow(&proof.s1, &p) * g.modpow(&proof.c1, &p) % &p == &proof.t_b1 * b.modpow(&proof.c1, &p) % &p
+}
+
+#[derive(Clone, Debug)]
+pub struct EncryptedBallot {
+    // one encrypted choice per candidate, with its proof, in the order of the selections
+    pub choices: Vec<(Ciphertext, BallotProof)>,
+    // proof that the product of the choices encrypts 0 or 1, required by Plurality elections
+    pub sum_proof: Option<BallotProof>,
+}
+
+/// Encrypts a ballot: one encryption of 1 (selected) or 0 (not selected) per candidate.
+/// The proof that at most one candidate is selected is only computed if it holds.
+pub fn encrypt_ballot(election_id: u64, voter: &[u8; 32], public_key: &BigUint, selections: &[bool]) -> EncryptedBallot {
+    let q = order();
+    let mut choices = Vec::new();
+    let mut product = Ciphertext { a: BigUint::from(1u32), b: BigUint::from(1u32) };
+    let mut total_r = BigUint::ZERO;
+    for selected in selections {
+        let r = random_exponent();
+        let ciphertext = encrypt(public_key, *selected as u64, &r);
+        let proof = prove_zero_or_one(election_id, voter, public_key, &ciphertext, *selected, &r);
+        product = product.multiply(&ciphertext);
+        total_r = (total_r + r) % &q;
+        choices.push((ciphertext, proof));
+    }
+
+    let selected_count = selections.iter().filter(|selected| **selected).count();
+    let sum_proof = (selected_count <= 1).then(|| prove_zero_or_one(election_id, voter, public_key, &product, selected_count == 1, &total_r));
+    EncryptedBallot { choices, sum_proof }
+}
+
+/// Decryption share of a trustee for an encrypted tally `(A, B)`: `d = A^s`, where `s` is the trustee's share of the joint
+/// secret key, with a Chaum-Pedersen proof that it uses the same secret as the trustee's verification key `g^s`.
+#[derive(Clone, Debug)]
+pub struct DecryptionShare {
+    pub share: BigUint,
+    pub t_g: BigUint,
+    pub t_a: BigUint,
+    pub response: BigUint,
+}
+
+pub fn decryption_share(election_id: u64, trustee: &[u8; 32], secret: &BigUint, a: &BigUint) -> DecryptionShare {
+    let (p, q, g) = (prime(), order(), generator());
+    let public_key = g.modpow(secret, &p);
+    let share = a.modpow(secret, &p);
+    let w = random_exponent();
+    let t_g = g.modpow(&w, &p);
+    let t_a = a.modpow(&w, &p);
+    let c = challenge(election_id, trustee, &[&public_key, a, &share, &t_g, &t_a]);
+    let response = (w + c * secret) % q;
+    DecryptionShare { share, t_g, t_a, response }
+}
+
+pub fn verify_decryption_share(election_id: u64, trustee: &[u8; 32], public_key: &BigUint, a: &BigUint, share: &DecryptionShare) -> bool {
+    let (p, g) = (prime(), generator());
+    let c = challenge(election_id, trustee, &[public_key, a, &share.share, &share.t_g, &share.t_a]);
+    is_group_element(&share.share)
+        && g.modpow(&share.response, &p) == &share.t_g * public_key.modpow(&c, &p) % &p
+        && a.modpow(&share.response, &p) == &share.t_a * share.share.modpow(&c, &p) % &p
+}
+
+/// The Lagrange coefficient at 0 of the trustee at `index` among the trustees at `indexes`: `prod_(m != index) m / (m - index) (mod q)`.
+pub fn lagrange_coefficient(indexes: &[u64], index: u64) -> BigUint {
+    let q = order();
+    let (numerator, denominator) = indexes.iter().filter(|m| **m != index).fold((BigUint::from(1u32), BigUint::from(1u32)), |(numerator, denominator), m| {
+        let difference = if *m > index { BigUint::from(m - index) } else { &q - (index - m) };
+        (numerator * m % &q, denominator * difference % &q)
+    });
+    numerator * denominator.modpow(&(&q - 2u32), &q) % &q
+}
+
+/// Decrypts the count of an encrypted tally `(A, B)` from the decryption shares `d_j` of at least `threshold` trustees,
+/// given with their positions `j`: `g^count = B / prod_j d_j^l_j` (see `lagrange_coefficient`), solved by trying
+/// every count up to `max_count` (the turnout).
+pub fn decrypt_count(b: &BigUint, shares: &[(u64, BigUint)], max_count: u64) -> Option<u64> {
+    let (p, g) = (prime(), generator());
+    let indexes: Vec<u64> = shares.iter().map(|(index, _)| *index).collect();
+    let shares_product = shares.iter().fold(BigUint::from(1u32), |product, (index, share)| product * share.modpow(&lagrange_coefficient(&indexes, *index), &p) % &p);
+    let target = b * inverse(&shares_product) % &p;
+    let mut power = BigUint::from(1u32);
+    for count in 0..=max_count {
+        if power == target {
+            return Some(count);
+        }
+        power = power * &g % &p;
+    }
+    None
+}
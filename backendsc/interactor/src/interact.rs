@@ -1,15 +1,18 @@
 #![allow(non_snake_case)]
 
 mod config;
+pub mod elgamal;
 mod proxy;
+pub mod voter_roll;
 
 use config::Config;
-use multiversx_sc_snippets::imports::*;
+use multiversx_sc_snippets::{hex, imports::*};
 use serde::{Deserialize, Serialize};
 use std::{
     io::{self, Read, Write}, path::Path
 };
 use num_bigint;
+use sha2::{Digest, Sha256};
 const STATE_FILE: &str = "state.toml";
 
 pub async fn backendsc_cli() {
@@ -43,17 +46,113 @@ pub async fn backendsc_cli() {
             "getCandidateIDs" => call_get_candidate_id_list(&mut interact, args).await,
             "getCandidate" => call_get_candidate(&mut interact, args).await,
             "getVotes" => call_get_votes(&mut interact, args).await,
+            "getBallotCount" => call_get_ballot_count(&mut interact, args).await,
+            "getBallots" => call_get_ballots(&mut interact, args).await,
+            "getBallot" => call_get_ballot(&mut interact, args).await,
+            "verifyReceipt" => call_verify_receipt(&mut interact, args).await,
             "getDisputeIDList" => call_get_dispute_id_list(&mut interact, args).await,
             "getDispute" => call_get_dispute(&mut interact, args).await,
+            "getPairwiseMatrix" => call_get_pairwise_matrix(&mut interact, args).await,
             "result_vector" => call_result_vector(&mut interact, args).await,
+            "result_average" => call_result_average(&mut interact, args).await,
             "results" => call_results(&mut interact, args).await,
+            "getRanking" => call_get_ranking(&mut interact, args).await,
+            "getLockedPairs" => call_get_locked_pairs(&mut interact, args).await,
+            "getStarResult" => call_get_star_result(&mut interact, args).await,
+            "getStvRounds" => call_get_stv_rounds(&mut interact, args).await,
+            "getTieBreaks" => call_get_tie_breaks(&mut interact, args).await,
+            "getBlankBallots" => call_get_blank_ballots(&mut interact, args).await,
+            "getBlankBallotsAllowed" => call_get_blank_ballots_allowed(&mut interact, args).await,
+            "setBlankBallotsAllowed" => call_set_blank_ballots_allowed(&mut interact, args).await,
+            "getRevotingAllowed" => call_get_revoting_allowed(&mut interact, args).await,
+            "setRevotingAllowed" => call_set_revoting_allowed(&mut interact, args).await,
+            "getSupersededBallots" => call_get_superseded_ballots(&mut interact, args).await,
+            "getDelegationAllowed" => call_get_delegation_allowed(&mut interact, args).await,
+            "issueVoterCredential" => call_issue_voter_credential(&mut interact, args).await,
+            "setVoterCredentialRoles" => interact.set_voter_credential_roles().await,
+            "getVoterCredentialToken" => interact.voter_credential_token().await,
+            "getCredentialRequired" => call_get_credential_required(&mut interact, args).await,
+            "setCredentialRequired" => call_set_credential_required(&mut interact, args).await,
+            "revokeVoterCredential" => call_revoke_voter_credential(&mut interact, args).await,
+            "getRevokedVoters" => call_get_revoked_voters(&mut interact, args).await,
+            "getVoter" => call_get_voter(&mut interact, args).await,
+            "setDelegationAllowed" => call_set_delegation_allowed(&mut interact, args).await,
+            "delegateVote" => call_delegate_vote(&mut interact, args).await,
+            "revokeDelegation" => call_revoke_delegation(&mut interact, args).await,
+            "getDelegators" => call_get_delegators(&mut interact, args).await,
+            "getDelegationChain" => call_get_delegation_chain(&mut interact, args).await,
+            "getEffectiveWeight" => call_get_effective_weight(&mut interact, args).await,
+            "setNoneOfTheAbove" => call_set_none_of_the_above(&mut interact, args).await,
+            "rerunElection" => call_rerun_election(&mut interact, args).await,
+            "castTieBreakingVote" => call_cast_tie_breaking_vote(&mut interact, args).await,
             "electionList" => interact.election_list().await,
             "registerElection" => call_register_election(&mut interact, args).await,
+            "getRatingScale" => call_get_rating_scale(&mut interact, args).await,
+            "setRatingScale" => call_set_rating_scale(&mut interact, args).await,
+            "setSeats" => call_set_seats(&mut interact, args).await,
+            "getPointBudget" => call_get_point_budget(&mut interact, args).await,
+            "setPointBudget" => call_set_point_budget(&mut interact, args).await,
+            "getVoiceCreditBudget" => call_get_voice_credit_budget(&mut interact, args).await,
+            "setVoiceCreditBudget" => call_set_voice_credit_budget(&mut interact, args).await,
+            "getVoiceCreditsSpent" => call_get_voice_credits_spent(&mut interact, args).await,
+            "getNetVotes" => call_get_net_votes(&mut interact, args).await,
+            "getGrades" => call_get_grades(&mut interact, args).await,
+            "setGrades" => call_set_grades(&mut interact, args).await,
+            "getGradeResults" => call_get_grade_results(&mut interact, args).await,
+            "getRunoffSchedule" => call_get_runoff_schedule(&mut interact, args).await,
+            "setRunoffSchedule" => call_set_runoff_schedule(&mut interact, args).await,
+            "getBallotRules" => call_get_ballot_rules(&mut interact, args).await,
+            "setBallotRules" => call_set_ballot_rules(&mut interact, args).await,
+            "getPartyIDs" => call_get_party_id_list(&mut interact, args).await,
+            "getParty" => call_get_party(&mut interact, args).await,
+            "getPartyList" => call_get_party_list(&mut interact, args).await,
+            "getPartyListRules" => call_get_party_list_rules(&mut interact, args).await,
+            "setPartyListRules" => call_set_party_list_rules(&mut interact, args).await,
+            "getPartyResults" => call_get_party_results(&mut interact, args).await,
+            "registerParty" => call_register_party(&mut interact, args).await,
+            "addCandidateToParty" => call_add_candidate_to_party(&mut interact, args).await,
+            "getReferendumRules" => call_get_referendum_rules(&mut interact, args).await,
+            "setReferendumRules" => call_set_referendum_rules(&mut interact, args).await,
+            "getReferendumResult" => call_get_referendum_result(&mut interact, args).await,
+            "voteReferendum" => call_vote_referendum(&mut interact, args).await,
             "submitCandidancy" => call_submit_candidancy(&mut interact, args).await,
             "registerCandidate" => call_register_candidate(&mut interact, args).await,
             "registerSelf" => call_register_self(&mut interact, args).await,
+            "buildVoterRoll" => call_build_voter_roll(args),
+            "voterRollProof" => call_voter_roll_proof(args),
+            "getVoterRoll" => call_get_voter_roll(&mut interact, args).await,
+            "setVoterRoll" => call_set_voter_roll(&mut interact, args).await,
+            "getVoterWeight" => call_get_voter_weight(&mut interact, args).await,
+            "registerFromVoterRoll" => call_register_from_voter_roll(&mut interact, args).await,
+            "voteWithProof" => call_vote_with_proof(&mut interact, args).await,
             "registerVoter" => call_register_voter(&mut interact, args).await,
             "vote" => call_vote(&mut interact, args).await,
+            "voteRated" => call_vote_rated(&mut interact, args).await,
+            "voteStaked" => call_vote_staked(&mut interact, args).await,
+            "voteRatedStaked" => call_vote_rated_staked(&mut interact, args).await,
+            "getRevealPeriod" => call_get_reveal_period(&mut interact, args).await,
+            "setRevealPeriod" => call_set_reveal_period(&mut interact, args).await,
+            "commitVote" => call_commit_vote(&mut interact, args).await,
+            "revealVote" => call_reveal_vote(&mut interact, args).await,
+            "getCommitment" => call_get_commitment(&mut interact, args).await,
+            "getUnrevealedVoters" => call_get_unrevealed_voters(&mut interact, args).await,
+            "getTrustees" => call_get_trustees(&mut interact, args).await,
+            "setTrustees" => call_set_trustees(&mut interact, args).await,
+            "getTrusteeThreshold" => call_get_trustee_threshold(&mut interact, args).await,
+            "getTrusteeKey" => call_get_trustee_key(&mut interact, args).await,
+            "getCoefficientCommitments" => call_get_coefficient_commitments(&mut interact, args).await,
+            "getTrusteeVerificationKey" => call_get_trustee_verification_key(&mut interact, args).await,
+            "registerTrusteeKey" => call_register_trustee_key(&mut interact, args).await,
+            "getJointPublicKey" => call_get_joint_public_key(&mut interact, args).await,
+            "voteEncrypted" => call_vote_encrypted(&mut interact, args).await,
+            "getEncryptedTally" => call_get_encrypted_tally(&mut interact, args).await,
+            "postDecryptionShares" => call_post_decryption_shares(&mut interact, args).await,
+            "getDecryptingTrustees" => call_get_decrypting_trustees(&mut interact, args).await,
+            "publishTally" => call_publish_tally(&mut interact, args).await,
+            "getVotingToken" => call_get_voting_token(&mut interact, args).await,
+            "setVotingToken" => call_set_voting_token(&mut interact, args).await,
+            "getStake" => call_get_stake(&mut interact, args).await,
+            "withdrawStake" => call_withdraw_stake(&mut interact, args).await,
             "endElection" => call_end_election(&mut interact, args).await,
             "makeDispute" => call_make_dispute(&mut interact, args).await,
             "exit" => break,
@@ -155,6 +254,56 @@ async fn call_get_votes(interact: &mut ContractInteract, mut args: std::str::Spl
     call_on_election_id(&mut args, |election_id, _| interact.votes(election_id)).await;
 }
 
+async fn call_get_ballot_count(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.ballot_count(election_id)).await;
+}
+
+async fn call_get_ballots(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let from = match get_value::<u64>(&mut args) {
+        Ok(from) => from,
+        Err(e) => {println!("Error parsing first ballot id: {}", e); return;}
+    };
+    let count = match get_value::<u64>(&mut args) {
+        Ok(count) => count,
+        Err(e) => {println!("Error parsing ballot count: {}", e); return;}
+    };
+    interact.ballots(election_id, from, count).await;
+}
+
+async fn call_get_ballot(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let ballot_id = match get_value::<u64>(&mut args) {
+        Ok(ballot_id) => ballot_id,
+        Err(e) => {println!("Error parsing ballot id: {}", e); return;}
+    };
+    interact.ballot(election_id, ballot_id).await;
+}
+
+/// Checks a receipt returned by a vote: `verifyReceipt <election_id> <ballot_id> <salt>`, the salt being the
+/// hex-value of the receipt.
+async fn call_verify_receipt(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let ballot_id = match get_value::<u64>(&mut args) {
+        Ok(ballot_id) => ballot_id,
+        Err(e) => {println!("Error parsing ballot id: {}", e); return;}
+    };
+    let salt: [u8; 32] = match args.next().map(hex::decode) {
+        Some(Ok(salt)) if salt.len() == 32 => salt.try_into().unwrap(),
+        _ => {println!("receipt salt required: 32 bytes in hexadecimal"); return;}
+    };
+    interact.verify_receipt(election_id, ballot_id, salt).await;
+}
+
 async fn call_get_dispute_id_list(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
     call_on_election_id(&mut args, |election_id, _| interact.dispute_id_list(election_id)).await;
 }
@@ -171,6 +320,10 @@ async fn call_get_dispute(interact: &mut ContractInteract, mut args: std::str::S
     interact.dispute(election_id, dispute_id).await;
 }
 
+async fn call_get_pairwise_matrix(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.pairwise_matrix(election_id)).await;
+}
+
 async fn call_result_vector(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
     call_on_election_id_and_candidate_id(&mut args, 
         |election_id, candidate_id, _| 
@@ -178,10 +331,234 @@ async fn call_result_vector(interact: &mut ContractInteract, mut args: std::str:
     ).await;
 }
 
+async fn call_result_average(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id_and_candidate_id(&mut args, 
+        |election_id, candidate_id, _| 
+        interact.result_average(election_id, candidate_id)
+    ).await;
+}
+
 async fn call_results(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
     call_on_election_id(&mut args, |election_id, _| interact.results(election_id)).await;
 }
 
+async fn call_get_ranking(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.ranking(election_id)).await;
+}
+
+async fn call_get_locked_pairs(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.locked_pairs(election_id)).await;
+}
+
+async fn call_get_star_result(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.star_result(election_id)).await;
+}
+
+async fn call_get_stv_rounds(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.stv_rounds(election_id)).await;
+}
+
+async fn call_get_tie_breaks(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.get_tie_breaks(election_id)).await;
+}
+
+async fn call_cast_tie_breaking_vote(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id_and_candidate_id(&mut args, 
+        |election_id, candidate_id, _| 
+        interact.cast_tie_breaking_vote(election_id, candidate_id)
+    ).await;
+}
+
+async fn call_get_blank_ballots(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.blank_ballots(election_id)).await;
+}
+
+async fn call_get_blank_ballots_allowed(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.blank_ballots_allowed(election_id)).await;
+}
+
+async fn call_set_blank_ballots_allowed(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let allowed = match get_value::<bool>(&mut args) {
+        Ok(allowed) => allowed,
+        Err(e) => {println!("Error parsing allowed: {}", e); return;}
+    };
+    interact.set_blank_ballots_allowed(election_id, allowed).await;
+}
+
+async fn call_get_revoting_allowed(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.revoting_allowed(election_id)).await;
+}
+
+async fn call_set_revoting_allowed(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let allowed = match get_value::<bool>(&mut args) {
+        Ok(allowed) => allowed,
+        Err(e) => {println!("Error parsing allowed: {}", e); return;}
+    };
+    interact.set_revoting_allowed(election_id, allowed).await;
+}
+
+async fn call_get_superseded_ballots(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.superseded_ballots(election_id)).await;
+}
+
+async fn call_issue_voter_credential(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let name = match args.next() {
+        Some(name) => name,
+        None => {println!("token display name required"); return;}
+    };
+    let ticker = match args.next() {
+        Some(ticker) => ticker,
+        None => {println!("token ticker required"); return;}
+    };
+    interact.issue_voter_credential(name, ticker).await;
+}
+
+async fn call_get_credential_required(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.credential_required(election_id)).await;
+}
+
+async fn call_set_credential_required(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let required = match get_value::<bool>(&mut args) {
+        Ok(required) => required,
+        Err(e) => {println!("Error parsing required: {}", e); return;}
+    };
+    interact.set_credential_required(election_id, required).await;
+}
+
+async fn call_revoke_voter_credential(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let voter_address = match args.next() {
+        Some(voter_address) => voter_address,
+        None => {println!("voter address required"); return;}
+    };
+    interact.revoke_voter_credential(election_id, voter_address).await;
+}
+
+async fn call_get_revoked_voters(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.revoked_voters(election_id)).await;
+}
+
+async fn call_get_voter(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let voter_address = match args.next() {
+        Some(voter_address) => voter_address,
+        None => {println!("voter address required"); return;}
+    };
+    interact.voter(election_id, voter_address).await;
+}
+
+async fn call_get_delegation_allowed(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.delegation_allowed(election_id)).await;
+}
+
+async fn call_set_delegation_allowed(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let allowed = match get_value::<bool>(&mut args) {
+        Ok(allowed) => allowed,
+        Err(e) => {println!("Error parsing allowed: {}", e); return;}
+    };
+    interact.set_delegation_allowed(election_id, allowed).await;
+}
+
+async fn call_delegate_vote(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let delegate = match args.next() {
+        Some(delegate) => delegate,
+        None => {println!("delegate address required"); return;}
+    };
+    interact.delegate_vote(election_id, delegate).await;
+}
+
+async fn call_revoke_delegation(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.revoke_delegation(election_id)).await;
+}
+
+async fn call_get_delegators(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.delegators(election_id)).await;
+}
+
+async fn call_get_delegation_chain(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let voter_address = match args.next() {
+        Some(voter_address) => voter_address,
+        None => {println!("voter address required"); return;}
+    };
+    interact.delegation_chain(election_id, voter_address).await;
+}
+
+async fn call_get_effective_weight(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let voter_address = match args.next() {
+        Some(voter_address) => voter_address,
+        None => {println!("voter address required"); return;}
+    };
+    interact.effective_weight(election_id, voter_address).await;
+}
+
+async fn call_set_none_of_the_above(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let enabled = match get_value::<bool>(&mut args) {
+        Ok(enabled) => enabled,
+        Err(e) => {println!("Error parsing enabled: {}", e); return;}
+    };
+    interact.set_none_of_the_above(election_id, enabled).await;
+}
+
+async fn call_rerun_election(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let start_time = match args.next() {
+        Some(start_time_str) => match chrono::DateTime::parse_from_rfc3339(start_time_str) {
+            Ok(dt) => dt.timestamp() as u64,
+            Err(_) => {println!("invalid start time; format: YYYY-MM-DD HH:MM:SS"); return;},
+        },
+        None => {println!("start time required; format: YYYY-MM-DD HH:MM:SS"); return;},
+    };
+    let end_time = match args.next() {
+        Some(end_time_str) => match chrono::DateTime::parse_from_rfc3339(end_time_str) {
+            Ok(dt) => dt.timestamp() as u64,
+            Err(_) => {println!("invalid end time; format: YYYY-MM-DD HH:MM:SS"); return;},
+        },
+        None => {println!("end time required; format: YYYY-MM-DD HH:MM:SS"); return;},
+    };
+    interact.rerun_election(election_id, start_time, end_time).await;
+}
+
 async fn call_register_election(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
     let name = match args.next() {
         Some(name) => name,
@@ -210,260 +587,2513 @@ async fn call_register_election(interact: &mut ContractInteract, mut args: std::
         },
         None => {println!("end time required; format: YYYY-MM-DD HH:MM:SS"); return ;},
     };
-    interact.register_election(name, description, election_type, start_time, end_time).await;
+    // optional tie-break policy, the tied candidate registered first wins by default
+    let tie_break_policy = match args.next() {
+        Some("EarliestRegistration") | None => None,
+        Some("Random") => Some(1u64),
+        Some("AdminCastingVote") => Some(2u64),
+        Some(_) => {println!("invalid tie-break policy; EarliestRegistration, Random or AdminCastingVote"); return;},
+    };
+    interact.register_election(name, description, election_type, start_time, end_time, tie_break_policy).await;
 }
 
-async fn call_submit_candidancy(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+async fn call_get_rating_scale(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.rating_scale(election_id)).await;
+}
+
+async fn call_set_rating_scale(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
     let election_id = match get_value::<u64>(&mut args) {
         Ok(election_id) => election_id,
-        Err(e) => {
-            println!("Error parsing election id: {}", e);
-            return ;
-        }
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
     };
-    let candidate_name = match args.next() {
-        Some(candidate_name) => candidate_name,
-        None => {
-            println!("candidate name required");
-            return;
-        }
+    let min = match get_value::<u64>(&mut args) {
+        Ok(min) => min,
+        Err(e) => {println!("Error parsing min rating: {}", e); return;}
     };
-    let candidate_description = match args.next() {
-        Some(candidate_description) => candidate_description,
-        None => {
-            println!("candidate description required");
-            return;
-        }
+    let max = match get_value::<u64>(&mut args) {
+        Ok(max) => max,
+        Err(e) => {println!("Error parsing max rating: {}", e); return;}
     };
-    let candidate_fee = match get_biguint(&mut args) {
-        Ok(candidate_fee) => candidate_fee,
-        Err(e) => {
-            println!("Error parsing candidate fee: {}", e);
-            return ;
-        }
+    interact.set_rating_scale(election_id, min, max).await;
+}
+
+async fn call_set_seats(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
     };
-    
-    interact.submit_candidancy(election_id, candidate_name, candidate_description, candidate_fee).await;
+    let seats = match get_value::<u32>(&mut args) {
+        Ok(seats) => seats,
+        Err(e) => {println!("Error parsing seats: {}", e); return;}
+    };
+    interact.set_seats(election_id, seats).await;
 }
 
-async fn call_register_candidate(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
-    call_on_election_id_and_candidate_id(&mut args, 
-        |election_id, candidate_id, _| 
-        interact.register_candidate(election_id, candidate_id)
-    ).await;
+async fn call_get_point_budget(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.point_budget(election_id)).await;
 }
 
-async fn call_register_self(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+async fn call_set_point_budget(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
     let election_id = match get_value::<u64>(&mut args) {
         Ok(election_id) => election_id,
         Err(e) => {println!("Error parsing election id: {}", e); return;}
     };
-    let verification_data = match args.next() {
-        Some(verification_data) => verification_data,
-        None => {println!("verification data required"); return;}
+    let budget = match get_value::<u64>(&mut args) {
+        Ok(budget) => budget,
+        Err(e) => {println!("Error parsing point budget: {}", e); return;}
     };
-    
-    interact.register_self(election_id, verification_data).await;
+    interact.set_point_budget(election_id, budget).await;
 }
 
-async fn call_register_voter(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
-    
+async fn call_get_voice_credit_budget(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.voice_credit_budget(election_id)).await;
+}
+
+async fn call_set_voice_credit_budget(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let budget = match get_value::<u64>(&mut args) {
+        Ok(budget) => budget,
+        Err(e) => {println!("Error parsing voice credit budget: {}", e); return;}
+    };
+    interact.set_voice_credit_budget(election_id, budget).await;
+}
+
+async fn call_get_voice_credits_spent(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
     let election_id = match get_value::<u64>(&mut args) {
         Ok(election_id) => election_id,
         Err(e) => {println!("Error parsing election id: {}", e); return;}
     };
-    
     let voter_address = match args.next() {
         Some(voter_address) => voter_address,
         None => {println!("voter address required"); return;}
     };
-    
-    interact.register_voter(election_id, voter_address).await;
+    interact.voice_credits_spent(election_id, voter_address).await;
 }
 
-async fn call_vote(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+async fn call_get_net_votes(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id_and_candidate_id(&mut args, |election_id, candidate_id, _| interact.net_votes(election_id, candidate_id)).await;
+}
+
+async fn call_get_grades(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.grades(election_id)).await;
+}
 
+async fn call_set_grades(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
     let election_id = match get_value::<u64>(&mut args) {
         Ok(election_id) => election_id,
         Err(e) => {println!("Error parsing election id: {}", e); return;}
     };
-
-    let mut choices = Vec::new();
-    for arg in args {
-        match arg.parse::<u16>() {
-            Ok(choice) => choices.push(choice),
-            Err(e) => {
-                println!("Error parsing vote: {}", e);
-                return;
-            }
-        }
-    }
-
-    
-    interact.vote(election_id, choices).await;
+    // grade names, from the worst grade to the best
+    let grades: Vec<&str> = args.collect();
+    interact.set_grades(election_id, grades).await;
 }
 
-async fn call_end_election(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
-    call_on_election_id(&mut args, |election_id, _| interact.end_election(election_id)).await;
+async fn call_get_grade_results(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.grade_results(election_id)).await;
 }
 
-async fn call_make_dispute(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
-    
+async fn call_get_runoff_schedule(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.runoff_schedule(election_id)).await;
+}
 
+async fn call_set_runoff_schedule(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
     let election_id = match get_value::<u64>(&mut args) {
         Ok(election_id) => election_id,
         Err(e) => {println!("Error parsing election id: {}", e); return;}
     };
-
-    let dispute_name = match args.next() {
-        Some(dispute_name) => dispute_name,
-        None => {println!("dispute name required"); return;}
+    let start_time = match args.next() {
+        Some(start_time_str) => match chrono::DateTime::parse_from_rfc3339(start_time_str) {
+            Ok(dt) => dt.timestamp() as u64,
+            Err(_) => {println!("invalid start time; format: YYYY-MM-DD HH:MM:SS"); return;},
+        },
+        None => {println!("start time required; format: YYYY-MM-DD HH:MM:SS"); return;},
+    };
+    let end_time = match args.next() {
+        Some(end_time_str) => match chrono::DateTime::parse_from_rfc3339(end_time_str) {
+            Ok(dt) => dt.timestamp() as u64,
+            Err(_) => {println!("invalid end time; format: YYYY-MM-DD HH:MM:SS"); return;},
+        },
+        None => {println!("end time required; format: YYYY-MM-DD HH:MM:SS"); return;},
+    };
+    interact.set_runoff_schedule(election_id, start_time, end_time).await;
+}
+
+async fn call_get_ballot_rules(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.ballot_rules(election_id)).await;
+}
+
+async fn call_set_ballot_rules(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let min_selections = match get_value::<u32>(&mut args) {
+        Ok(min_selections) => min_selections,
+        Err(e) => {println!("Error parsing minimum selections: {}", e); return;}
+    };
+    let max_selections = match get_value::<u32>(&mut args) {
+        Ok(max_selections) => max_selections,
+        Err(e) => {println!("Error parsing maximum selections: {}", e); return;}
+    };
+    let full_ranking = match get_value::<bool>(&mut args) {
+        Ok(full_ranking) => full_ranking,
+        Err(e) => {println!("Error parsing full ranking: {}", e); return;}
+    };
+    interact.set_ballot_rules(election_id, min_selections, max_selections, full_ranking).await;
+}
+
+async fn call_get_party_id_list(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.party_id_list(election_id)).await;
+}
+
+async fn call_get_party(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id_and_candidate_id(&mut args, |election_id, party_id, _| interact.party(election_id, party_id)).await;
+}
+
+async fn call_get_party_list(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id_and_candidate_id(&mut args, |election_id, party_id, _| interact.party_list(election_id, party_id)).await;
+}
+
+async fn call_get_party_list_rules(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.party_list_rules(election_id)).await;
+}
+
+async fn call_set_party_list_rules(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let method = match args.next() {
+        Some("DHondt") => 0u64,
+        Some("SainteLague") => 1u64,
+        _ => {println!("seat allocation method required; DHondt or SainteLague"); return;}
+    };
+    let threshold_percent = match get_value::<u64>(&mut args) {
+        Ok(threshold_percent) => threshold_percent,
+        Err(e) => {println!("Error parsing threshold percentage: {}", e); return;}
+    };
+    interact.set_party_list_rules(election_id, method, threshold_percent).await;
+}
+
+async fn call_get_party_results(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.party_results(election_id)).await;
+}
+
+async fn call_register_party(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let name = match args.next() {
+        Some(name) => name,
+        None => {println!("party name required"); return;}
+    };
+    interact.register_party(election_id, name).await;
+}
+
+async fn call_add_candidate_to_party(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let party_id = match get_value::<u16>(&mut args) {
+        Ok(party_id) => party_id,
+        Err(e) => {println!("Error parsing party id: {}", e); return;}
+    };
+    let candidate_id = match get_value::<u16>(&mut args) {
+        Ok(candidate_id) => candidate_id,
+        Err(e) => {println!("Error parsing candidate id: {}", e); return;}
+    };
+    interact.add_candidate_to_party(election_id, party_id, candidate_id).await;
+}
+
+async fn call_get_referendum_rules(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.referendum_rules(election_id)).await;
+}
+
+async fn call_set_referendum_rules(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let quorum_percent = match get_value::<u64>(&mut args) {
+        Ok(quorum_percent) => quorum_percent,
+        Err(e) => {println!("Error parsing quorum percentage: {}", e); return;}
+    };
+    // pass threshold as a fraction, e.g. 1/2, 3/5 or 2/3
+    let (threshold_numerator, threshold_denominator) = match args.next().and_then(|t| t.split_once('/')) {
+        Some((numerator, denominator)) => match (numerator.parse::<u64>(), denominator.parse::<u64>()) {
+            (Ok(numerator), Ok(denominator)) => (numerator, denominator),
+            _ => {println!("invalid pass threshold; format: NUMERATOR/DENOMINATOR"); return;}
+        },
+        None => {println!("pass threshold required; format: NUMERATOR/DENOMINATOR"); return;}
+    };
+    interact.set_referendum_rules(election_id, quorum_percent, threshold_numerator, threshold_denominator).await;
+}
+
+async fn call_get_referendum_result(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.referendum_result(election_id)).await;
+}
+
+async fn call_vote_referendum(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    // the choice IDs of the contract: REFERENDUM_YES, REFERENDUM_NO and REFERENDUM_ABSTAIN
+    let choice = match args.next() {
+        Some("yes") => 1u16,
+        Some("no") => 2u16,
+        Some("abstain") => 3u16,
+        _ => {println!("choice required; yes, no or abstain"); return;}
+    };
+    interact.vote(election_id, vec![choice]).await;
+}
+
+async fn call_submit_candidancy(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {
+            println!("Error parsing election id: {}", e);
+            return ;
+        }
+    };
+    let candidate_name = match args.next() {
+        Some(candidate_name) => candidate_name,
+        None => {
+            println!("candidate name required");
+            return;
+        }
+    };
+    let candidate_description = match args.next() {
+        Some(candidate_description) => candidate_description,
+        None => {
+            println!("candidate description required");
+            return;
+        }
+    };
+    let candidate_fee = match get_biguint(&mut args) {
+        Ok(candidate_fee) => candidate_fee,
+        Err(e) => {
+            println!("Error parsing candidate fee: {}", e);
+            return ;
+        }
     };
     
-    let dispute_description = match args.next() {
-        Some(dispute_description) => dispute_description,
-        None => {println!("dispute description required"); return;}
+    interact.submit_candidancy(election_id, candidate_name, candidate_description, candidate_fee).await;
+}
+
+async fn call_register_candidate(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id_and_candidate_id(&mut args, 
+        |election_id, candidate_id, _| 
+        interact.register_candidate(election_id, candidate_id)
+    ).await;
+}
+
+async fn call_register_self(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let verification_data = match args.next() {
+        Some(verification_data) => verification_data,
+        None => {println!("verification data required"); return;}
+    };
+    
+    interact.register_self(election_id, verification_data).await;
+}
+
+fn load_voter_roll(path: &str) -> Option<voter_roll::VoterRollTree> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) => {println!("Error reading {path}: {e}"); return None;}
+    };
+    match voter_roll::parse_csv(&contents) {
+        Ok(voters) => Some(voter_roll::VoterRollTree::new(voters)),
+        Err(e) => {println!("Error parsing {path}: {e}"); None}
+    }
+}
+
+/// Builds the Merkle tree of a voter roll CSV file (`<address>[,<weight>]` per line), prints its root
+/// and writes the proof of every voter to `<csv_path>.proofs.csv`.
+fn call_build_voter_roll(mut args: std::str::SplitWhitespace<'_>) {
+    let path = match args.next() {
+        Some(path) => path,
+        None => {println!("voter roll CSV file required"); return;}
+    };
+    let Some(tree) = load_voter_roll(path) else { return; };
+
+    let mut proofs = String::new();
+    for (address, weight) in tree.voters.iter() {
+        let proof = tree.proof(address).unwrap();
+        proofs.push_str(&format!("{},{},{}\n", bech32::encode(address), weight, hex::encode(proof)));
+    }
+    let proofs_path = format!("{path}.proofs.csv");
+    if let Err(e) = std::fs::write(&proofs_path, proofs) {
+        println!("Error writing {proofs_path}: {e}");
+        return;
+    }
+    println!("Voters: {}", tree.voters.len());
+    println!("Root: {}", hex::encode(tree.root()));
+    println!("Proofs written to {proofs_path}");
+}
+
+fn call_voter_roll_proof(mut args: std::str::SplitWhitespace<'_>) {
+    let path = match args.next() {
+        Some(path) => path,
+        None => {println!("voter roll CSV file required"); return;}
+    };
+    let address = match args.next().map(voter_roll::parse_address) {
+        Some(Ok(address)) => address,
+        Some(Err(e)) => {println!("Error parsing voter address: {e}"); return;}
+        None => {println!("voter address required"); return;}
+    };
+    let Some(tree) = load_voter_roll(path) else { return; };
+    match tree.proof(&address) {
+        Some(proof) => println!("Proof: {}", hex::encode(proof)),
+        None => println!("Address is not in the voter roll"),
+    }
+}
+
+async fn call_get_voter_roll(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.voter_roll(election_id)).await;
+}
+
+async fn call_set_voter_roll(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let root: [u8; 32] = match args.next().map(hex::decode) {
+        Some(Ok(root)) if root.len() == 32 => root.try_into().unwrap(),
+        _ => {println!("root required: 32 bytes in hexadecimal"); return;}
+    };
+    let voters = match get_value::<u64>(&mut args) {
+        Ok(voters) => voters,
+        Err(e) => {println!("Error parsing number of voters: {}", e); return;}
+    };
+    let weighted = match get_value::<bool>(&mut args) {
+        Ok(weighted) => weighted,
+        Err(e) => {println!("Error parsing weighted: {}", e); return;}
+    };
+    interact.set_voter_roll(election_id, root, voters, weighted).await;
+}
+
+async fn call_get_voter_weight(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let voter_address = match args.next() {
+        Some(voter_address) => voter_address,
+        None => {println!("voter address required"); return;}
+    };
+    interact.voter_weight(election_id, voter_address).await;
+}
+
+async fn call_register_from_voter_roll(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let path = match args.next() {
+        Some(path) => path,
+        None => {println!("voter roll CSV file required"); return;}
+    };
+    let Some(tree) = load_voter_roll(path) else { return; };
+    interact.register_from_voter_roll(election_id, &tree).await;
+}
+
+async fn call_vote_with_proof(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let path = match args.next() {
+        Some(path) => path,
+        None => {println!("voter roll CSV file required"); return;}
+    };
+    let vote: Vec<u16> = match args.map(|arg| arg.parse::<u16>()).collect() {
+        Ok(vote) => vote,
+        Err(e) => {println!("Error parsing candidate id: {}", e); return;}
+    };
+    let Some(tree) = load_voter_roll(path) else { return; };
+    interact.vote_with_proof(election_id, &tree, vote).await;
+}
+
+async fn call_register_voter(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    
+    let voter_address = match args.next() {
+        Some(voter_address) => voter_address,
+        None => {println!("voter address required"); return;}
+    };
+    
+    interact.register_voter(election_id, voter_address).await;
+}
+
+async fn call_vote(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+
+    let mut choices = Vec::new();
+    for arg in args {
+        match arg.parse::<u16>() {
+            Ok(choice) => choices.push(choice),
+            Err(e) => {
+                println!("Error parsing vote: {}", e);
+                return;
+            }
+        }
+    }
+
+    
+    interact.vote(election_id, choices).await;
+}
+
+async fn call_vote_rated(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+
+    // each rating is given as <candidate_id>:<rating>
+    let mut ratings = Vec::new();
+    for arg in args {
+        let rating = arg.split_once(':').and_then(|(candidate, rating)| {
+            Some((candidate.parse::<u16>().ok()?, rating.parse::<i64>().ok()?))
+        });
+        match rating {
+            Some(rating) => ratings.push(rating),
+            None => {
+                println!("Error parsing rating {}; format: <candidate_id>:<rating>", arg);
+                return;
+            }
+        }
+    }
+
+    interact.vote_rated(election_id, ratings).await;
+}
+
+async fn call_get_reveal_period(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.reveal_period(election_id)).await;
+}
+
+async fn call_set_reveal_period(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let reveal_period = match get_value::<u64>(&mut args) {
+        Ok(reveal_period) => reveal_period,
+        Err(e) => {println!("Error parsing reveal period: {}", e); return;}
     };
+    interact.set_reveal_period(election_id, reveal_period).await;
+}
+
+/// Parses a ballot given as a list of <candidate_id> or, for rated votes, of <candidate_id>:<rating>.
+fn parse_ballot(args: std::str::SplitWhitespace<'_>) -> Option<(Vec<u16>, Vec<i64>)> {
+    let mut candidates = Vec::new();
+    let mut ratings = Vec::new();
+    for arg in args {
+        match arg.split_once(':') {
+            Some((candidate, rating)) => {
+                candidates.push(candidate.parse::<u16>().ok()?);
+                ratings.push(rating.parse::<i64>().ok()?);
+            },
+            None => candidates.push(arg.parse::<u16>().ok()?),
+        }
+    }
+    if !ratings.is_empty() && ratings.len() != candidates.len() {
+        return None;
+    }
+    Some((candidates, ratings))
+}
+
+/// Computes the commitment of a ballot as the contract does: sha256(election_id || voter || ballot || salt), where
+/// the election ID is 8 bytes (big-endian), the voter is their 32-byte address, the ballot is the list of candidate IDs
+/// (2 bytes each, big-endian), each followed by its rating (8 bytes, big-endian) for rated votes, and the salt is 32 bytes.
+fn ballot_hash(election_id: u64, voter: &Address, candidates: &[u16], ratings: &[i64], salt: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(election_id.to_be_bytes());
+    hasher.update(voter.as_bytes());
+    for (i, candidate) in candidates.iter().enumerate() {
+        hasher.update(candidate.to_be_bytes());
+        if let Some(rating) = ratings.get(i) {
+            hasher.update(rating.to_be_bytes());
+        }
+    }
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+/// Parses the salt of a committed vote: 32 random bytes in hexadecimal.
+fn parse_salt(args: &mut std::str::SplitWhitespace<'_>) -> Option<[u8; 32]> {
+    match args.next().map(hex::decode) {
+        Some(Ok(salt)) if salt.len() == 32 => salt.try_into().ok(),
+        _ => None,
+    }
+}
+
+async fn call_commit_vote(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    // the salt must be random and kept secret until the vote is revealed
+    let salt = match parse_salt(&mut args) {
+        Some(salt) => salt,
+        None => {println!("salt required: 32 random bytes in hexadecimal"); return;}
+    };
+    let (candidates, ratings) = match parse_ballot(args) {
+        Some(ballot) => ballot,
+        None => {println!("Error parsing vote; format: <candidate_id>... or <candidate_id>:<rating>..."); return;}
+    };
+    let hash = ballot_hash(election_id, &interact.wallet_address, &candidates, &ratings, &salt);
+    interact.commit_vote(election_id, hash).await;
+}
+
+async fn call_reveal_vote(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let salt = match parse_salt(&mut args) {
+        Some(salt) => salt,
+        None => {println!("salt required: 32 bytes in hexadecimal"); return;}
+    };
+    let (candidates, ratings) = match parse_ballot(args) {
+        Some(ballot) => ballot,
+        None => {println!("Error parsing vote; format: <candidate_id>... or <candidate_id>:<rating>..."); return;}
+    };
+    if ratings.is_empty() {
+        interact.reveal_vote(election_id, &salt, candidates).await;
+    } else {
+        interact.reveal_vote_rated(election_id, &salt, candidates.into_iter().zip(ratings).collect()).await;
+    }
+}
+
+async fn call_get_commitment(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let voter_address = match args.next() {
+        Some(voter_address) => voter_address,
+        None => {println!("voter address required"); return;}
+    };
+    interact.commitment(election_id, voter_address).await;
+}
+
+async fn call_get_unrevealed_voters(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.unrevealed_voters(election_id)).await;
+}
+
+async fn call_get_trustees(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.trustees(election_id)).await;
+}
+
+async fn call_set_trustees(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let threshold = match get_value::<usize>(&mut args) {
+        Ok(threshold) => threshold,
+        Err(e) => {println!("Error parsing threshold: {}", e); return;}
+    };
+    let trustees: Vec<&str> = args.collect();
+    interact.set_trustees(election_id, threshold, trustees).await;
+}
+
+async fn call_get_trustee_threshold(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.trustee_threshold(election_id)).await;
+}
+
+async fn call_get_trustee_key(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let trustee = match args.next() {
+        Some(trustee) => trustee,
+        None => {println!("trustee address required"); return;}
+    };
+    interact.trustee_key(election_id, trustee).await;
+}
+
+async fn call_get_coefficient_commitments(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let trustee = match args.next() {
+        Some(trustee) => trustee,
+        None => {println!("trustee address required"); return;}
+    };
+    interact.coefficient_commitments(election_id, trustee).await;
+}
+
+async fn call_get_trustee_verification_key(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let trustee = match args.next() {
+        Some(trustee) => trustee,
+        None => {println!("trustee address required"); return;}
+    };
+    interact.trustee_verification_key(election_id, trustee).await;
+}
+
+/// Parses a number given in hexadecimal, as printed by the trustee tool.
+fn get_hex(args: &mut std::str::SplitWhitespace<'_>) -> Result<num_bigint::BigUint, &'static str> {
+    let value = args.next().ok_or("missing value")?;
+    num_bigint::BigUint::parse_bytes(value.trim_start_matches("0x").as_bytes(), 16).ok_or("invalid hexadecimal number")
+}
+
+async fn call_register_trustee_key(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    // without the coefficients of a secret polynomial, a new one is generated
+    let mut coefficients = Vec::new();
+    loop {
+        match get_hex(&mut args) {
+            Ok(coefficient) => coefficients.push(coefficient),
+            Err("missing value") => break,
+            Err(e) => {println!("Error parsing coefficient: {}", e); return;}
+        }
+    }
+    interact.register_trustee_key(election_id, (!coefficients.is_empty()).then_some(coefficients)).await;
+}
+
+async fn call_get_joint_public_key(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.joint_public_key(election_id)).await;
+}
+
+async fn call_vote_encrypted(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let selected: Vec<u16> = match args.map(|arg| arg.parse::<u16>()).collect() {
+        Ok(selected) => selected,
+        Err(e) => {println!("Error parsing candidate id: {}", e); return;}
+    };
+    interact.vote_encrypted(election_id, selected).await;
+}
+
+async fn call_get_encrypted_tally(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id_and_candidate_id(&mut args, |election_id, candidate_id, _| interact.encrypted_tally(election_id, candidate_id)).await;
+}
+
+async fn call_post_decryption_shares(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let secret = match get_hex(&mut args) {
+        Ok(secret) => secret,
+        Err(e) => {println!("Error parsing secret key share: {}", e); return;}
+    };
+    interact.post_decryption_shares(election_id, secret).await;
+}
+
+async fn call_get_decrypting_trustees(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.decrypting_trustees(election_id)).await;
+}
+
+async fn call_publish_tally(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.publish_tally(election_id)).await;
+}
+
+async fn call_vote_staked(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let token_id = match args.next() {
+        Some(token_id) => token_id,
+        None => {println!("token identifier required"); return;}
+    };
+    let amount = match get_biguint(&mut args) {
+        Ok(amount) => amount,
+        Err(e) => {println!("Error parsing stake amount: {}", e); return;}
+    };
+
+    let mut choices = Vec::new();
+    for arg in args {
+        match arg.parse::<u16>() {
+            Ok(choice) => choices.push(choice),
+            Err(e) => {
+                println!("Error parsing vote: {}", e);
+                return;
+            }
+        }
+    }
+
+    interact.vote_staked(election_id, choices, token_id, amount).await;
+}
+
+async fn call_vote_rated_staked(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let token_id = match args.next() {
+        Some(token_id) => token_id,
+        None => {println!("token identifier required"); return;}
+    };
+    let amount = match get_biguint(&mut args) {
+        Ok(amount) => amount,
+        Err(e) => {println!("Error parsing stake amount: {}", e); return;}
+    };
+
+    // each rating is given as <candidate_id>:<rating>
+    let mut ratings = Vec::new();
+    for arg in args {
+        let rating = arg.split_once(':').and_then(|(candidate, rating)| {
+            Some((candidate.parse::<u16>().ok()?, rating.parse::<i64>().ok()?))
+        });
+        match rating {
+            Some(rating) => ratings.push(rating),
+            None => {
+                println!("Error parsing rating {}; format: <candidate_id>:<rating>", arg);
+                return;
+            }
+        }
+    }
+
+    interact.vote_rated_staked(election_id, ratings, token_id, amount).await;
+}
+
+async fn call_get_voting_token(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.voting_token(election_id)).await;
+}
+
+async fn call_set_voting_token(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let token_id = match args.next() {
+        Some(token_id) => token_id,
+        None => {println!("token identifier required"); return;}
+    };
+    interact.set_voting_token(election_id, token_id).await;
+}
+
+async fn call_get_stake(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+    let voter_address = match args.next() {
+        Some(voter_address) => voter_address,
+        None => {println!("voter address required"); return;}
+    };
+    interact.stake(election_id, voter_address).await;
+}
+
+async fn call_withdraw_stake(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.withdraw_stake(election_id)).await;
+}
+
+async fn call_end_election(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    call_on_election_id(&mut args, |election_id, _| interact.end_election(election_id)).await;
+}
+
+async fn call_make_dispute(interact: &mut ContractInteract, mut args: std::str::SplitWhitespace<'_>) {
+    
+
+    let election_id = match get_value::<u64>(&mut args) {
+        Ok(election_id) => election_id,
+        Err(e) => {println!("Error parsing election id: {}", e); return;}
+    };
+
+    let dispute_name = match args.next() {
+        Some(dispute_name) => dispute_name,
+        None => {println!("dispute name required"); return;}
+    };
+    
+    let dispute_description = match args.next() {
+        Some(dispute_description) => dispute_description,
+        None => {println!("dispute description required"); return;}
+    };
+
+    
+    interact.make_dispute(election_id, dispute_name, dispute_description).await;
+}
+
+
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct State {
+    contract_address: Option<Bech32Address>
+}
+
+impl State {
+        // Deserializes state from file
+        pub fn load_state() -> Self {
+            if Path::new(STATE_FILE).exists() {
+                let mut file = std::fs::File::open(STATE_FILE).unwrap();
+                let mut content = String::new();
+                file.read_to_string(&mut content).unwrap();
+                toml::from_str(&content).unwrap()
+            } else {
+                Self::default()
+            }
+        }
+    
+        /// Sets the contract address
+        pub fn set_address(&mut self, address: Bech32Address) {
+            self.contract_address = Some(address);
+        }
+    
+        /// Returns the contract address
+        pub fn current_address(&self) -> &Bech32Address {
+            self.contract_address
+                .as_ref()
+                .expect("no known contract, deploy first")
+        }
+    }
+    
+    impl Drop for State {
+        // Serializes state to file
+        fn drop(&mut self) {
+            let mut file = std::fs::File::create(STATE_FILE).unwrap();
+            file.write_all(toml::to_string(self).unwrap().as_bytes())
+                .unwrap();
+        }
+    }
+
+pub struct ContractInteract {
+    interactor: Interactor,
+    wallet_address: Address,
+    contract_code: BytesValue,
+    state: State
+}
+
+impl ContractInteract {
+    pub async fn new() -> Self {
+        let config = Config::new();
+        let mut interactor = Interactor::new(config.gateway_uri())
+            .await
+            .use_chain_simulator(config.use_chain_simulator());
+
+        interactor.set_current_dir_from_workspace("backendsc");
+        let pem_path = if cfg!(target_family = "windows") {
+            Path::new(r"E:\Facultate\master\an1\BPDA\lab\intro\new_wallet.pem")
+        } else {
+            Path::new(r"/mnt/e/Facultate/master/an1/BPDA/lab/intro/new_wallet.pem")
+        };
+        let pem = std::fs::read_to_string(pem_path).expect("Failed to read PEM file");
+        let wallet = Wallet::from_pem_file_contents(pem).expect("Invalid PEM file");
+        let wallet_address = interactor.register_wallet(wallet.clone()).await;
+        // Useful in the chain simulator setting
+        // generate blocks until ESDTSystemSCAddress is enabled
+        interactor.generate_blocks_until_epoch(1).await.unwrap();
+        
+        let contract_code = BytesValue::interpret_from(
+            "mxsc:../output/backendsc.mxsc.json",
+            &InterpreterContext::default(),
+        );
+
+        ContractInteract {
+            interactor,
+            wallet_address,
+            contract_code,
+            state: State::load_state()
+        }
+    }
+
+    pub async fn deploy(&mut self, candidate_fee: BigUint<StaticApi>) {
+
+        let new_address = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .gas(300_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .init(candidate_fee)
+            .code(&self.contract_code)
+            .returns(ReturnsNewAddress)
+            .run()
+            .await;
+        let new_address_bech32 = bech32::encode(&new_address);
+        self.state
+            .set_address(Bech32Address::from_bech32_string(new_address_bech32.clone()));
+
+        println!("new address: {new_address_bech32}");
+    }
+
+    pub async fn upgrade(&mut self) {
+        let response = self
+            .interactor
+            .tx()
+            .to(self.state.current_address())
+            .from(&self.wallet_address)
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .upgrade()
+            .code(&self.contract_code)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsNewAddress)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn candidate_fee(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .candidate_fee()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn update_candidate_fee(&mut self, candidate_fee: BigUint<StaticApi>) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .update_candidate_fee(candidate_fee)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn election_id_list(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .election_id_list()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn election_data(&mut self, election_id: u64) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .election_data(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn registered_voters(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .registered_voters(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn potential_candidate_id_list(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .potential_candidate_id_list(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn candidate_id_list(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .candidate_id_list(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn candidate(&mut self, election_id: u64, candidate_id: u16) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .candidate(election_id, candidate_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn votes(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_votes(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn ballot_count(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_ballot_count(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn ballots(&mut self, election_id: u64, from: u64, count: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_ballots(election_id, from, count)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn verify_receipt(&mut self, election_id: u64, ballot_id: u64, salt: [u8; 32]) {
+        let salt = ManagedByteArray::<StaticApi, 32>::new_from_bytes(&salt);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .verify_receipt(election_id, ballot_id, salt)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        match result_value {
+            proxy::ReceiptStatus::Counted => println!("Ballot {ballot_id} is in the ledger of election {election_id}, matches the receipt and is counted"),
+            proxy::ReceiptStatus::Superseded => println!("Ballot {ballot_id} is in the ledger of election {election_id} and matches the receipt, but was replaced by a later ballot and is not counted"),
+            proxy::ReceiptStatus::Invalid => println!("The receipt does not match any ballot of election {election_id}"),
+        }
+    }
+
+    pub async fn ballot(&mut self, election_id: u64, ballot_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_ballot(election_id, ballot_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn dispute_id_list(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .dispute_id_list(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn dispute(&mut self, election_id: u64, dispute_id: u16) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .dispute(election_id, dispute_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn pairwise_matrix(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_pairwise_matrix(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn result_vector(&mut self, election_id: u64, candidate_id: u16) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .result_vector(election_id, candidate_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn result_average(&mut self, election_id: u64, candidate_id: u16) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .result_average(election_id, candidate_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn results(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .results(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn ranking(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .ranking(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn locked_pairs(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .locked_pairs(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn stv_rounds(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .stv_rounds(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn star_result(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .star_result(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn election_list(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .election_list()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn get_tie_breaks(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_tie_breaks(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn cast_tie_breaking_vote(&mut self, election_id: u64, candidate_id: u16) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .cast_tie_breaking_vote(election_id, candidate_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn blank_ballots(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .blank_ballots(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn blank_ballots_allowed(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .blank_ballots_allowed(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_blank_ballots_allowed(&mut self, election_id: u64, allowed: bool) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_blank_ballots_allowed(election_id, allowed)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn revoting_allowed(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .revoting_allowed(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_revoting_allowed(&mut self, election_id: u64, allowed: bool) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_revoting_allowed(election_id, allowed)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn superseded_ballots(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .superseded_ballots(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn issue_voter_credential(&mut self, name: &str, ticker: &str) {
+        // the issue cost of a token
+        let issue_cost = BigUint::<StaticApi>::from(50_000_000_000_000_000u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(100_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .issue_voter_credential(ManagedBuffer::new_from_bytes(name.as_bytes()), ManagedBuffer::new_from_bytes(ticker.as_bytes()))
+            .egld(issue_cost)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_voter_credential_roles(&mut self) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(100_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_voter_credential_roles()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn voter_credential_token(&mut self) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .voter_credential()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn credential_required(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .credential_required(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_credential_required(&mut self, election_id: u64, required: bool) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_credential_required(election_id, required)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn revoke_voter_credential(&mut self, election_id: u64, voter_address: &str) {
+        let voter_address = bech32::decode(voter_address);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .revoke_voter_credential(election_id, voter_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn revoked_voters(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .revoked_voters(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        for voter_address in result_value.into_vec() {
+            println!("{}", bech32::encode(&voter_address));
+        }
+    }
+
+    pub async fn voter(&mut self, election_id: u64, voter_address: &str) {
+        let voter_address = bech32::decode(voter_address);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_voter(election_id, voter_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    /// Returns the voter credential of the wallet to send with its vote, if the election uses voter credentials.
+    async fn voter_credential_payment(&mut self, election_id: u64) -> MultiEsdtPayment<StaticApi> {
+        let voter = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_voter(election_id, &self.wallet_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let mut payments = MultiEsdtPayment::new();
+        if let OptionalValue::Some(voter) = voter {
+            if voter.credential_nonce > 0 {
+                payments.push(EsdtTokenPayment::new(voter.token, voter.credential_nonce, BigUint::from(1u64)));
+            }
+        }
+        payments
+    }
+
+    pub async fn delegation_allowed(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .delegation_allowed(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_delegation_allowed(&mut self, election_id: u64, allowed: bool) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_delegation_allowed(election_id, allowed)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn delegate_vote(&mut self, election_id: u64, delegate: &str) {
+        let delegate = bech32::decode(delegate);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .delegate_vote(election_id, delegate)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn revoke_delegation(&mut self, election_id: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .revoke_delegation(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn delegators(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .delegators(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        for delegator in result_value.into_vec() {
+            println!("{}", bech32::encode(&delegator));
+        }
+    }
+
+    pub async fn delegation_chain(&mut self, election_id: u64, voter_address: &str) {
+        let voter_address = bech32::decode(voter_address);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_delegation_chain(election_id, voter_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let chain: Vec<String> = result_value.into_vec().iter().map(bech32::encode).collect();
+        println!("{}", chain.join(" -> "));
+    }
+
+    pub async fn effective_weight(&mut self, election_id: u64, voter_address: &str) {
+        let voter_address = bech32::decode(voter_address);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_effective_weight(election_id, voter_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_none_of_the_above(&mut self, election_id: u64, enabled: bool) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_none_of_the_above(election_id, enabled)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn rerun_election(&mut self, election_id: u64, start_time: u64, end_time: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .rerun_election(election_id, start_time, end_time)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn register_election(&mut self, name: &str, description: &str, election_type: u64, start_time: u64, end_time: u64, tie_break_policy: Option<u64>) {
+        //let name = ManagedBuffer::new_from_bytes(&b""[..]);
+        //let description = ManagedBuffer::new_from_bytes(&b""[..]);
+
+
+        let name = ManagedBuffer::new_from_bytes(name.as_bytes());
+        let description = ManagedBuffer::new_from_bytes(description.as_bytes());
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .register_election(name, description, election_type, start_time, end_time, OptionalValue::from(tie_break_policy))
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn rating_scale(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .rating_scale(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_rating_scale(&mut self, election_id: u64, min: u64, max: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_rating_scale(election_id, min, max)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn set_seats(&mut self, election_id: u64, seats: u32) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_seats(election_id, seats)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn point_budget(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .point_budget(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_point_budget(&mut self, election_id: u64, budget: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_point_budget(election_id, budget)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn voice_credit_budget(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .voice_credit_budget(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_voice_credit_budget(&mut self, election_id: u64, budget: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_voice_credit_budget(election_id, budget)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn voice_credits_spent(&mut self, election_id: u64, voter_address: &str) {
+        let voter_address = bech32::decode(voter_address);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .voice_credits_spent(election_id, voter_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn net_votes(&mut self, election_id: u64, candidate_id: u16) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .net_votes(election_id, candidate_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn grades(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .grades(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_grades(&mut self, election_id: u64, grades: Vec<&str>) {
+        let grades = MultiValueVec::from(grades.iter().map(|grade| ManagedBuffer::<StaticApi>::new_from_bytes(grade.as_bytes())).collect::<Vec<_>>());
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_grades(election_id, grades)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn grade_results(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .grade_results(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn runoff_schedule(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .runoff_schedule(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_runoff_schedule(&mut self, election_id: u64, start_time: u64, end_time: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_runoff_schedule(election_id, start_time, end_time)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn ballot_rules(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .ballot_rules(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_ballot_rules(&mut self, election_id: u64, min_selections: u32, max_selections: u32, full_ranking: bool) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_ballot_rules(election_id, min_selections, max_selections, full_ranking)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn party_id_list(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .party_id_list(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn party(&mut self, election_id: u64, party_id: u16) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .party(election_id, party_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn party_list(&mut self, election_id: u64, party_id: u16) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .party_list(election_id, party_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn party_list_rules(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .party_list_rules(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_party_list_rules(&mut self, election_id: u64, method: u64, threshold_percent: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_party_list_rules(election_id, method, threshold_percent)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn party_results(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .party_results(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn register_party(&mut self, election_id: u64, name: &str) {
+        let name = ManagedBuffer::new_from_bytes(name.as_bytes());
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .register_party(election_id, name)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn add_candidate_to_party(&mut self, election_id: u64, party_id: u16, candidate_id: u16) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .add_candidate_to_party(election_id, party_id, candidate_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn referendum_rules(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .referendum_rules(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_referendum_rules(&mut self, election_id: u64, quorum_percent: u64, threshold_numerator: u64, threshold_denominator: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_referendum_rules(election_id, quorum_percent, threshold_numerator, threshold_denominator)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn referendum_result(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .referendum_result(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn submit_candidancy(&mut self, election_id: u64, name: &str, description: &str, egld_amount: BigUint<StaticApi>) {
+
+        let name = ManagedBuffer::new_from_bytes(name.as_bytes());
+        let description = ManagedBuffer::new_from_bytes(description.as_bytes());
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .submit_candidancy(election_id, name, description)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
 
-    
-    interact.make_dispute(election_id, dispute_name, dispute_description).await;
-}
+        println!("Result: {response:?}");
+    }
 
+    pub async fn register_candidate(&mut self, election_id: u64, candidate_id: u16) {
 
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .register_candidate(election_id, candidate_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
 
-#[derive(Debug, Default, Serialize, Deserialize)]
-pub struct State {
-    contract_address: Option<Bech32Address>
-}
+        println!("Result: {response:?}");
+    }
 
-impl State {
-        // Deserializes state from file
-        pub fn load_state() -> Self {
-            if Path::new(STATE_FILE).exists() {
-                let mut file = std::fs::File::open(STATE_FILE).unwrap();
-                let mut content = String::new();
-                file.read_to_string(&mut content).unwrap();
-                toml::from_str(&content).unwrap()
-            } else {
-                Self::default()
-            }
-        }
-    
-        /// Sets the contract address
-        pub fn set_address(&mut self, address: Bech32Address) {
-            self.contract_address = Some(address);
-        }
-    
-        /// Returns the contract address
-        pub fn current_address(&self) -> &Bech32Address {
-            self.contract_address
-                .as_ref()
-                .expect("no known contract, deploy first")
-        }
+    pub async fn register_self(&mut self, election_id: u64, verification_data: &str) {
+        let verification_data = ManagedBuffer::new_from_bytes(verification_data.as_bytes());
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .register_self(election_id, verification_data)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
     }
-    
-    impl Drop for State {
-        // Serializes state to file
-        fn drop(&mut self) {
-            let mut file = std::fs::File::create(STATE_FILE).unwrap();
-            file.write_all(toml::to_string(self).unwrap().as_bytes())
-                .unwrap();
-        }
+
+    pub async fn register_voter(&mut self, election_id: u64, voter_address: &str) {
+        let voter_address = bech32::decode(voter_address);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .register_voter(election_id, voter_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
     }
 
-pub struct ContractInteract {
-    interactor: Interactor,
-    wallet_address: Address,
-    contract_code: BytesValue,
-    state: State
-}
+    pub async fn vote(&mut self, election_id: u64, vote: Vec<u16>) {
+        let vote = MultiValueVec::from(vote);
+        let payments = self.voter_credential_payment(election_id).await;
 
-impl ContractInteract {
-    pub async fn new() -> Self {
-        let config = Config::new();
-        let mut interactor = Interactor::new(config.gateway_uri())
-            .await
-            .use_chain_simulator(config.use_chain_simulator());
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .vote(election_id, vote)
+            .payment(payments)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
 
-        interactor.set_current_dir_from_workspace("backendsc");
-        let pem_path = if cfg!(target_family = "windows") {
-            Path::new(r"E:\Facultate\master\an1\BPDA\lab\intro\new_wallet.pem")
-        } else {
-            Path::new(r"/mnt/e/Facultate/master/an1/BPDA/lab/intro/new_wallet.pem")
-        };
-        let pem = std::fs::read_to_string(pem_path).expect("Failed to read PEM file");
-        let wallet = Wallet::from_pem_file_contents(pem).expect("Invalid PEM file");
-        let wallet_address = interactor.register_wallet(wallet.clone()).await;
-        // Useful in the chain simulator setting
-        // generate blocks until ESDTSystemSCAddress is enabled
-        interactor.generate_blocks_until_epoch(1).await.unwrap();
-        
-        let contract_code = BytesValue::interpret_from(
-            "mxsc:../output/backendsc.mxsc.json",
-            &InterpreterContext::default(),
+        println!("Result: {response:?}");
+    }
+
+    pub async fn vote_staked(&mut self, election_id: u64, vote: Vec<u16>, token_id: &str, amount: BigUint<StaticApi>) {
+        let vote = MultiValueVec::from(vote);
+        let mut payments = self.voter_credential_payment(election_id).await;
+        payments.push(EsdtTokenPayment::new(TokenIdentifier::from(token_id), 0u64, amount));
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .vote(election_id, vote)
+            .payment(payments)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn vote_rated_staked(&mut self, election_id: u64, ratings: Vec<(u16, i64)>, token_id: &str, amount: BigUint<StaticApi>) {
+        let ratings = MultiValueVec::from(
+            ratings
+                .into_iter()
+                .map(MultiValue2::from)
+                .collect::<Vec<_>>(),
         );
+        let mut payments = self.voter_credential_payment(election_id).await;
+        payments.push(EsdtTokenPayment::new(TokenIdentifier::from(token_id), 0u64, amount));
 
-        ContractInteract {
-            interactor,
-            wallet_address,
-            contract_code,
-            state: State::load_state()
-        }
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .vote_rated(election_id, ratings)
+            .payment(payments)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
     }
 
-    pub async fn deploy(&mut self, candidate_fee: BigUint<StaticApi>) {
+    pub async fn voting_token(&mut self, election_id: u64) {
 
-        let new_address = self
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .voting_token(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_voting_token(&mut self, election_id: u64, token_id: &str) {
+
+        let response = self
             .interactor
             .tx()
             .from(&self.wallet_address)
-            .gas(300_000_000u64)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .init(candidate_fee)
-            .code(&self.contract_code)
-            .returns(ReturnsNewAddress)
+            .set_voting_token(election_id, TokenIdentifier::from(token_id))
+            .returns(ReturnsResultUnmanaged)
             .run()
             .await;
-        let new_address_bech32 = bech32::encode(&new_address);
-        self.state
-            .set_address(Bech32Address::from_bech32_string(new_address_bech32.clone()));
 
-        println!("new address: {new_address_bech32}");
+        println!("Result: {response:?}");
+    }
+
+    pub async fn stake(&mut self, election_id: u64, voter_address: &str) {
+        let voter_address = bech32::decode(voter_address);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .stake(election_id, voter_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn withdraw_stake(&mut self, election_id: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .withdraw_stake(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn reveal_period(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .reveal_period(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    pub async fn set_reveal_period(&mut self, election_id: u64, reveal_period: u64) {
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .set_reveal_period(election_id, reveal_period)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
     }
 
-    pub async fn upgrade(&mut self) {
+    pub async fn commit_vote(&mut self, election_id: u64, hash: [u8; 32]) {
+        let hash = ManagedByteArray::<StaticApi, 32>::new_from_bytes(&hash);
+        let payments = self.voter_credential_payment(election_id).await;
+
         let response = self
             .interactor
             .tx()
-            .to(self.state.current_address())
             .from(&self.wallet_address)
+            .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .upgrade()
-            .code(&self.contract_code)
-            .code_metadata(CodeMetadata::UPGRADEABLE)
-            .returns(ReturnsNewAddress)
+            .commit_vote(election_id, hash)
+            .payment(payments)
+            .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
         println!("Result: {response:?}");
     }
 
-    pub async fn candidate_fee(&mut self) {
-        let result_value = self
+    pub async fn reveal_vote(&mut self, election_id: u64, salt: &[u8; 32], vote: Vec<u16>) {
+        let salt = ManagedByteArray::<StaticApi, 32>::new_from_bytes(salt);
+        let vote = MultiValueVec::from(vote);
+
+        let response = self
             .interactor
-            .query()
+            .tx()
+            .from(&self.wallet_address)
             .to(self.state.current_address())
+            .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .candidate_fee()
+            .reveal_vote(election_id, salt, vote)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {result_value:?}");
+        println!("Result: {response:?}");
     }
 
-    pub async fn update_candidate_fee(&mut self, candidate_fee: BigUint<StaticApi>) {
+    pub async fn reveal_vote_rated(&mut self, election_id: u64, salt: &[u8; 32], ratings: Vec<(u16, i64)>) {
+        let salt = ManagedByteArray::<StaticApi, 32>::new_from_bytes(salt);
+        let ratings = MultiValueVec::from(
+            ratings
+                .into_iter()
+                .map(MultiValue2::from)
+                .collect::<Vec<_>>(),
+        );
+
         let response = self
             .interactor
             .tx()
@@ -471,7 +3101,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .update_candidate_fee(candidate_fee)
+            .reveal_vote_rated(election_id, salt, ratings)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -479,13 +3109,15 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    pub async fn election_id_list(&mut self) {
+    pub async fn commitment(&mut self, election_id: u64, voter_address: &str) {
+        let voter_address = bech32::decode(voter_address);
+
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::BackendScProxy)
-            .election_id_list()
+            .commitment(election_id, voter_address)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -493,13 +3125,14 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn election_data(&mut self, election_id: u64) {
+    pub async fn unrevealed_voters(&mut self, election_id: u64) {
+
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::BackendScProxy)
-            .election_data(election_id)
+            .unrevealed_voters(election_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -507,59 +3140,76 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn registered_voters(&mut self, election_id: u64) {
+    pub async fn vote_rated(&mut self, election_id: u64, ratings: Vec<(u16, i64)>) {
+        let ratings = MultiValueVec::from(
+            ratings
+                .into_iter()
+                .map(MultiValue2::from)
+                .collect::<Vec<_>>(),
+        );
+        let payments = self.voter_credential_payment(election_id).await;
 
-        let result_value = self
+        let response = self
             .interactor
-            .query()
+            .tx()
+            .from(&self.wallet_address)
             .to(self.state.current_address())
+            .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .registered_voters(election_id)
+            .vote_rated(election_id, ratings)
+            .payment(payments)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {result_value:?}");
+        println!("Result: {response:?}");
     }
 
-    pub async fn potential_candidate_id_list(&mut self, election_id: u64) {
+    pub async fn end_election(&mut self, election_id: u64) {
 
-        let result_value = self
+        let response = self
             .interactor
-            .query()
+            .tx()
+            .from(&self.wallet_address)
             .to(self.state.current_address())
+            .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .potential_candidate_id_list(election_id)
+            .end_election(election_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {result_value:?}");
+        println!("Result: {response:?}");
     }
 
-    pub async fn candidate_id_list(&mut self, election_id: u64) {
+    pub async fn make_dispute(&mut self, election_id: u64, dispute_name: &str, dispute_description: &str) {
 
-        let result_value = self
+        let dispute_name = ManagedBuffer::new_from_bytes(dispute_name.as_bytes());
+        let dispute_description = ManagedBuffer::new_from_bytes(dispute_description.as_bytes());
+
+        let response = self
             .interactor
-            .query()
+            .tx()
+            .from(&self.wallet_address)
             .to(self.state.current_address())
+            .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .candidate_id_list(election_id)
+            .make_dispute(election_id, dispute_name, dispute_description)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {result_value:?}");
+        println!("Result: {response:?}");
     }
 
-    pub async fn candidate(&mut self, election_id: u64, candidate_id: u16) {
+    pub async fn trustees(&mut self, election_id: u64) {
 
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::BackendScProxy)
-            .candidate(election_id, candidate_id)
+            .trustees(election_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -567,44 +3217,107 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn votes(&mut self, election_id: u64) {
+    pub async fn set_trustees(&mut self, election_id: u64, threshold: usize, trustees: Vec<&str>) {
+        let trustees = MultiValueVec::from(trustees.into_iter().map(bech32::decode).collect::<Vec<_>>());
 
-        let result_value = self
+        let response = self
             .interactor
-            .query()
+            .tx()
+            .from(&self.wallet_address)
             .to(self.state.current_address())
+            .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .votes(election_id)
+            .set_trustees(election_id, threshold, trustees)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {result_value:?}");
+        println!("Result: {response:?}");
     }
 
-    pub async fn dispute_id_list(&mut self, election_id: u64) {
+    pub async fn trustee_key(&mut self, election_id: u64, trustee: &str) {
+        let trustee = bech32::decode(trustee);
 
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::BackendScProxy)
-            .dispute_id_list(election_id)
+            .trustee_key(election_id, trustee)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {result_value:?}");
+        println!("Result: {result_value:x}");
     }
 
-    pub async fn dispute(&mut self, election_id: u64, dispute_id: u16) {
+    /// Registers the public key of the wallet as a trustee of the election, with the proof of knowledge of its secret key
+    /// and the commitments to the coefficients of its secret polynomial. A new polynomial is generated if no coefficients
+    /// are given. Prints the key share of every trustee, to be sent to them privately (see `trustee combine-key-shares`).
+    pub async fn register_trustee_key(&mut self, election_id: u64, coefficients: Option<Vec<num_bigint::BigUint>>) {
+        let trustees: Vec<Address> = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .trustees(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+            .into_vec();
+        let key = match coefficients {
+            Some(coefficients) => elgamal::trustee_key(election_id, self.wallet_address.as_array(), coefficients),
+            None => {
+                let threshold = self
+                    .interactor
+                    .query()
+                    .to(self.state.current_address())
+                    .typed(proxy::BackendScProxy)
+                    .trustee_threshold(election_id)
+                    .returns(ReturnsResultUnmanaged)
+                    .run()
+                    .await;
+                let key = elgamal::generate_key(election_id, self.wallet_address.as_array(), threshold);
+                for coefficient in key.coefficients.iter() {
+                    println!("Secret coefficient: {coefficient:x}");
+                }
+                key
+            },
+        };
+        for (i, trustee) in trustees.iter().enumerate() {
+            let index = i as u64 + 1;
+            println!("Key share for trustee {index} ({}): {:x}", bech32::encode(trustee), elgamal::key_share(&key.coefficients, index));
+        }
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(100_000_000u64)
+            .typed(proxy::BackendScProxy)
+            .register_trustee_key(
+                election_id,
+                to_managed(&key.public_key),
+                to_managed(&key.commitment),
+                to_managed(&key.response),
+                MultiValueVec::from(key.coefficient_commitments.iter().map(to_managed).collect::<Vec<_>>()),
+            )
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn trustee_threshold(&mut self, election_id: u64) {
 
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::BackendScProxy)
-            .dispute(election_id, dispute_id)
+            .trustee_threshold(election_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -612,66 +3325,97 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn result_vector(&mut self, election_id: u64, candidate_id: u16) {
+    pub async fn coefficient_commitments(&mut self, election_id: u64, trustee: &str) {
+        let trustee = bech32::decode(trustee);
 
-        let result_value = self
+        let result_value: Vec<num_bigint::BigUint> = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::BackendScProxy)
-            .result_vector(election_id, candidate_id)
+            .coefficient_commitments(election_id, trustee)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {result_value:?}");
+        for commitment in result_value {
+            println!("Result: {commitment:x}");
+        }
     }
 
-    pub async fn results(&mut self, election_id: u64) {
+    pub async fn trustee_verification_key(&mut self, election_id: u64, trustee: &str) {
+        let trustee = bech32::decode(trustee);
 
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::BackendScProxy)
-            .results(election_id)
+            .trustee_verification_key(election_id, trustee)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {result_value:?}");
+        println!("Result: {result_value:x}");
     }
 
-    pub async fn election_list(&mut self) {
+    pub async fn joint_public_key(&mut self, election_id: u64) {
+
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(proxy::BackendScProxy)
-            .election_list()
+            .joint_public_key(election_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {result_value:?}");
+        println!("Result: {result_value:x}");
     }
 
-    pub async fn register_election(&mut self, name: &str, description: &str, election_type: u64, start_time: u64, end_time: u64) {
-        //let name = ManagedBuffer::new_from_bytes(&b""[..]);
-        //let description = ManagedBuffer::new_from_bytes(&b""[..]);
-
+    /// Encrypts a ballot selecting the given candidates to the joint public key of the trustees and casts it.
+    pub async fn vote_encrypted(&mut self, election_id: u64, selected: Vec<u16>) {
+        let candidates = self.candidate_ids(election_id).await;
+        if let Some(candidate_id) = selected.iter().find(|candidate_id| !candidates.contains(candidate_id)) {
+            println!("Invalid candidate: {candidate_id}");
+            return;
+        }
+        let joint_key = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .joint_public_key(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
 
-        let name = ManagedBuffer::new_from_bytes(name.as_bytes());
-        let description = ManagedBuffer::new_from_bytes(description.as_bytes());
+        let selections: Vec<bool> = candidates.iter().map(|candidate_id| selected.contains(candidate_id)).collect();
+        let ballot = elgamal::encrypt_ballot(election_id, self.wallet_address.as_array(), &joint_key, &selections);
+        let choices: ManagedVec<StaticApi, proxy::EncryptedChoice<StaticApi>> = ManagedVec::from(
+            candidates
+                .iter()
+                .zip(ballot.choices.iter())
+                .map(|(candidate_id, (ciphertext, proof))| proxy::EncryptedChoice {
+                    candidate: *candidate_id,
+                    ciphertext: proxy::Ciphertext { a: to_managed(&ciphertext.a), b: to_managed(&ciphertext.b) },
+                    proof: to_managed_proof(proof),
+                })
+                .collect::<Vec<_>>(),
+        );
+        let sum_proof = OptionalValue::from(ballot.sum_proof.as_ref().map(to_managed_proof));
+        let payments = self.voter_credential_payment(election_id).await;
 
         let response = self
             .interactor
             .tx()
             .from(&self.wallet_address)
             .to(self.state.current_address())
-            .gas(30_000_000u64)
+            .gas(600_000_000u64)
             .typed(proxy::BackendScProxy)
-            .register_election(name, description, election_type, start_time, end_time)
+            .vote_encrypted(election_id, choices, sum_proof)
+            .payment(payments)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -679,20 +3423,35 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    pub async fn submit_candidancy(&mut self, election_id: u64, name: &str, description: &str, egld_amount: BigUint<StaticApi>) {
+    pub async fn encrypted_tally(&mut self, election_id: u64, candidate_id: u16) {
+        let tally = self.encrypted_tally_value(election_id, candidate_id).await;
+        println!("Result: a: {:x}, b: {:x}", tally.a, tally.b);
+    }
 
-        let name = ManagedBuffer::new_from_bytes(name.as_bytes());
-        let description = ManagedBuffer::new_from_bytes(description.as_bytes());
+    /// Computes the decryption shares of the wallet for the encrypted tally of every candidate with its share of the
+    /// joint secret key (the sum of the key shares it received) and posts them.
+    pub async fn post_decryption_shares(&mut self, election_id: u64, secret: num_bigint::BigUint) {
+        let mut shares = Vec::new();
+        for candidate_id in self.candidate_ids(election_id).await {
+            let tally = self.encrypted_tally_value(election_id, candidate_id).await;
+            let share = elgamal::decryption_share(election_id, self.wallet_address.as_array(), &secret, &tally.a);
+            shares.push(proxy::DecryptionShare {
+                candidate: candidate_id,
+                share: to_managed(&share.share),
+                t_g: to_managed(&share.t_g),
+                t_a: to_managed(&share.t_a),
+                response: to_managed(&share.response),
+            });
+        }
 
         let response = self
             .interactor
             .tx()
             .from(&self.wallet_address)
             .to(self.state.current_address())
-            .gas(30_000_000u64)
+            .gas(600_000_000u64)
             .typed(proxy::BackendScProxy)
-            .submit_candidancy(election_id, name, description)
-            .egld(egld_amount)
+            .post_decryption_shares(election_id, MultiValueVec::from(shares))
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -700,34 +3459,88 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    pub async fn register_candidate(&mut self, election_id: u64, candidate_id: u16) {
+    pub async fn decrypting_trustees(&mut self, election_id: u64) {
 
-        let response = self
+        let result_value = self
             .interactor
-            .tx()
-            .from(&self.wallet_address)
+            .query()
             .to(self.state.current_address())
-            .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .register_candidate(election_id, candidate_id)
+            .decrypting_trustees(election_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {response:?}");
+        println!("Result: {result_value:?}");
     }
 
-    pub async fn register_self(&mut self, election_id: u64, verification_data: &str) {
-        let verification_data = ManagedBuffer::new_from_bytes(verification_data.as_bytes());
+    /// Decrypts the tally of every candidate from the decryption shares posted by the trustees (their proofs
+    /// were verified by the contract) and publishes it.
+    pub async fn publish_tally(&mut self, election_id: u64) {
+        let decrypting_trustees: Vec<Address> = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .decrypting_trustees(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+            .into_vec();
+        let trustees: Vec<Address> = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .trustees(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+            .into_vec();
+        let turnout = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .election_data(election_id)
+            .returns(ReturnsResult)
+            .run()
+            .await
+            .turnout;
+
+        let mut counts = Vec::new();
+        for candidate_id in self.candidate_ids(election_id).await {
+            let tally = self.encrypted_tally_value(election_id, candidate_id).await;
+            let mut shares = Vec::new();
+            for trustee in decrypting_trustees.iter() {
+                // the position of the trustee in `getTrustees`, from 1
+                let index = trustees.iter().position(|t| t == trustee).unwrap() as u64 + 1;
+                let share = self
+                    .interactor
+                    .query()
+                    .to(self.state.current_address())
+                    .typed(proxy::BackendScProxy)
+                    .decryption_share(election_id, trustee, candidate_id)
+                    .returns(ReturnsResultUnmanaged)
+                    .run()
+                    .await;
+                shares.push((index, share));
+            }
+            match elgamal::decrypt_count(&tally.b, &shares, turnout) {
+                Some(count) => counts.push(MultiValue2::from((candidate_id, count))),
+                None => {println!("Could not decrypt the tally of candidate {candidate_id}"); return;},
+            }
+        }
+        println!("Tally: {counts:?}");
 
         let response = self
             .interactor
             .tx()
             .from(&self.wallet_address)
             .to(self.state.current_address())
-            .gas(30_000_000u64)
+            .gas(300_000_000u64)
             .typed(proxy::BackendScProxy)
-            .register_self(election_id, verification_data)
+            .publish_tally(election_id, MultiValueVec::from(counts))
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -735,26 +3548,49 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    pub async fn register_voter(&mut self, election_id: u64, voter_address: &str) {
-        let voter_address = bech32::decode(voter_address);
+    async fn candidate_ids(&mut self, election_id: u64) -> Vec<u16> {
+        self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .candidate_id_list(election_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await
+            .into_vec()
+    }
 
-        let response = self
+    async fn encrypted_tally_value(&mut self, election_id: u64, candidate_id: u16) -> elgamal::Ciphertext {
+        let tally = self
             .interactor
-            .tx()
-            .from(&self.wallet_address)
+            .query()
             .to(self.state.current_address())
-            .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .register_voter(election_id, voter_address)
+            .get_encrypted_tally(election_id, candidate_id)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+        elgamal::Ciphertext { a: to_unmanaged(&tally.a), b: to_unmanaged(&tally.b) }
+    }
+
+    pub async fn voter_roll(&mut self, election_id: u64) {
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_voter_roll(election_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
-        println!("Result: {response:?}");
+        println!("Result: {result_value:?}");
     }
 
-    pub async fn vote(&mut self, election_id: u64, vote: Vec<u16>) {
-        let vote = MultiValueVec::from(vote);
+    pub async fn set_voter_roll(&mut self, election_id: u64, root: [u8; 32], voters: u64, weighted: bool) {
+        let root = ManagedByteArray::<StaticApi, 32>::new_from_bytes(&root);
 
         let response = self
             .interactor
@@ -763,7 +3599,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .vote(election_id, vote)
+            .set_voter_roll(election_id, root, voters, weighted)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -771,7 +3607,47 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    pub async fn end_election(&mut self, election_id: u64) {
+    pub async fn voter_weight(&mut self, election_id: u64, voter_address: &str) {
+        let voter_address = bech32::decode(voter_address);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .voter_weight(election_id, voter_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    /// Returns the proof of membership of the wallet in a voter roll, checked against the root committed for the election.
+    async fn voter_roll_proof(&mut self, election_id: u64, tree: &voter_roll::VoterRollTree) -> Option<ManagedBuffer<StaticApi>> {
+        let Some(proof) = tree.proof(&self.wallet_address) else {
+            println!("Wallet address is not in the voter roll");
+            return None;
+        };
+        let voter_roll = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::BackendScProxy)
+            .get_voter_roll(election_id)
+            .returns(ReturnsResult)
+            .run()
+            .await
+            .into_option();
+        match voter_roll {
+            Some(voter_roll) if voter_roll.root.to_byte_array() == tree.root() => Some(ManagedBuffer::new_from_bytes(&proof)),
+            Some(_) => {println!("The voter roll does not match the root of the election"); None},
+            None => {println!("Election has no voter roll"); None},
+        }
+    }
+
+    pub async fn register_from_voter_roll(&mut self, election_id: u64, tree: &voter_roll::VoterRollTree) {
+        let Some(verification_data) = self.voter_roll_proof(election_id, tree).await else { return; };
 
         let response = self
             .interactor
@@ -780,7 +3656,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .end_election(election_id)
+            .register_self(election_id, verification_data)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -788,10 +3664,9 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    pub async fn make_dispute(&mut self, election_id: u64, dispute_name: &str, dispute_description: &str) {
-
-        let dispute_name = ManagedBuffer::new_from_bytes(dispute_name.as_bytes());
-        let dispute_description = ManagedBuffer::new_from_bytes(dispute_description.as_bytes());
+    pub async fn vote_with_proof(&mut self, election_id: u64, tree: &voter_roll::VoterRollTree, vote: Vec<u16>) {
+        let Some(verification_data) = self.voter_roll_proof(election_id, tree).await else { return; };
+        let vote = MultiValueVec::from(vote);
 
         let response = self
             .interactor
@@ -800,12 +3675,32 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::BackendScProxy)
-            .make_dispute(election_id, dispute_name, dispute_description)
+            .vote_with_proof(election_id, verification_data, vote)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
 
         println!("Result: {response:?}");
     }
+}
+
+fn to_managed(value: &num_bigint::BigUint) -> BigUint<StaticApi> {
+    BigUint::from_bytes_be(&value.to_bytes_be())
+}
+
+fn to_unmanaged(value: &BigUint<StaticApi>) -> num_bigint::BigUint {
+    num_bigint::BigUint::from_bytes_be(value.to_bytes_be().as_slice())
+}
 
+fn to_managed_proof(proof: &elgamal::BallotProof) -> proxy::BallotProof<StaticApi> {
+    proxy::BallotProof {
+        t_a0: to_managed(&proof.t_a0),
+        t_b0: to_managed(&proof.t_b0),
+        t_a1: to_managed(&proof.t_a1),
+        t_b1: to_managed(&proof.t_b1),
+        c0: to_managed(&proof.c0),
+        c1: to_managed(&proof.c1),
+        s0: to_managed(&proof.s0),
+        s1: to_managed(&proof.s1),
+    }
 }
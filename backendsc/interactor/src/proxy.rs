@@ -142,6 +142,117 @@ where
             .original_result()
     }
 
+    pub fn voter_credential(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoterCredentialToken")
+            .original_result()
+    }
+
+    pub fn credential_required<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCredentialRequired")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn revoked_voters<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRevokedVoters")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    /// Returns the registration of a voter: whether they can still vote and their voter credential. 
+    pub fn get_voter<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        voter_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Voter<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoter")
+            .argument(&election_id)
+            .argument(&voter_address)
+            .original_result()
+    }
+
+    pub fn roll_leaf_used<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        election_id: Arg0,
+        leaf: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRollLeafUsed")
+            .argument(&election_id)
+            .argument(&leaf)
+            .original_result()
+    }
+
+    pub fn roll_registrations<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRollRegistrations")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn voter_weight<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        voter_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoterWeight")
+            .argument(&election_id)
+            .argument(&voter_address)
+            .original_result()
+    }
+
+    /// Returns the voter roll of the election, or nothing if its voters are registered one by one. 
+    pub fn get_voter_roll<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<VoterRoll<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoterRoll")
+            .argument(&election_id)
+            .original_result()
+    }
+
     pub fn potential_candidate_id_list<
         Arg0: ProxyArg<u64>,
     >(
@@ -184,7 +295,8 @@ where
             .original_result()
     }
 
-    pub fn votes<
+    /// Returns the votes of the ballots counted in the election, in the order they were cast. 
+    pub fn get_votes<
         Arg0: ProxyArg<u64>,
     >(
         self,
@@ -197,273 +309,1963 @@ where
             .original_result()
     }
 
-    pub fn dispute_id_list<
+    pub fn get_ballot_count<
         Arg0: ProxyArg<u64>,
     >(
         self,
         election_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u16>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getDisputeIDList")
+            .raw_call("getBallotCount")
             .argument(&election_id)
             .original_result()
     }
 
-    pub fn dispute<
+    /// Returns up to `count` ballots of the election, starting with the ballot `from` (ballot IDs start at 1). 
+    pub fn get_ballots<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<u16>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
     >(
         self,
         election_id: Arg0,
-        dispute_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Dispute<Env::Api>> {
+        from: Arg1,
+        count: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Ballot<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getDispute")
+            .raw_call("getBallots")
             .argument(&election_id)
-            .argument(&dispute_id)
+            .argument(&from)
+            .argument(&count)
             .original_result()
     }
 
-    pub fn result_vector<
+    pub fn get_ballot<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<u16>,
+        Arg1: ProxyArg<u64>,
     >(
         self,
         election_id: Arg0,
-        candidate_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        ballot_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Ballot<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("result_vector")
+            .raw_call("getBallot")
             .argument(&election_id)
-            .argument(&candidate_id)
+            .argument(&ballot_id)
             .original_result()
     }
 
-    /// Determines the winning candidate of an election based on its type. 
-    ///  
-    /// This function verifies that the specified election has ended and then evaluates 
-    /// the votes according to the election type. It supports Plurality, Approval, and  
-    /// Single Transferable Vote election types. The function returns the candidate ID  
-    /// with the highest number of votes. 
-    ///  
-    /// # Arguments 
-    ///  
-    /// * `election_id` - The identifier of the election whose results should be computed. 
-    ///  
-    /// # Returns 
-    ///  
-    /// The candidate ID of the winning candidate. 
-    ///  
-    /// # Panics 
-    ///  
-    /// Panics if the election does not exist or has not ended yet. 
-    pub fn results<
+    /// Checks the salt of a receipt returned by a vote against the ballot with the same ID in the ledger of the 
+    /// election: `Counted` if the receipt hash recomputed from the ballot and the salt is the one stored when the 
+    /// ballot was cast and the ballot counts toward the results, `Superseded` if it is but the voter replaced the 
+    /// ballot with a later one, and `Invalid` otherwise. The salt is only returned to the voter, so the public ledger 
+    /// is not enough to produce a valid receipt. The ledger does not record voters, so the check does not reveal which 
+    /// address cast the ballot (in elections with revoting or delegation, the `current_ballot` storage does). 
+    pub fn verify_receipt<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
     >(
         self,
         election_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u16> {
+        ballot_id: Arg1,
+        salt: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReceiptStatus> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("results")
+            .raw_call("verifyReceipt")
             .argument(&election_id)
+            .argument(&ballot_id)
+            .argument(&salt)
             .original_result()
     }
 
-    pub fn election_list(
+    pub fn blank_ballots_allowed<
+        Arg0: ProxyArg<u64>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ElectionData<Env::Api>>> {
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("electionList")
+            .raw_call("getBlankBallotsAllowed")
+            .argument(&election_id)
             .original_result()
     }
 
-    pub fn register_election<
-        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<u64>,
-        Arg3: ProxyArg<u64>,
-        Arg4: ProxyArg<u64>,
+    pub fn blank_ballots<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        name: Arg0,
-        description: Arg1,
-        election_type: Arg2,
-        start_time: Arg3,
-        end_time: Arg4,
+        election_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("registerElection")
-            .argument(&name)
-            .argument(&description)
-            .argument(&election_type)
-            .argument(&start_time)
-            .argument(&end_time)
+            .raw_call("getBlankBallots")
+            .argument(&election_id)
             .original_result()
     }
 
-    pub fn submit_candidancy<
+    pub fn revoting_allowed<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         election_id: Arg0,
-        name: Arg1,
-        description: Arg2,
-    ) -> TxTypedCall<Env, From, To, (), Gas, u16> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
-            .raw_call("submitCandidancy")
+            .payment(NotPayable)
+            .raw_call("getRevotingAllowed")
             .argument(&election_id)
-            .argument(&name)
-            .argument(&description)
             .original_result()
     }
 
-    pub fn register_candidate<
+    pub fn delegation_allowed<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<u16>,
     >(
         self,
         election_id: Arg0,
-        candidate_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u16> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("registerCandidate")
+            .raw_call("getDelegationAllowed")
             .argument(&election_id)
-            .argument(&candidate_id)
             .original_result()
     }
 
-    pub fn register_self<
+    pub fn delegators<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         election_id: Arg0,
-        verification_data: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("registerSelf")
+            .raw_call("getDelegators")
             .argument(&election_id)
-            .argument(&verification_data)
             .original_result()
     }
 
-    pub fn register_voter<
+    /// Returns the delegation chain of a voter: the voter, then each delegate in turn, up to their representative, 
+    /// the first voter of the chain who voted directly or did not delegate their vote. 
+    pub fn get_delegation_chain<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         election_id: Arg0,
         voter_address: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("registerVoter")
+            .raw_call("getDelegationChain")
             .argument(&election_id)
             .argument(&voter_address)
             .original_result()
     }
 
-    pub fn vote<
+    /// Returns the weight a voter carries in the election: their own weight plus the weights of the voters whose 
+    /// delegation chain ends with them, or 0 if their own vote is delegated. 
+    pub fn get_effective_weight<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<MultiValueEncoded<Env::Api, u16>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         election_id: Arg0,
-        vote: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        voter_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("vote")
+            .raw_call("getEffectiveWeight")
             .argument(&election_id)
-            .argument(&vote)
+            .argument(&voter_address)
             .original_result()
     }
 
-    pub fn end_election<
+    pub fn superseded_ballots<
         Arg0: ProxyArg<u64>,
     >(
         self,
         election_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("endElection")
+            .raw_call("getSupersededBallots")
             .argument(&election_id)
             .original_result()
     }
 
-    pub fn make_dispute<
+    pub fn reveal_period<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         election_id: Arg0,
-        dispute_name: Arg1,
-        dispute_description: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u16> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("makeDispute")
+            .raw_call("getRevealPeriod")
             .argument(&election_id)
-            .argument(&dispute_name)
-            .argument(&dispute_description)
             .original_result()
     }
-}
-
-#[type_abi]
-#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
-pub struct ElectionData<Api>
-where
-    Api: ManagedTypeApi,
-{
-    pub name: ManagedBuffer<Api>,
-    pub description: ManagedBuffer<Api>,
-    pub start_time: u64,
-    pub end_time: u64,
-    pub election_type: ElectionType,
-    pub ended: bool,
-    pub admin: ManagedAddress<Api>,
-}
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
-pub enum ElectionType {
-    #[default]
-    Plurality,
-    Approval,
-    SingleTransferableVote,
-}
+    pub fn commitment<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        voter_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Commitment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCommitment")
+            .argument(&election_id)
+            .argument(&voter_address)
+            .original_result()
+    }
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
-pub struct Candidate<Api>
-where
-    Api: ManagedTypeApi,
-{
-    pub name: ManagedBuffer<Api>,
-    pub description: ManagedBuffer<Api>,
-    pub creator: ManagedAddress<Api>,
-}
+    pub fn unrevealed_voters<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnrevealedVoters")
+            .argument(&election_id)
+            .original_result()
+    }
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
-pub struct Vote<Api>
-where
-    Api: ManagedTypeApi,
+    pub fn trustees<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrustees")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn trustee_threshold<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrusteeThreshold")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn trustee_key<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        trustee: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrusteeKey")
+            .argument(&election_id)
+            .argument(&trustee)
+            .original_result()
+    }
+
+    pub fn coefficient_commitments<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        trustee: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCoefficientCommitments")
+            .argument(&election_id)
+            .argument(&trustee)
+            .original_result()
+    }
+
+    pub fn encrypted_ballots<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedVec<Env::Api, EncryptedChoice<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEncryptedBallots")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn decryption_share<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        trustee: Arg1,
+        candidate_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDecryptionShare")
+            .argument(&election_id)
+            .argument(&trustee)
+            .argument(&candidate_id)
+            .original_result()
+    }
+
+    pub fn decrypting_trustees<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDecryptingTrustees")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn tally_published<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTallyPublished")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    /// Returns the encrypted number of votes of a candidate of an encrypted election. 
+    /// Before the first ballot, it is the trivial encryption of 0: (1, 1). 
+    pub fn get_encrypted_tally<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        candidate_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Ciphertext<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEncryptedTally")
+            .argument(&election_id)
+            .argument(&candidate_id)
+            .original_result()
+    }
+
+    /// Returns the joint public key ballots of an encrypted election are encrypted to: the product of the 
+    /// public keys of all its trustees (mod p). Its secret key is shared among the trustees, so that decrypting 
+    /// requires the shares of `threshold` of them (see `getTrusteeVerificationKey`). 
+    ///  
+    /// # Panics 
+    ///  
+    /// Panics if the election is not encrypted or if a trustee has not registered their key yet. 
+    pub fn joint_public_key<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getJointPublicKey")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    /// Returns the verification key `g^s_j (mod p)` of a trustee of an encrypted election, where `s_j` is their share of 
+    /// the joint secret key: the sum of the values at `j` of the secret polynomials of all the trustees, `j` being 
+    /// the position of the trustee in `getTrustees` (from 1). It is computed from the public keys and coefficient 
+    /// commitments of the trustees: `prod_i (h_i * C_i1^j * C_i2^(j^2) * ... * C_i(t-1)^(j^(t-1)))`. 
+    ///  
+    /// # Panics 
+    ///  
+    /// Panics if the address is not a trustee of the election or if a trustee has not registered their key yet. 
+    pub fn trustee_verification_key<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        trustee: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrusteeVerificationKey")
+            .argument(&election_id)
+            .argument(&trustee)
+            .original_result()
+    }
+
+    pub fn ballot_rules<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BallotRules> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBallotRules")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn voting_token<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVotingToken")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn stake<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        voter_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStake")
+            .argument(&election_id)
+            .argument(&voter_address)
+            .original_result()
+    }
+
+    pub fn rating_scale<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RatingScale> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRatingScale")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn point_budget<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPointBudget")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn voice_credit_budget<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoiceCreditBudget")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn voice_credits_spent<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        voter_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoiceCreditsSpent")
+            .argument(&election_id)
+            .argument(&voter_address)
+            .original_result()
+    }
+
+    pub fn net_votes<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        candidate_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigInt<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNetVotes")
+            .argument(&election_id)
+            .argument(&candidate_id)
+            .original_result()
+    }
+
+    pub fn grades<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGrades")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn runoff_schedule<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RunoffSchedule> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRunoffSchedule")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn party_id_list<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u16>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartyIDs")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn party<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        party_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Party<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getParty")
+            .argument(&election_id)
+            .argument(&party_id)
+            .original_result()
+    }
+
+    pub fn party_list<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        party_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u16>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartyList")
+            .argument(&election_id)
+            .argument(&party_id)
+            .original_result()
+    }
+
+    pub fn candidate_party<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        candidate_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u16> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCandidateParty")
+            .argument(&election_id)
+            .argument(&candidate_id)
+            .original_result()
+    }
+
+    pub fn party_list_rules<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PartyListRules> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartyListRules")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn referendum_rules<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReferendumRules> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferendumRules")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn dispute_id_list<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u16>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDisputeIDList")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn dispute<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        dispute_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Dispute<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDispute")
+            .argument(&election_id)
+            .argument(&dispute_id)
+            .original_result()
+    }
+
+    /// Returns the pairwise preference matrix of an election. 
+    ///  
+    /// Each entry holds the number of votes that rank `candidate` above `opponent`, 
+    /// so that every head-to-head count used by the Schulze method can be audited. 
+    pub fn get_pairwise_matrix<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PairwisePreference>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairwiseMatrix")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn result_vector<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        candidate_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("result_vector")
+            .argument(&election_id)
+            .argument(&candidate_id)
+            .original_result()
+    }
+
+    pub fn result_average<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        candidate_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("result_average")
+            .argument(&election_id)
+            .argument(&candidate_id)
+            .original_result()
+    }
+
+    pub fn star_result<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, StarResult<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStarResult")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn ranking<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u16>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRanking")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn grade_results<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, GradeResult<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGradeResults")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn party_results<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PartyResult<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPartyResults")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn referendum_result<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReferendumResult> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferendumResult")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    /// Returns the ties that decided the results of the election and how they were broken, 
+    /// in the order the count met them (nothing if no tie occurred). 
+    pub fn get_tie_breaks<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TieBreak<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTieBreaks")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn stv_rounds<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, StvRound<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStvRounds")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn locked_pairs<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LockedPair>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLockedPairs")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    /// Determines the winning candidate of an election based on its type. 
+    ///  
+    /// This function verifies that the specified election has ended and then finalises it 
+    /// (see `finalize_election`). The votes are only evaluated once, the final ordering is then stored in `ranking`. 
+    ///  
+    /// # Arguments 
+    ///  
+    /// * `election_id` - The identifier of the election whose results should be computed. 
+    ///  
+    /// # Returns 
+    ///  
+    /// For Ranked Pairs elections, the full ordering of the candidates (winner first). 
+    /// For STAR elections, the winner of the automatic runoff. 
+    /// For Majority Judgment elections, the candidate with the best majority grade. 
+    /// For Single Transferable Vote elections, the elected candidates, in the order they were elected. 
+    /// For Party-List elections, the elected candidates, in the order their seats were allocated. 
+    /// For Referendum elections, `REFERENDUM_YES` if the proposal passed, `REFERENDUM_NO` if it was rejected, 
+    /// and nothing if the quorum was not met (the outcome is detailed in `getReferendumResult`). 
+    /// For Two-Round elections, the winner if they obtained an absolute majority of the votes, 
+    /// otherwise the two candidates of the second round. 
+    /// For the other election types, the candidate ID of the candidate with the highest number 
+    /// of votes (or points, or head-to-head wins for Schulze, or total rating for Score). 
+    /// Ties that decide the results are broken by the election's tie-break policy and reported by `getTieBreaks`; 
+    /// nothing is returned while the admin's casting vote is pending. 
+    /// If "None of the above" (`NONE_OF_THE_ABOVE`) comes first, the election is marked void and can be rerun with `rerunElection`. 
+    ///  
+    /// # Panics 
+    ///  
+    /// Panics if the election does not exist or has not ended yet, or if the tally of an encrypted election has not been published. 
+    pub fn results<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u16>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("results")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    /// Casts the admin's deciding vote on the pending tie of an election (see `break_tie`), then repeats the count. 
+    /// The repeated count finalises the election as `results` does, so if "None of the above" wins the tie, 
+    /// the election is marked void. 
+    pub fn cast_tie_breaking_vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        candidate_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("castTieBreakingVote")
+            .argument(&election_id)
+            .argument(&candidate_id)
+            .original_result()
+    }
+
+    pub fn election_list(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ElectionData<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("electionList")
+            .original_result()
+    }
+
+    pub fn register_election<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        name: Arg0,
+        description: Arg1,
+        election_type: Arg2,
+        start_time: Arg3,
+        end_time: Arg4,
+        tie_break_policy: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerElection")
+            .argument(&name)
+            .argument(&description)
+            .argument(&election_type)
+            .argument(&start_time)
+            .argument(&end_time)
+            .argument(&tie_break_policy)
+            .original_result()
+    }
+
+    pub fn set_rating_scale<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+        min: Arg1,
+        max: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRatingScale")
+            .argument(&election_id)
+            .argument(&min)
+            .argument(&max)
+            .original_result()
+    }
+
+    pub fn set_point_budget<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+        budget: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPointBudget")
+            .argument(&election_id)
+            .argument(&budget)
+            .original_result()
+    }
+
+    pub fn set_voice_credit_budget<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+        budget: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setVoiceCreditBudget")
+            .argument(&election_id)
+            .argument(&budget)
+            .original_result()
+    }
+
+    pub fn set_grades<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        election_id: Arg0,
+        grades: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setGrades")
+            .argument(&election_id)
+            .argument(&grades)
+            .original_result()
+    }
+
+    pub fn set_runoff_schedule<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+        start_time: Arg1,
+        end_time: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRunoffSchedule")
+            .argument(&election_id)
+            .argument(&start_time)
+            .argument(&end_time)
+            .original_result()
+    }
+
+    pub fn set_ballot_rules<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<u32>,
+        Arg3: ProxyArg<bool>,
+    >(
+        self,
+        election_id: Arg0,
+        min_selections: Arg1,
+        max_selections: Arg2,
+        full_ranking: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setBallotRules")
+            .argument(&election_id)
+            .argument(&min_selections)
+            .argument(&max_selections)
+            .argument(&full_ranking)
+            .original_result()
+    }
+
+    pub fn set_voting_token<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setVotingToken")
+            .argument(&election_id)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn set_referendum_rules<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+        quorum_percent: Arg1,
+        threshold_numerator: Arg2,
+        threshold_denominator: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setReferendumRules")
+            .argument(&election_id)
+            .argument(&quorum_percent)
+            .argument(&threshold_numerator)
+            .argument(&threshold_denominator)
+            .original_result()
+    }
+
+    pub fn set_none_of_the_above<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        election_id: Arg0,
+        enabled: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setNoneOfTheAbove")
+            .argument(&election_id)
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn set_reveal_period<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+        reveal_period: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRevealPeriod")
+            .argument(&election_id)
+            .argument(&reveal_period)
+            .original_result()
+    }
+
+    /// Makes the election an encrypted election, whose ballots are ElGamal-encrypted to the joint public key 
+    /// of the given trustees (see `voteEncrypted`), and whose tally is decrypted by any `threshold` of them (t-of-n). 
+    /// Each trustee must then register their key with `registerTrusteeKey` before the election starts. 
+    /// Calling it with no trustees makes the election a plain election again. 
+    pub fn set_trustees<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        election_id: Arg0,
+        threshold: Arg1,
+        trustees: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTrustees")
+            .argument(&election_id)
+            .argument(&threshold)
+            .argument(&trustees)
+            .original_result()
+    }
+
+    /// Registers the ElGamal public key `h = g^a_0 (mod p)` of the calling trustee, with a Schnorr proof that 
+    /// they know the secret key `a_0`: `g^response == commitment * h^c (mod p)`, where `c` is the challenge of the 
+    /// public key and the commitment (see `challenge`). 
+    ///  
+    /// The secret key is the constant coefficient of a secret polynomial `f(x) = a_0 + a_1 x + ... + a_(t-1) x^(t-1) (mod q)` 
+    /// of degree `threshold - 1`, whose other coefficients are committed to with `coefficients`: `g^a_1, ..., g^a_(t-1)`. 
+    /// Off-chain, the trustee sends `f(j)` privately to the trustee at position `j` in `getTrustees` (from 1), who checks it 
+    /// against these commitments: `g^f(j) == h * C_1^j * ... * C_(t-1)^(j^(t-1))`. The sum of the values a trustee receives 
+    /// (their own included) is their share of the joint secret key (see `getTrusteeVerificationKey`). 
+    pub fn register_trustee_key<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, BigUint<Env::Api>>>,
+    >(
+        self,
+        election_id: Arg0,
+        public_key: Arg1,
+        commitment: Arg2,
+        response: Arg3,
+        coefficients: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerTrusteeKey")
+            .argument(&election_id)
+            .argument(&public_key)
+            .argument(&commitment)
+            .argument(&response)
+            .argument(&coefficients)
+            .original_result()
+    }
+
+    /// Commits the Merkle root of the voter roll of the election, so that its voters register themselves with 
+    /// a proof of membership instead of being registered one by one (see `registerSelf` and `voteWithProof`). 
+    /// `voters` is the number of voters in the roll, which counts in the electorate of a referendum until they register. 
+    /// If `weighted` is true, each vote is weighted by the weight of the voter in the roll. 
+    pub fn set_voter_roll<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<bool>,
+    >(
+        self,
+        election_id: Arg0,
+        root: Arg1,
+        voters: Arg2,
+        weighted: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setVoterRoll")
+            .argument(&election_id)
+            .argument(&root)
+            .argument(&voters)
+            .argument(&weighted)
+            .original_result()
+    }
+
+    pub fn set_blank_ballots_allowed<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        election_id: Arg0,
+        allowed: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setBlankBallotsAllowed")
+            .argument(&election_id)
+            .argument(&allowed)
+            .original_result()
+    }
+
+    /// Lets the voters of the election recast their ballot any number of times before `end_time`: each ballot 
+    /// replaces the previous ballot of the voter, which stays in the ledger but is no longer counted. 
+    pub fn set_revoting_allowed<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        election_id: Arg0,
+        allowed: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRevotingAllowed")
+            .argument(&election_id)
+            .argument(&allowed)
+            .original_result()
+    }
+
+    /// Issues the token of the voter credentials (an NFT collection). The issue cost must be paid in EGLD. 
+    /// The roles of the contract must then be set with `setVoterCredentialRoles`. 
+    pub fn issue_voter_credential<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        token_display_name: Arg0,
+        token_ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueVoterCredential")
+            .argument(&token_display_name)
+            .argument(&token_ticker)
+            .original_result()
+    }
+
+    /// Gives the contract the roles to mint and burn voter credentials and makes it the only holder of the transfer 
+    /// role, so that voters cannot transfer their credential, except back to the contract. 
+    pub fn set_voter_credential_roles(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setVoterCredentialRoles")
+            .original_result()
+    }
+
+    /// Requires the voters of the election to hold a voter credential, minted when they register and sent 
+    /// with their vote (see `use_voter_credential`). Voters must not be registered yet. 
+    pub fn set_credential_required<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        election_id: Arg0,
+        required: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCredentialRequired")
+            .argument(&election_id)
+            .argument(&required)
+            .original_result()
+    }
+
+    /// Revokes the voter credential of a voter: they can no longer vote or delegate, and the weight they delegated 
+    /// is no longer counted. A ballot they already cast stays counted. 
+    pub fn revoke_voter_credential<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        voter_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeVoterCredential")
+            .argument(&election_id)
+            .argument(&voter_address)
+            .original_result()
+    }
+
+    /// Lets the registered voters of the election delegate their vote to another registered voter (see `delegateVote`). 
+    pub fn set_delegation_allowed<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        election_id: Arg0,
+        allowed: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDelegationAllowed")
+            .argument(&election_id)
+            .argument(&allowed)
+            .original_result()
+    }
+
+    pub fn rerun_election<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+        start_time: Arg1,
+        end_time: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rerunElection")
+            .argument(&election_id)
+            .argument(&start_time)
+            .argument(&end_time)
+            .original_result()
+    }
+
+    pub fn set_party_list_rules<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+        method: Arg1,
+        threshold_percent: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPartyListRules")
+            .argument(&election_id)
+            .argument(&method)
+            .argument(&threshold_percent)
+            .original_result()
+    }
+
+    pub fn register_party<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        name: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u16> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerParty")
+            .argument(&election_id)
+            .argument(&name)
+            .original_result()
+    }
+
+    pub fn add_candidate_to_party<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+        Arg2: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        party_id: Arg1,
+        candidate_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addCandidateToParty")
+            .argument(&election_id)
+            .argument(&party_id)
+            .argument(&candidate_id)
+            .original_result()
+    }
+
+    pub fn set_seats<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        election_id: Arg0,
+        seats: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSeats")
+            .argument(&election_id)
+            .argument(&seats)
+            .original_result()
+    }
+
+    pub fn submit_candidancy<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        name: Arg1,
+        description: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u16> {
+        self.wrapped_tx
+            .raw_call("submitCandidancy")
+            .argument(&election_id)
+            .argument(&name)
+            .argument(&description)
+            .original_result()
+    }
+
+    pub fn register_candidate<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+    >(
+        self,
+        election_id: Arg0,
+        candidate_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u16> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerCandidate")
+            .argument(&election_id)
+            .argument(&candidate_id)
+            .original_result()
+    }
+
+    /// Registers the caller as a voter of the election. 
+    ///  
+    /// If the election has a voter roll, `verification_data` is the proof of membership of the caller 
+    /// (see `register_from_voter_roll`), and voters can register until the election ends. 
+    pub fn register_self<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        verification_data: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerSelf")
+            .argument(&election_id)
+            .argument(&verification_data)
+            .original_result()
+    }
+
+    pub fn register_voter<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        voter_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerVoter")
+            .argument(&election_id)
+            .argument(&voter_address)
+            .original_result()
+    }
+
+    pub fn vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, u16>>,
+    >(
+        self,
+        election_id: Arg0,
+        vote: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, OptionalValue<Receipt<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("vote")
+            .argument(&election_id)
+            .argument(&vote)
+            .original_result()
+    }
+
+    /// Registers the caller from the voter roll of the election (see `registerSelf`) and casts their vote, 
+    /// in a single transaction. 
+    pub fn vote_with_proof<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, u16>>,
+    >(
+        self,
+        election_id: Arg0,
+        verification_data: Arg1,
+        vote: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, OptionalValue<Receipt<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("voteWithProof")
+            .argument(&election_id)
+            .argument(&verification_data)
+            .argument(&vote)
+            .original_result()
+    }
+
+    pub fn vote_rated<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<u16, i64>>>,
+    >(
+        self,
+        election_id: Arg0,
+        ratings: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, Receipt<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("voteRated")
+            .argument(&election_id)
+            .argument(&ratings)
+            .original_result()
+    }
+
+    /// Delegates the vote of the caller to another registered voter, until voting ends. The delegated weight 
+    /// follows the delegations of the delegate up to the first voter who votes directly; a delegator who votes 
+    /// directly overrides their delegation. 
+    pub fn delegate_vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        delegate: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("delegateVote")
+            .argument(&election_id)
+            .argument(&delegate)
+            .original_result()
+    }
+
+    pub fn revoke_delegation<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeDelegation")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    /// Commits the vote of the caller in a commit-reveal election. 
+    ///  
+    /// The commitment is `sha256(election_id || voter || ballot || salt)` with a random 32-byte salt (see `ballot_hash`), 
+    /// so it cannot be replayed in another election or by another voter; the vote is only counted once it is 
+    /// revealed with `revealVote` or `revealVoteRated` during the reveal period. In a token-weighted election, 
+    /// the stake is paid and locked when committing. 
+    pub fn commit_vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        election_id: Arg0,
+        hash: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("commitVote")
+            .argument(&election_id)
+            .argument(&hash)
+            .original_result()
+    }
+
+    pub fn reveal_vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, u16>>,
+    >(
+        self,
+        election_id: Arg0,
+        salt: Arg1,
+        vote: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Receipt<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revealVote")
+            .argument(&election_id)
+            .argument(&salt)
+            .argument(&vote)
+            .original_result()
+    }
+
+    pub fn reveal_vote_rated<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<u16, i64>>>,
+    >(
+        self,
+        election_id: Arg0,
+        salt: Arg1,
+        ratings: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Receipt<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revealVoteRated")
+            .argument(&election_id)
+            .argument(&salt)
+            .argument(&ratings)
+            .original_result()
+    }
+
+    /// Casts an encrypted ballot in an encrypted election. 
+    ///  
+    /// The ballot holds one encrypted choice per candidate: an ElGamal encryption of 1 if the candidate is selected, 
+    /// 0 otherwise, under the joint public key of the trustees (see `getJointPublicKey`), with a proof that it 
+    /// encrypts 0 or 1. A Plurality ballot also needs `sum_proof`, proving that the product of its ciphertexts 
+    /// encrypts 0 or 1, i.e. that at most one candidate is selected. The proofs are bound to the voter's address, 
+    /// so a ballot cannot be replayed by another voter. 
+    ///  
+    /// The ciphertexts are multiplied into the encrypted tally of each candidate (see `getEncryptedTally`), 
+    /// which the trustees decrypt after the election (see `postDecryptionShares` and `publishTally`). 
+    pub fn vote_encrypted<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, EncryptedChoice<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<BallotProof<Env::Api>>>,
+    >(
+        self,
+        election_id: Arg0,
+        ballot: Arg1,
+        sum_proof: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("voteEncrypted")
+            .argument(&election_id)
+            .argument(&ballot)
+            .argument(&sum_proof)
+            .original_result()
+    }
+
+    /// Posts the decryption shares of the calling trustee, once the election has ended. 
+    ///  
+    /// For each candidate, the share is `d = A^s (mod p)`, where `(A, B)` is the encrypted tally of the candidate 
+    /// and `s` the trustee's share of the joint secret key, with a Chaum-Pedersen proof that it uses the same secret as 
+    /// the trustee's verification key `v` (see `getTrusteeVerificationKey`): `g^response == t_g * v^c` and 
+    /// `A^response == t_a * d^c (mod p)`. 
+    pub fn post_decryption_shares<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, DecryptionShare<Env::Api>>>,
+    >(
+        self,
+        election_id: Arg0,
+        shares: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("postDecryptionShares")
+            .argument(&election_id)
+            .argument(&shares)
+            .original_result()
+    }
+
+    /// Publishes the decrypted tally of an encrypted election, once `threshold` trustees have posted their decryption shares. 
+    ///  
+    /// Anyone can publish it: each count is checked against the encrypted tally `(A, B)` of the candidate and the shares 
+    /// `d_j` of the first `threshold` trustees who posted them, combined by Lagrange interpolation at 0: 
+    /// `g^count * prod_j d_j^l_j == B (mod p)`, where `l_j = prod_(m != j) m / (m - j) (mod q)` over their positions. 
+    /// The counts are then stored in `result_vector` and the election can be finalised by `results`. 
+    pub fn publish_tally<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<u16, u64>>>,
+    >(
+        self,
+        election_id: Arg0,
+        counts: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("publishTally")
+            .argument(&election_id)
+            .argument(&counts)
+            .original_result()
+    }
+
+    pub fn end_election<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("endElection")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn withdraw_stake<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        election_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawStake")
+            .argument(&election_id)
+            .original_result()
+    }
+
+    pub fn make_dispute<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        election_id: Arg0,
+        dispute_name: Arg1,
+        dispute_description: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u16> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("makeDispute")
+            .argument(&election_id)
+            .argument(&dispute_name)
+            .argument(&dispute_description)
+            .original_result()
+    }
+
+    pub fn resolve_dispute<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u16>,
+        Arg2: ProxyArg<bool>,
+    >(
+        self,
+        election_id: Arg0,
+        dispute_id: Arg1,
+        valid: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolveDispute")
+            .argument(&election_id)
+            .argument(&dispute_id)
+            .argument(&valid)
+            .original_result()
+    }
+
+    pub fn get_block_timestamp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentBlockTimestamp")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct ElectionData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub name: ManagedBuffer<Api>,
+    pub description: ManagedBuffer<Api>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub election_type: ElectionType,
+    pub ended: bool,
+    pub admin: ManagedAddress<Api>,
+    pub seats: u32,
+    pub previous_round: Option<u64>,
+    pub next_round: Option<u64>,
+    pub tie_break_policy: TieBreakPolicy,
+    pub turnout: u64,
+    pub void: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
+pub enum ElectionType {
+    #[default]
+    Plurality,
+    Approval,
+    SingleTransferableVote,
+    Borda,
+    Schulze,
+    RankedPairs,
+    Score,
+    Star,
+    Cumulative,
+    Quadratic,
+    MajorityJudgment,
+    TwoRound,
+    PartyList,
+    Referendum,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
+pub enum TieBreakPolicy {
+    #[default]
+    EarliestRegistration,
+    Random,
+    AdminCastingVote,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
+pub struct Voter<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub address: ManagedAddress<Api>,
+    pub eligible: bool,
+    pub token: TokenIdentifier<Api>,
+    pub credential_nonce: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, Debug)]
+pub struct VoterRoll<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub root: ManagedByteArray<Api, 32usize>,
+    pub voters: u64,
+    pub weighted: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
+pub struct Candidate<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u16,
+    pub name: ManagedBuffer<Api>,
+    pub description: ManagedBuffer<Api>,
+    pub creator: ManagedAddress<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct Vote<Api>
+where
+    Api: ManagedTypeApi,
 {
     pub candidates: ManagedVec<Api, u16>,
+    pub ratings: ManagedVec<Api, i64>,
+    pub credits: u64,
+    pub weight: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct Ballot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub vote: Vote<Api>,
+    pub timestamp: u64,
+    pub block_nonce: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
+pub enum ReceiptStatus {
+    #[default]
+    Invalid,
+    Counted,
+    Superseded,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, Debug)]
+pub struct Commitment<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub hash: ManagedByteArray<Api, 32usize>,
+    pub weight: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct EncryptedChoice<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub candidate: u16,
+    pub ciphertext: Ciphertext<Api>,
+    pub proof: BallotProof<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct Ciphertext<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub a: BigUint<Api>,
+    pub b: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct BallotProof<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub t_a0: BigUint<Api>,
+    pub t_b0: BigUint<Api>,
+    pub t_a1: BigUint<Api>,
+    pub t_b1: BigUint<Api>,
+    pub c0: BigUint<Api>,
+    pub c1: BigUint<Api>,
+    pub s0: BigUint<Api>,
+    pub s1: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct BallotRules {
+    pub min_selections: u32,
+    pub max_selections: u32,
+    pub full_ranking: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct RatingScale {
+    pub min: u64,
+    pub max: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct RunoffSchedule {
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
+pub struct Party<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u16,
+    pub name: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct PartyListRules {
+    pub method: SeatAllocationMethod,
+    pub threshold_percent: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
+pub enum SeatAllocationMethod {
+    #[default]
+    DHondt,
+    SainteLague,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct ReferendumRules {
+    pub quorum_percent: u64,
+    pub threshold_numerator: u64,
+    pub threshold_denominator: u64,
 }
 
 #[type_abi]
@@ -472,9 +2274,144 @@ pub struct Dispute<Api>
 where
     Api: ManagedTypeApi,
 {
+    pub id: u16,
     pub name: ManagedBuffer<Api>,
     pub description: ManagedBuffer<Api>,
     pub creator: ManagedAddress<Api>,
     pub resolved: bool,
     pub result_adjusted: bool,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
+pub struct PairwisePreference {
+    pub candidate: u16,
+    pub opponent: u16,
+    pub count: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct StarResult<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub scores: ManagedVec<Api, VotingResult<Api>>,
+    pub first_finalist: u16,
+    pub second_finalist: u16,
+    pub first_finalist_votes: u64,
+    pub second_finalist_votes: u64,
+    pub no_preference: u64,
+    pub winner: u16,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct VotingResult<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub candidate: u16,
+    pub count: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct GradeResult<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub candidate: u16,
+    pub median_grade: u64,
+    pub histogram: ManagedVec<Api, u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
+pub struct PartyResult<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub party: u16,
+    pub votes: BigUint<Api>,
+    pub seats: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct ReferendumResult {
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub abstain_votes: u64,
+    pub registered_voters: u64,
+    pub outcome: ReferendumOutcome,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
+pub enum ReferendumOutcome {
+    #[default]
+    Passed,
+    Rejected,
+    QuorumNotMet,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct TieBreak<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub candidates: ManagedVec<Api, u16>,
+    pub elimination: bool,
+    pub policy: TieBreakPolicy,
+    pub seed: ManagedBuffer<Api>,
+    pub deadline: u64,
+    pub chosen: u16,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct StvRound<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub counts: ManagedVec<Api, VotingResult<Api>>,
+    pub quota: u64,
+    pub exhausted_votes: u64,
+    pub exhausted_value: u64,
+    pub elected: ManagedVec<Api, u16>,
+    pub eliminated: u16,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
+pub struct LockedPair {
+    pub winner: u16,
+    pub loser: u16,
+    pub margin: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, Clone, Debug)]
+pub struct Receipt<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ballot_id: u64,
+    pub salt: ManagedByteArray<Api, 32usize>,
+    pub hash: ManagedByteArray<Api, 32usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct DecryptionShare<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub candidate: u16,
+    pub share: BigUint<Api>,
+    pub t_g: BigUint<Api>,
+    pub t_a: BigUint<Api>,
+    pub response: BigUint<Api>,
+}
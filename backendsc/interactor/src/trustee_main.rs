@@ -0,0 +1,138 @@
+//! Offline tool for the trustees of encrypted elections: key generation, ballot encryption, decryption shares
+//! and verification of the published values. Numbers are read and printed in hexadecimal.
+
+use clap::{Parser, Subcommand};
+use multiversx_sc_snippets::imports::bech32;
+use num_bigint::BigUint;
+use rust_interact::elgamal;
+
+#[derive(Parser)]
+#[command(about = "Trustee tool for encrypted elections")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generates a secret polynomial of degree `threshold - 1`, the values to register with `registerTrusteeKey`
+    /// and the key share of each of the `trustees` trustees, to send to them privately
+    Keygen { election_id: u64, trustee_address: String, threshold: usize, trustees: u64 },
+    /// Verifies the proof of knowledge of a registered public key
+    VerifyKey { election_id: u64, trustee_address: String, public_key: String, commitment: String, response: String },
+    /// Verifies a key share received by the trustee at `index` against the public key and coefficient commitments of its sender
+    VerifyKeyShare { index: u64, share: String, public_key: String, coefficient_commitments: Vec<String> },
+    /// Combines the key shares received by a trustee (their own included) into their share of the joint secret key
+    CombineKeyShares { shares: Vec<String> },
+    /// Encrypts a ballot (1 for each selected candidate, 0 otherwise, in the order of the candidate IDs)
+    Encrypt { election_id: u64, voter_address: String, joint_public_key: String, selections: Vec<u8> },
+    /// Computes the decryption share of the first component `A` of an encrypted tally with a share of the joint secret key
+    Share { election_id: u64, trustee_address: String, secret: String, a: String },
+    /// Verifies a posted decryption share against the verification key of the trustee (`getTrusteeVerificationKey`)
+    VerifyShare { election_id: u64, trustee_address: String, verification_key: String, a: String, share: String, t_g: String, t_a: String, response: String },
+    /// Decrypts the count of an encrypted tally from its second component `B` and the shares of at least `threshold`
+    /// trustees, each given as `index:share` with the position of the trustee in `getTrustees` (from 1)
+    Decrypt { b: String, max_count: u64, shares: Vec<String> },
+}
+
+fn parse_hex(value: &str) -> BigUint {
+    BigUint::parse_bytes(value.trim_start_matches("0x").as_bytes(), 16).expect("invalid hexadecimal number")
+}
+
+/// Parses a decryption share given as `index:share`.
+fn parse_indexed_share(value: &str) -> (u64, BigUint) {
+    let (index, share) = value.split_once(':').expect("share must be given as index:share");
+    (index.parse().expect("invalid trustee index"), parse_hex(share))
+}
+
+fn parse_address(address: &str) -> [u8; 32] {
+    *bech32::decode(address).as_array()
+}
+
+fn print_value(name: &str, value: &BigUint) {
+    println!("{name}: {value:x}");
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::Keygen { election_id, trustee_address, threshold, trustees } => {
+            let key = elgamal::generate_key(election_id, &parse_address(&trustee_address), threshold);
+            // the coefficients are only needed to compute the key shares, the secret key share is kept instead
+            for coefficient in key.coefficients.iter() {
+                print_value("coefficient", coefficient);
+            }
+            print_value("public_key", &key.public_key);
+            print_value("commitment", &key.commitment);
+            print_value("response", &key.response);
+            for commitment in key.coefficient_commitments.iter() {
+                print_value("coefficient_commitment", commitment);
+            }
+            for index in 1..=trustees {
+                print_value(&format!("key_share {index}"), &elgamal::key_share(&key.coefficients, index));
+            }
+        },
+        Command::VerifyKey { election_id, trustee_address, public_key, commitment, response } => {
+            let valid = elgamal::verify_trustee_key(election_id, &parse_address(&trustee_address), &parse_hex(&public_key), &parse_hex(&commitment), &parse_hex(&response));
+            println!("valid: {valid}");
+        },
+        Command::VerifyKeyShare { index, share, public_key, coefficient_commitments } => {
+            let coefficient_commitments: Vec<BigUint> = coefficient_commitments.iter().map(|commitment| parse_hex(commitment)).collect();
+            let valid = elgamal::verify_key_share(&parse_hex(&public_key), &coefficient_commitments, index, &parse_hex(&share));
+            println!("valid: {valid}");
+        },
+        Command::CombineKeyShares { shares } => {
+            let shares: Vec<BigUint> = shares.iter().map(|share| parse_hex(share)).collect();
+            let secret = elgamal::combine_key_shares(&shares);
+            // the secret key share must be kept until the decryption shares are posted
+            print_value("secret", &secret);
+            print_value("verification_key", &elgamal::generator().modpow(&secret, &elgamal::prime()));
+        },
+        Command::Encrypt { election_id, voter_address, joint_public_key, selections } => {
+            let selections: Vec<bool> = selections.iter().map(|selection| *selection == 1).collect();
+            let ballot = elgamal::encrypt_ballot(election_id, &parse_address(&voter_address), &parse_hex(&joint_public_key), &selections);
+            for (i, (ciphertext, proof)) in ballot.choices.iter().enumerate() {
+                println!("choice {i}:");
+                print_value("  a", &ciphertext.a);
+                print_value("  b", &ciphertext.b);
+                print_proof(proof);
+            }
+            match ballot.sum_proof {
+                Some(proof) => {
+                    println!("sum proof:");
+                    print_proof(&proof);
+                },
+                None => println!("sum proof: none (more than one candidate selected)"),
+            }
+        },
+        Command::Share { election_id, trustee_address, secret, a } => {
+            let share = elgamal::decryption_share(election_id, &parse_address(&trustee_address), &parse_hex(&secret), &parse_hex(&a));
+            print_value("share", &share.share);
+            print_value("t_g", &share.t_g);
+            print_value("t_a", &share.t_a);
+            print_value("response", &share.response);
+        },
+        Command::VerifyShare { election_id, trustee_address, verification_key, a, share, t_g, t_a, response } => {
+            let share = elgamal::DecryptionShare { share: parse_hex(&share), t_g: parse_hex(&t_g), t_a: parse_hex(&t_a), response: parse_hex(&response) };
+            let valid = elgamal::verify_decryption_share(election_id, &parse_address(&trustee_address), &parse_hex(&verification_key), &parse_hex(&a), &share);
+            println!("valid: {valid}");
+        },
+        Command::Decrypt { b, max_count, shares } => {
+            let shares: Vec<(u64, BigUint)> = shares.iter().map(|share| parse_indexed_share(share)).collect();
+            match elgamal::decrypt_count(&parse_hex(&b), &shares, max_count) {
+                Some(count) => println!("count: {count}"),
+                None => println!("count: not found (invalid shares or count above {max_count})"),
+            }
+        },
+    }
+}
+
+fn print_proof(proof: &elgamal::BallotProof) {
+    print_value("  t_a0", &proof.t_a0);
+    print_value("  t_b0", &proof.t_b0);
+    print_value("  t_a1", &proof.t_a1);
+    print_value("  t_b1", &proof.t_b1);
+    print_value("  c0", &proof.c0);
+    print_value("  c1", &proof.c1);
+    print_value("  s0", &proof.s0);
+    print_value("  s1", &proof.s1);
+}
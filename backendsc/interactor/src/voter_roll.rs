@@ -0,0 +1,124 @@
+//! Merkle trees of voter rolls, built as the contract verifies them (see `setVoterRoll` and `registerSelf` in the contract).
+//!
+//! A voter roll is a CSV file with one voter per line: `<address>` or `<address>,<weight>` (the weight defaults to 1).
+
+use std::collections::{HashMap, HashSet};
+
+use multiversx_sc::types::Address;
+use sha2::{Digest, Sha256};
+
+pub type Hash = [u8; 32];
+
+pub struct VoterRollTree {
+    // the voters and their weights, in the order of the CSV file
+    pub voters: Vec<(Address, u64)>,
+    // the index of each voter in `voters`, which is also the index of their leaf
+    indexes: HashMap<Address, usize>,
+    // the levels of the tree, from the leaves to the root
+    levels: Vec<Vec<Hash>>,
+}
+
+/// The leaf of a voter: `sha256(address || weight)`, the weight being 8 bytes big-endian.
+pub fn leaf(address: &Address, weight: u64) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(address.as_array());
+    hasher.update(weight.to_be_bytes());
+    hasher.finalize().into()
+}
+
+/// The parent of two nodes: the sha256 of the two nodes, the smaller one first.
+fn parent(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    if left <= right {
+        hasher.update(left);
+        hasher.update(right);
+    } else {
+        hasher.update(right);
+        hasher.update(left);
+    }
+    hasher.finalize().into()
+}
+
+/// Decodes an `erd1...` bech32 address.
+pub fn parse_address(address: &str) -> Result<Address, String> {
+    let (hrp, data) = bech32::decode(address).map_err(|e| format!("invalid address {address}: {e}"))?;
+    if hrp.as_str() != "erd" {
+        return Err(format!("invalid address {address}: not an erd address"));
+    }
+    let bytes: [u8; 32] = data.try_into().map_err(|_| format!("invalid address {address}: not 32 bytes"))?;
+    Ok(Address::from(bytes))
+}
+
+/// Parses a voter roll CSV file.
+pub fn parse_csv(contents: &str) -> Result<Vec<(Address, u64)>, String> {
+    let mut voters: Vec<(Address, u64)> = Vec::new();
+    let mut addresses: HashSet<Address> = HashSet::new();
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split(',').map(str::trim);
+        let address = fields.next().unwrap_or_default();
+        let address = parse_address(address).map_err(|e| format!("line {}: {e}", i + 1))?;
+        let weight = match fields.next() {
+            Some(weight) => weight.parse::<u64>().map_err(|e| format!("line {}: invalid weight: {e}", i + 1))?,
+            None => 1,
+        };
+        if !addresses.insert(address.clone()) {
+            return Err(format!("line {}: duplicate address", i + 1));
+        }
+        voters.push((address, weight));
+    }
+    if voters.is_empty() {
+        return Err("the voter roll is empty".to_string());
+    }
+    Ok(voters)
+}
+
+impl VoterRollTree {
+    /// Builds the tree of a voter roll. A node without a sibling is moved up to the next level as it is.
+    pub fn new(voters: Vec<(Address, u64)>) -> Self {
+        let mut levels = vec![voters.iter().map(|(address, weight)| leaf(address, *weight)).collect::<Vec<_>>()];
+        while levels.last().unwrap().len() > 1 {
+            let level = levels.last().unwrap();
+            let next_level = level
+                .chunks(2)
+                .map(|pair| if pair.len() == 2 { parent(&pair[0], &pair[1]) } else { pair[0] })
+                .collect();
+            levels.push(next_level);
+        }
+        let indexes = voters.iter().enumerate().map(|(index, (address, _))| (address.clone(), index)).collect();
+        VoterRollTree { voters, indexes, levels }
+    }
+
+    pub fn root(&self) -> Hash {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Returns the verification data of a voter for `registerSelf` and `voteWithProof`: their weight
+    /// (8 bytes, big-endian) followed by the sibling hashes on the path from their leaf to the root.
+    pub fn proof(&self, address: &Address) -> Option<Vec<u8>> {
+        let mut index = *self.indexes.get(address)?;
+        let mut proof = self.voters[index].1.to_be_bytes().to_vec();
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.extend_from_slice(sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+/// Checks a proof against a root, as the contract does.
+pub fn verify(root: &Hash, address: &Address, proof: &[u8]) -> bool {
+    if proof.len() < 8 || !(proof.len() - 8).is_multiple_of(32) {
+        return false;
+    }
+    let weight = u64::from_be_bytes(proof[..8].try_into().unwrap());
+    let node = proof[8..]
+        .chunks(32)
+        .fold(leaf(address, weight), |node, sibling| parent(&node, sibling.try_into().unwrap()));
+    node == *root
+}
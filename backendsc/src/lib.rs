@@ -1,6 +1,6 @@
 #![no_std]
 
-use types::{Candidate, CandidateID, Dispute, DisputeID, ElectionData, ElectionType, ElectionID, Vote, VotingResult};
+use types::{Ballot, BallotID, BallotProof, BallotRules, Candidate, CandidateID, Ciphertext, Commitment, DecryptionShare, Dispute, DisputeID, ElectionData, ElectionType, ElectionID, EncryptedChoice, GradeResult, LockedPair, PairwisePreference, Party, PartyID, PartyListRules, PartyResult, RatingScale, Receipt, ReceiptStatus, ReferendumOutcome, ReferendumResult, ReferendumRules, RunoffSchedule, SeatAllocationMethod, StarResult, StvRound, TieBreak, TieBreakPolicy, Vote, Voter, VoterCredential, VoterRoll, VotingResult, AVERAGE_PRECISION, BLANK_BALLOT, CASTING_VOTE_PERIOD, ELGAMAL_GENERATOR, ELGAMAL_ORDER, ELGAMAL_PRIME, NONE_OF_THE_ABOVE, STV_VOTE_PRECISION, REFERENDUM_ABSTAIN, REFERENDUM_NO, REFERENDUM_YES};
 #[allow(unused_imports)]
 use multiversx_sc::imports::*;
 
@@ -52,6 +52,70 @@ pub trait BackendSc {
     // whether a voter is eligible to vote (true if hasn't voted yet, false otherwise)
     fn voter_eligible(&self, election_id: ElectionID, voter_address: ManagedAddress) -> SingleValueMapper<bool>;
 
+    #[view(getVoterCredentialToken)]
+    #[storage_mapper("voter_credential")]
+    // the non-transferable token of the voter credentials, issued once by the owner
+    fn voter_credential(&self) -> NonFungibleTokenMapper;
+
+    #[view(getCredentialRequired)]
+    #[storage_mapper("credential_required")]
+    // whether voters of an election receive a voter credential on registration, which they need to vote
+    fn credential_required(&self, election_id: ElectionID) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("voter_credential_nonce")]
+    // the nonce of the voter credential of each voter
+    fn voter_credential_nonce(&self, election_id: ElectionID, voter_address: ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(getRevokedVoters)]
+    #[storage_mapper("revoked_voters")]
+    // the voters whose credential was revoked by the admin
+    fn revoked_voters(&self, election_id: ElectionID) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Returns the registration of a voter: whether they can still vote and their voter credential.
+    #[view(getVoter)]
+    fn get_voter(&self, election_id: ElectionID, voter_address: ManagedAddress) -> OptionalValue<Voter<Self::Api>> {
+        if !self.registered_voters(election_id).contains(&voter_address) {
+            return OptionalValue::None;
+        }
+        let credential_nonce = self.voter_credential_nonce(election_id, voter_address.clone()).get();
+        let token = if credential_nonce == 0 { TokenIdentifier::from(ManagedBuffer::new()) } else { self.voter_credential().get_token_id() };
+        OptionalValue::Some(Voter {
+            eligible: self.voter_eligible(election_id, voter_address.clone()).get(),
+            address: voter_address,
+            token,
+            credential_nonce,
+        })
+    }
+
+    #[storage_mapper("voter_roll")]
+    // the Merkle root of the eligible voters of an election, if the admin committed one
+    fn voter_roll(&self, election_id: ElectionID) -> SingleValueMapper<VoterRoll<Self::Api>>;
+
+    #[view(isRollLeafUsed)]
+    #[storage_mapper("roll_leaf_used")]
+    // whether a leaf of the voter roll was used to register a voter
+    fn roll_leaf_used(&self, election_id: ElectionID, leaf: ManagedByteArray<Self::Api, 32>) -> SingleValueMapper<bool>;
+
+    #[view(getRollRegistrations)]
+    #[storage_mapper("roll_registrations")]
+    // the number of registered voters who registered from the voter roll
+    fn roll_registrations(&self, election_id: ElectionID) -> SingleValueMapper<u64>;
+
+    #[view(getVoterWeight)]
+    #[storage_mapper("voter_weight")]
+    // the weight given to a voter by a weighted voter roll
+    fn voter_weight(&self, election_id: ElectionID, voter_address: ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Returns the voter roll of the election, or nothing if its voters are registered one by one.
+    #[view(getVoterRoll)]
+    fn get_voter_roll(&self, election_id: ElectionID) -> OptionalValue<VoterRoll<Self::Api>> {
+        if self.voter_roll(election_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.voter_roll(election_id).get())
+        }
+    }
+
 
     #[view(getPotentialCandidateIDs)]
     #[storage_mapper("potential_candidate_id_list")]
@@ -69,10 +133,374 @@ pub trait BackendSc {
     fn candidate(&self, election_id: ElectionID, candidate_id: CandidateID) -> SingleValueMapper<Candidate<Self::Api>>;
 
 
+    #[storage_mapper("ballots")]
+    // the ledger of the ballots of each election, in the order they were cast (the index of a ballot is its ID)
+    fn ballots(&self, election_id: ElectionID) -> VecMapper<Ballot<Self::Api>>;
+
+    #[storage_mapper("receipt_hashes")]
+    // the receipt hash of each ballot of the ledger, at the same index (the salt of the hash is only in the receipt)
+    fn receipt_hashes(&self, election_id: ElectionID) -> VecMapper<ManagedByteArray<Self::Api, 32>>;
+
+    /// Returns the votes of the ballots counted in the election, in the order they were cast.
     #[view(getVotes)]
-    #[storage_mapper("votes")]
-    // the list of votes for each election
-    fn votes(&self, election_id: ElectionID) -> UnorderedSetMapper<Vote<Self::Api>>;
+    fn get_votes(&self, election_id: ElectionID) -> MultiValueEncoded<Vote<Self::Api>> {
+        self.counted_votes(election_id).into()
+    }
+
+    /// Returns the votes of the ballots of the election that count toward the results: every ballot of the
+    /// ledger except the ones replaced by a later ballot of the same voter. In an election with delegation,
+    /// the weight of each ballot includes the weights delegated to its voter.
+    fn counted_votes(&self, election_id: ElectionID) -> ManagedVec<Vote<Self::Api>> {
+        let mut ballots: ManagedVec<Ballot<Self::Api>> = self.ballots(election_id).iter().collect();
+        for delegator in self.delegators(election_id).iter() {
+            if !self.current_ballot(election_id, delegator.clone()).is_empty() || self.revoked_voters(election_id).contains(&delegator) {
+                // the delegator voted directly, or their credential was revoked
+                continue;
+            }
+            let representative = self.representative(election_id, &delegator);
+            let ballot_id = self.current_ballot(election_id, representative).get();
+            if ballot_id == 0 || ballot_id == BLANK_BALLOT {
+                continue;
+            }
+            let index = ballot_id as usize - 1;
+            let mut ballot = ballots.get(index);
+            ballot.vote.weight += self.voter_roll_weight(election_id, &delegator);
+            let _ = ballots.set(index, ballot);
+        }
+
+        let superseded_ballots = self.superseded_ballots(election_id);
+        ballots
+            .iter()
+            .filter(|ballot| !superseded_ballots.contains(&ballot.id))
+            .map(|ballot| ballot.vote)
+            .collect()
+    }
+
+    #[view(getBallotCount)]
+    fn get_ballot_count(&self, election_id: ElectionID) -> u64 {
+        self.ballots(election_id).len() as u64
+    }
+
+    /// Returns up to `count` ballots of the election, starting with the ballot `from` (ballot IDs start at 1).
+    #[view(getBallots)]
+    fn get_ballots(&self, election_id: ElectionID, from: BallotID, count: u64) -> MultiValueEncoded<Ballot<Self::Api>> {
+        require!(from > 0, "Ballot IDs start at 1");
+        let last = (self.ballots(election_id).len() as u64).min(from.saturating_add(count).saturating_sub(1));
+        (from..=last).map(|ballot_id| self.ballots(election_id).get(ballot_id as usize)).collect()
+    }
+
+    #[view(getBallot)]
+    fn get_ballot(&self, election_id: ElectionID, ballot_id: BallotID) -> Ballot<Self::Api> {
+        require!(ballot_id > 0 && ballot_id <= self.ballots(election_id).len() as u64, "Ballot does not exist");
+        self.ballots(election_id).get(ballot_id as usize)
+    }
+
+    /// Checks the salt of a receipt returned by a vote against the ballot with the same ID in the ledger of the
+    /// election: `Counted` if the receipt hash recomputed from the ballot and the salt is the one stored when the
+    /// ballot was cast and the ballot counts toward the results, `Superseded` if it is but the voter replaced the
+    /// ballot with a later one, and `Invalid` otherwise. The salt is only returned to the voter, so the public ledger
+    /// is not enough to produce a valid receipt. The ledger does not record voters, so the check does not reveal which
+    /// address cast the ballot (in elections with revoting or delegation, the `current_ballot` storage does).
+    #[view(verifyReceipt)]
+    fn verify_receipt(&self, election_id: ElectionID, ballot_id: BallotID, salt: ManagedByteArray<Self::Api, 32>) -> ReceiptStatus {
+        if ballot_id == 0 || ballot_id > self.ballots(election_id).len() as u64 {
+            return ReceiptStatus::Invalid;
+        }
+        let ballot = self.ballots(election_id).get(ballot_id as usize);
+        if self.receipt_hash(election_id, &ballot, &salt) != self.receipt_hashes(election_id).get(ballot_id as usize) {
+            ReceiptStatus::Invalid
+        } else if self.superseded_ballots(election_id).contains(&ballot_id) {
+            ReceiptStatus::Superseded
+        } else {
+            ReceiptStatus::Counted
+        }
+    }
+
+    #[view(getBlankBallotsAllowed)]
+    #[storage_mapper("blank_ballots_allowed")]
+    // whether voters can cast a blank ballot
+    fn blank_ballots_allowed(&self, election_id: ElectionID) -> SingleValueMapper<bool>;
+
+    #[view(getBlankBallots)]
+    #[storage_mapper("blank_ballots")]
+    // the number of blank ballots cast in an election
+    fn blank_ballots(&self, election_id: ElectionID) -> SingleValueMapper<u64>;
+
+    #[view(getRevotingAllowed)]
+    #[storage_mapper("revoting_allowed")]
+    // whether voters can recast their ballot until the end of the election, only their latest ballot being counted
+    fn revoting_allowed(&self, election_id: ElectionID) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("current_ballot")]
+    // the ID of the latest ballot of each voter in an election with revoting or delegation (BLANK_BALLOT for a blank ballot);
+    // storage is public, so this links the address of each voter of such an election to their ballot in the ledger
+    fn current_ballot(&self, election_id: ElectionID, voter_address: ManagedAddress) -> SingleValueMapper<BallotID>;
+
+    #[view(getDelegationAllowed)]
+    #[storage_mapper("delegation_allowed")]
+    // whether registered voters can delegate their vote to another registered voter
+    fn delegation_allowed(&self, election_id: ElectionID) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("delegate")]
+    // the voter to whom each voter delegated their vote
+    fn delegate(&self, election_id: ElectionID, voter_address: ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getDelegators)]
+    #[storage_mapper("delegators")]
+    // the voters who delegated their vote
+    fn delegators(&self, election_id: ElectionID) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Returns the delegation chain of a voter: the voter, then each delegate in turn, up to their representative,
+    /// the first voter of the chain who voted directly or did not delegate their vote.
+    #[view(getDelegationChain)]
+    fn get_delegation_chain(&self, election_id: ElectionID, voter_address: ManagedAddress) -> MultiValueEncoded<ManagedAddress> {
+        let mut chain = MultiValueEncoded::new();
+        let mut voter_address = voter_address;
+        chain.push(voter_address.clone());
+        while self.current_ballot(election_id, voter_address.clone()).is_empty() && !self.delegate(election_id, voter_address.clone()).is_empty() {
+            voter_address = self.delegate(election_id, voter_address).get();
+            chain.push(voter_address.clone());
+        }
+        chain
+    }
+
+    /// Returns the weight a voter carries in the election: their own weight plus the weights of the voters whose
+    /// delegation chain ends with them, or 0 if their own vote is delegated.
+    #[view(getEffectiveWeight)]
+    fn get_effective_weight(&self, election_id: ElectionID, voter_address: ManagedAddress) -> BigUint {
+        if !self.registered_voters(election_id).contains(&voter_address) || self.representative(election_id, &voter_address) != voter_address {
+            return BigUint::zero();
+        }
+        let mut weight = self.voter_roll_weight(election_id, &voter_address);
+        for delegator in self.delegators(election_id).iter() {
+            if delegator != voter_address && !self.revoked_voters(election_id).contains(&delegator) && self.representative(election_id, &delegator) == voter_address {
+                weight += self.voter_roll_weight(election_id, &delegator);
+            }
+        }
+        weight
+    }
+
+    /// Follows the delegations of a voter up to the first voter who voted directly or did not delegate their vote.
+    fn representative(&self, election_id: ElectionID, voter_address: &ManagedAddress) -> ManagedAddress {
+        let mut representative = voter_address.clone();
+        while self.current_ballot(election_id, representative.clone()).is_empty() && !self.delegate(election_id, representative.clone()).is_empty() {
+            representative = self.delegate(election_id, representative).get();
+        }
+        representative
+    }
+
+    /// Returns the weight of a registered voter outside token-weighted elections: their weight in a weighted
+    /// voter roll, 1 otherwise.
+    fn voter_roll_weight(&self, election_id: ElectionID, voter_address: &ManagedAddress) -> BigUint {
+        if self.voter_weight(election_id, voter_address.clone()).is_empty() {
+            BigUint::from(1u64)
+        } else {
+            self.voter_weight(election_id, voter_address.clone()).get()
+        }
+    }
+
+    fn tracks_current_ballots(&self, election_id: ElectionID) -> bool {
+        self.revoting_allowed(election_id).get() || self.delegation_allowed(election_id).get()
+    }
+
+    #[view(getSupersededBallots)]
+    #[storage_mapper("superseded_ballots")]
+    // the ballots replaced by a later ballot of the same voter, which are not counted
+    fn superseded_ballots(&self, election_id: ElectionID) -> UnorderedSetMapper<BallotID>;
+
+    #[view(getRevealPeriod)]
+    #[storage_mapper("reveal_period")]
+    // the duration of the reveal window after the end of a commit-reveal election, 0 if votes are cast in plaintext
+    fn reveal_period(&self, election_id: ElectionID) -> SingleValueMapper<u64>;
+
+    #[view(getCommitment)]
+    #[storage_mapper("commitment")]
+    // the committed vote of each voter of a commit-reveal election
+    fn commitment(&self, election_id: ElectionID, voter_address: ManagedAddress) -> SingleValueMapper<Commitment<Self::Api>>;
+
+    #[view(getUnrevealedVoters)]
+    #[storage_mapper("unrevealed_voters")]
+    // the voters whose committed vote has not been revealed, their votes are not counted
+    fn unrevealed_voters(&self, election_id: ElectionID) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getTrustees)]
+    #[storage_mapper("trustees")]
+    // the trustees sharing the decryption key of an encrypted election (an election is encrypted if it has trustees)
+    fn trustees(&self, election_id: ElectionID) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getTrusteeThreshold)]
+    #[storage_mapper("trustee_threshold")]
+    // the number of trustees whose decryption shares are needed to decrypt the tally of an encrypted election
+    fn trustee_threshold(&self, election_id: ElectionID) -> SingleValueMapper<usize>;
+
+    #[view(getTrusteeKey)]
+    #[storage_mapper("trustee_key")]
+    // the ElGamal public key registered by a trustee: `g^a_0`, where `a_0` is the constant coefficient of their secret polynomial
+    fn trustee_key(&self, election_id: ElectionID, trustee: ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getCoefficientCommitments)]
+    #[storage_mapper("coefficient_commitments")]
+    // the commitments `g^a_1, ..., g^a_(t-1)` to the other coefficients of the secret polynomial of a trustee
+    fn coefficient_commitments(&self, election_id: ElectionID, trustee: ManagedAddress) -> SingleValueMapper<ManagedVec<BigUint>>;
+
+    #[view(getEncryptedBallots)]
+    #[storage_mapper("encrypted_ballots")]
+    // the ballots of an encrypted election
+    fn encrypted_ballots(&self, election_id: ElectionID) -> VecMapper<ManagedVec<EncryptedChoice<Self::Api>>>;
+
+    #[storage_mapper("encrypted_tally")]
+    // the product of the encrypted choices for a candidate: an encryption of their number of votes
+    fn encrypted_tally(&self, election_id: ElectionID, candidate_id: CandidateID) -> SingleValueMapper<Ciphertext<Self::Api>>;
+
+    #[view(getDecryptionShare)]
+    #[storage_mapper("decryption_share")]
+    // the share of a trustee for the decryption of the encrypted tally of a candidate
+    fn decryption_share(&self, election_id: ElectionID, trustee: ManagedAddress, candidate_id: CandidateID) -> SingleValueMapper<BigUint>;
+
+    #[view(getDecryptingTrustees)]
+    #[storage_mapper("decrypting_trustees")]
+    // the trustees who posted their decryption shares
+    fn decrypting_trustees(&self, election_id: ElectionID) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getTallyPublished)]
+    #[storage_mapper("tally_published")]
+    // whether the decrypted tally of an encrypted election was published
+    fn tally_published(&self, election_id: ElectionID) -> SingleValueMapper<bool>;
+
+    /// Returns the encrypted number of votes of a candidate of an encrypted election.
+    /// Before the first ballot, it is the trivial encryption of 0: (1, 1).
+    #[view(getEncryptedTally)]
+    fn get_encrypted_tally(&self, election_id: ElectionID, candidate_id: CandidateID) -> Ciphertext<Self::Api> {
+        if self.encrypted_tally(election_id, candidate_id).is_empty() {
+            Ciphertext { a: BigUint::from(1u64), b: BigUint::from(1u64) }
+        } else {
+            self.encrypted_tally(election_id, candidate_id).get()
+        }
+    }
+
+    /// Returns the joint public key ballots of an encrypted election are encrypted to: the product of the
+    /// public keys of all its trustees (mod p). Its secret key is shared among the trustees, so that decrypting
+    /// requires the shares of `threshold` of them (see `getTrusteeVerificationKey`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the election is not encrypted or if a trustee has not registered their key yet.
+    #[view(getJointPublicKey)]
+    fn joint_public_key(&self, election_id: ElectionID) -> BigUint {
+        require!(!self.trustees(election_id).is_empty(), "Election does not use encrypted votes");
+        let mut joint_key = BigUint::from(1u64);
+        for trustee in self.trustees(election_id).iter() {
+            require!(!self.trustee_key(election_id, trustee.clone()).is_empty(), "Trustee keys are missing");
+            joint_key = self.mod_mul(&joint_key, &self.trustee_key(election_id, trustee).get());
+        }
+        joint_key
+    }
+
+    /// Returns the verification key `g^s_j (mod p)` of a trustee of an encrypted election, where `s_j` is their share of
+    /// the joint secret key: the sum of the values at `j` of the secret polynomials of all the trustees, `j` being
+    /// the position of the trustee in `getTrustees` (from 1). It is computed from the public keys and coefficient
+    /// commitments of the trustees: `prod_i (h_i * C_i1^j * C_i2^(j^2) * ... * C_i(t-1)^(j^(t-1)))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the address is not a trustee of the election or if a trustee has not registered their key yet.
+    #[view(getTrusteeVerificationKey)]
+    fn trustee_verification_key(&self, election_id: ElectionID, trustee: ManagedAddress) -> BigUint {
+        require!(self.trustees(election_id).contains(&trustee), "Address is not a trustee");
+        let index = BigUint::from(self.trustees(election_id).get_index(&trustee) as u64);
+        let q = self.elgamal_order();
+        let mut verification_key = BigUint::from(1u64);
+        for dealer in self.trustees(election_id).iter() {
+            require!(!self.trustee_key(election_id, dealer.clone()).is_empty(), "Trustee keys are missing");
+            verification_key = self.mod_mul(&verification_key, &self.trustee_key(election_id, dealer.clone()).get());
+            let mut power = index.clone();
+            for commitment in self.coefficient_commitments(election_id, dealer).get().iter() {
+                verification_key = self.mod_mul(&verification_key, &self.mod_pow(&commitment, &power));
+                power = &power * &index % &q;
+            }
+        }
+        verification_key
+    }
+
+    #[view(getBallotRules)]
+    #[storage_mapper("ballot_rules")]
+    // the number of candidates a vote can select and whether it must rank every candidate, for election types voted with `vote`
+    fn ballot_rules(&self, election_id: ElectionID) -> SingleValueMapper<BallotRules>;
+
+    #[view(getVotingToken)]
+    #[storage_mapper("voting_token")]
+    // the token staked by the voters of a token-weighted election, empty otherwise
+    fn voting_token(&self, election_id: ElectionID) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getStake)]
+    #[storage_mapper("stake")]
+    // the tokens locked by each voter of a token-weighted election, until they are withdrawn
+    fn stake(&self, election_id: ElectionID, voter_address: ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getRatingScale)]
+    #[storage_mapper("rating_scale")]
+    // the lowest and highest rating a voter can give to a candidate, for election types that use ratings
+    fn rating_scale(&self, election_id: ElectionID) -> SingleValueMapper<RatingScale>;
+
+    #[view(getPointBudget)]
+    #[storage_mapper("point_budget")]
+    // the number of points each voter can spread across candidates, for Cumulative elections
+    fn point_budget(&self, election_id: ElectionID) -> SingleValueMapper<u64>;
+
+    #[view(getVoiceCreditBudget)]
+    #[storage_mapper("voice_credit_budget")]
+    // the number of voice credits each voter can spend, for Quadratic elections
+    fn voice_credit_budget(&self, election_id: ElectionID) -> SingleValueMapper<u64>;
+
+    #[view(getVoiceCreditsSpent)]
+    #[storage_mapper("voice_credits_spent")]
+    // the number of voice credits each voter has spent, for Quadratic elections
+    fn voice_credits_spent(&self, election_id: ElectionID, voter_address: ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(getNetVotes)]
+    #[storage_mapper("net_votes")]
+    // the votes for each candidate minus the votes against them, for Quadratic elections
+    fn net_votes(&self, election_id: ElectionID, candidate_id: CandidateID) -> SingleValueMapper<BigInt>;
+
+    #[view(getGrades)]
+    #[storage_mapper("grades")]
+    // the names of the grades of a Majority Judgment election, from the worst grade to the best
+    fn grades(&self, election_id: ElectionID) -> VecMapper<ManagedBuffer>;
+
+    #[view(getRunoffSchedule)]
+    #[storage_mapper("runoff_schedule")]
+    // the start and end times of the second round of a Two-Round election, if set by the admin
+    fn runoff_schedule(&self, election_id: ElectionID) -> SingleValueMapper<RunoffSchedule>;
+
+    #[view(getPartyIDs)]
+    #[storage_mapper("party_id_list")]
+    // the list of party ids of a Party-List election
+    fn party_id_list(&self, election_id: ElectionID) -> UnorderedSetMapper<PartyID>;
+
+    #[view(getParty)]
+    #[storage_mapper("party")]
+    // the party data for each party in an election
+    fn party(&self, election_id: ElectionID, party_id: PartyID) -> SingleValueMapper<Party<Self::Api>>;
+
+    #[view(getPartyList)]
+    #[storage_mapper("party_list")]
+    // the candidates of each party, in the order they take the seats of the party
+    fn party_list(&self, election_id: ElectionID, party_id: PartyID) -> VecMapper<CandidateID>;
+
+    #[view(getCandidateParty)]
+    #[storage_mapper("candidate_party")]
+    // the party of each candidate on a party list
+    fn candidate_party(&self, election_id: ElectionID, candidate_id: CandidateID) -> SingleValueMapper<PartyID>;
+
+    #[view(getPartyListRules)]
+    #[storage_mapper("party_list_rules")]
+    // the seat allocation method and electoral threshold of a Party-List election
+    fn party_list_rules(&self, election_id: ElectionID) -> SingleValueMapper<PartyListRules>;
+
+    #[view(getReferendumRules)]
+    #[storage_mapper("referendum_rules")]
+    // the turnout quorum and pass threshold of a Referendum election
+    fn referendum_rules(&self, election_id: ElectionID) -> SingleValueMapper<ReferendumRules>;
 
     #[view(getDisputeIDList)]
     #[storage_mapper("dispute_id_list")]
@@ -92,28 +520,38 @@ pub trait BackendSc {
 /// Updates the vote count for a given candidate in the election.
 /// 
 /// If the candidate is already present in the `vote_counts` list, this function increments
-/// their vote count by one. If the candidate is not present, it adds a new entry for the
-/// candidate with an initial vote count of one.
+/// their vote count by the weight of the vote (one, unless the election is token-weighted).
+/// If the candidate is not present, it adds a new entry for the candidate with an initial
+/// vote count of the weight of the vote.
 ///
 /// # Arguments
 ///
 /// * `vote_counts` - A mutable reference to a `ManagedVec` of `VotingResult` which holds
 ///   the current vote counts for each candidate.
 /// * `candidate_id` - The identifier of the candidate for whom the vote count should be updated.
+/// * `weight` - The weight of the vote.
+
+    fn count_candidate(&self, vote_counts: &mut ManagedVec<VotingResult<Self::Api>>, candidate_id: CandidateID, weight: &BigUint) {
+        self.count_candidate_points(vote_counts, candidate_id, weight.clone());
+    }
 
-    fn count_candidate(&self, vote_counts: &mut ManagedVec<VotingResult>, candidate_id: CandidateID) {
+    /// Adds a number of points to the count of a given candidate in the election.
+    ///
+    /// Works like `count_candidate`, but the count is increased by `points` instead of the weight of the vote.
+    /// Used by election types where a vote is worth more than a single point (e.g. Borda).
+    fn count_candidate_points(&self, vote_counts: &mut ManagedVec<VotingResult<Self::Api>>, candidate_id: CandidateID, points: BigUint) {
 
         for i in 0..vote_counts.len() {
-            // Add one to the count if the candidate is already in the list
+            // Add the points to the count if the candidate is already in the list
             let mut vote_result = vote_counts.get_mut(i);
             
             if vote_result.candidate == candidate_id {
-                vote_result.count += 1;
+                vote_result.count += points;
                 return;
             }
         }
         // If the candidate is not in the list, add it
-        vote_counts.push(VotingResult { candidate: candidate_id, count: 1 });
+        vote_counts.push(VotingResult { candidate: candidate_id, count: points });
         
     }
 
@@ -127,247 +565,2006 @@ pub trait BackendSc {
     /// # Returns
     ///
     /// A `ManagedVec` of `VotingResult` which holds the final vote counts for each candidate.
-    fn evaluate_plurality_or_approval(&self, election_id: ElectionID) -> ManagedVec<VotingResult> {
+    fn evaluate_plurality_or_approval(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
         
-        let mut vote_counts: ManagedVec<VotingResult> = ManagedVec::new();
+        let mut vote_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
 
         // Count each vote
-        for vote in self.votes(election_id).iter() {
+        for vote in self.counted_votes(election_id).iter() {
 
             // For each candidate in the vote (Plurality has only one candidate)
             for c in vote.candidates.iter() {
-                self.count_candidate(&mut vote_counts, c);
+                self.count_candidate(&mut vote_counts, c, &vote.weight);
             }
         }
         return vote_counts;
     }
 
-    /// Evaluates the results of a Single Transferable Vote election.
-    /// 
-    /// The Single Transferable Vote (STV) system is a proportional representation voting system
-    /// designed to achieve proportional representation through the use of multiple seats and vote transfer.
-    /// 
-    /// The algorithm works as follows:
-    /// 1. Count the initial votes for each candidate.
-    /// 2. Remove the worst performing candidate and redistribute its votes to the next preferred candidate.
-    /// 3. Repeat step 2 until a candidate has more than 50% of the votes.
-    /// 
-    /// The function returns a `ManagedVec` of `VotingResult` which holds the final vote counts for each candidate.
-    fn evaluate_single_transferable_vote(&self, election_id: ElectionID) -> ManagedVec<VotingResult> {
-        let mut votes_copy : ManagedVec<Vote<Self::Api>> = self.votes(election_id)
-                                                            .iter()
-                                                            .map(|vote| vote.clone())
-                                                            .collect();
-
-        // Count initial votes
-        let mut vote_counts: ManagedVec<VotingResult> = ManagedVec::new();
+    /// Evaluates the results of a Two-Round election.
+    ///
+    /// Votes are counted as in a Plurality election. If the candidate with the most votes obtained an
+    /// absolute majority of the votes, they win. Otherwise, a second round is created between the two
+    /// candidates with the most votes (see `create_runoff`), and both are stored in `ranking`.
+    /// A tie for a place in the second round is broken by `break_tie` (see `most_voted_candidates`).
+    ///
+    /// The function returns a `ManagedVec` of `VotingResult` which holds the votes of each candidate.
+    fn evaluate_two_round(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let vote_counts = self.evaluate_plurality_or_approval(election_id);
+        let total_votes = self.total_vote_weight(election_id);
+        let counts: ManagedVec<VotingResult<Self::Api>> = self.candidate_id_list(election_id).iter().map(|c| VotingResult {
+            candidate: c,
+            count: vote_counts.iter().find(|r| r.candidate == c).map_or(BigUint::zero(), |r| r.count),
+        }).collect();
+
+        // an absolute majority cannot be tied
+        if let Some(first) = counts.iter().find(|r| &r.count * 2u64 > total_votes) {
+            self.ranking(election_id).push(&first.candidate);
+            return vote_counts;
+        }
 
-        for vote in self.votes(election_id).iter() {
+        // no finalists are stored while the admin's casting vote is pending, and none without candidates
+        let Some(finalists) = self.most_voted_candidates(election_id, &counts, 2) else { return vote_counts };
+        for candidate in finalists.iter() {
+            self.ranking(election_id).push(&candidate);
+        }
+        if finalists.len() == 2 {
+            self.create_runoff(election_id, finalists.get(0), finalists.get(1));
+        }
+        vote_counts
+    }
 
-            // Only the first candidate is counted
-            let first_candidate: u16 = vote.candidates.get(0);
-            self.count_candidate(&mut vote_counts, first_candidate);
-        
+    /// Selects the `places` candidates with the most votes, given the votes of every candidate in registration order,
+    /// and returns them by number of votes.
+    ///
+    /// Candidates with equal votes are taken in registration order when they all get a place. When they outnumber
+    /// the remaining places, the tie is broken by `break_tie`, one place at a time.
+    /// Returns `None` while the admin's casting vote is pending.
+    fn most_voted_candidates(&self, election_id: ElectionID, counts: &ManagedVec<VotingResult<Self::Api>>, places: usize) -> Option<ManagedVec<CandidateID>> {
+        let mut remaining = counts.clone();
+        let mut selected: ManagedVec<CandidateID> = ManagedVec::new();
+        while selected.len() < places && !remaining.is_empty() {
+            let mut max_votes = BigUint::zero();
+            for result in remaining.iter() {
+                if result.count > max_votes {
+                    max_votes = result.count.clone();
+                }
+            }
+            let tied: ManagedVec<CandidateID> = remaining.iter().filter(|r| r.count == max_votes).map(|r| r.candidate).collect();
+            let next = if tied.len() <= places - selected.len() { tied.get(0) } else { self.break_tie(election_id, tied, false)? };
+            selected.push(next);
+            remaining = remaining.iter().filter(|r| r.candidate != next).collect();
         }
+        Some(selected)
+    }
 
-        // keep removing the worst performing candidate until you obtain majority
-        loop {
+    /// Creates the second round of a Two-Round election between two candidates.
+    ///
+    /// The second round is a Plurality election with the same name, description and admin, the two candidates
+    /// (and "None of the above" if the first round offered it), and the voters and voting settings of the first round
+    /// (see `copy_election_settings`). It takes place at the times set with `setRunoffSchedule`, or by default
+    /// starts now and lasts as long as the first round.
+    /// The rounds are linked through `next_round` and `previous_round` in their `ElectionData`.
+    fn create_runoff(&self, election_id: ElectionID, first_candidate: CandidateID, second_candidate: CandidateID) {
+        let first_round = self.election_data(election_id).get();
+        let schedule = if self.runoff_schedule(election_id).is_empty() {
+            let now = self.blockchain().get_block_timestamp();
+            RunoffSchedule { start_time: now, end_time: now + (first_round.end_time - first_round.start_time) }
+        } else {
+            self.runoff_schedule(election_id).get()
+        };
 
-            let mut worst : CandidateID = 0;
-            let mut min_vote = u64::MAX;
+        let runoff_id = self.generate_election_id();
+        let runoff = ElectionData {
+            id: runoff_id,
+            name: first_round.name,
+            description: first_round.description,
+            start_time: schedule.start_time,
+            end_time: schedule.end_time,
+            election_type: ElectionType::Plurality,
+            ended: false,
+            admin: first_round.admin,
+            seats: 1,
+            previous_round: Some(election_id),
+            next_round: None,
+            tie_break_policy: first_round.tie_break_policy,
+            turnout: 0,
+            void: false,
+        };
+        self.election_id_list().insert(runoff_id);
+        self.election_data(runoff_id).set(&runoff);
+        self.ballot_rules(runoff_id).set(BallotRules { min_selections: 1, max_selections: 1, full_ranking: false });
+        self.election_data(election_id).update(|election_data| {
+            election_data.next_round = Some(runoff_id);
+        });
 
-            for result in vote_counts.iter() {
+        for candidate_id in [first_candidate, second_candidate] {
+            self.candidate_id_list(runoff_id).insert(candidate_id);
+            self.candidate(runoff_id, candidate_id).set(self.candidate(election_id, candidate_id).get());
+        }
+        self.copy_election_settings(election_id, runoff_id);
+    }
 
-                // check if there is a winner(has more than 50% votes)
-                if result.count as usize > votes_copy.len() / 2 {
-                    return vote_counts;
-                }
+    /// Copies to the runoff or rerun of an election the settings that do not depend on its election type:
+    /// the voting token, blank ballots, reveal period, revoting, delegation, voter credentials, trustees (who register
+    /// new keys) and the "None of the above" option, then its registered voters (except revoked ones) and its voter roll.
+    fn copy_election_settings(&self, election_id: ElectionID, next_round_id: ElectionID) {
+        if !self.voting_token(election_id).is_empty() {
+            self.voting_token(next_round_id).set(self.voting_token(election_id).get());
+        }
+        self.blank_ballots_allowed(next_round_id).set(self.blank_ballots_allowed(election_id).get());
+        self.reveal_period(next_round_id).set(self.reveal_period(election_id).get());
+        self.revoting_allowed(next_round_id).set(self.revoting_allowed(election_id).get());
+        self.delegation_allowed(next_round_id).set(self.delegation_allowed(election_id).get());
+        self.credential_required(next_round_id).set(self.credential_required(election_id).get());
+        for trustee in self.trustees(election_id).iter() {
+            self.trustees(next_round_id).insert(trustee);
+        }
+        self.trustee_threshold(next_round_id).set(self.trustee_threshold(election_id).get());
+        if self.candidate_id_list(election_id).contains(&NONE_OF_THE_ABOVE) {
+            self.add_none_of_the_above(next_round_id);
+        }
 
-                // find worst performing candidate
-                if result.count < min_vote {
-                    min_vote = result.count;
-                    worst = result.candidate;
-                }
+        for voter_address in self.registered_voters(election_id).iter() {
+            if self.revoked_voters(election_id).contains(&voter_address) {
+                continue;
             }
+            self.registered_voters(next_round_id).insert(voter_address.clone());
+            self.mint_voter_credential(next_round_id, &voter_address);
+            self.voter_eligible(next_round_id, voter_address).set(true);
+        }
+        self.copy_voter_roll(election_id, next_round_id);
+    }
 
+    /// Copies the voter roll of an election and the weights of its registered voters to its runoff or rerun.
+    fn copy_voter_roll(&self, election_id: ElectionID, next_round_id: ElectionID) {
+        if self.voter_roll(election_id).is_empty() {
+            return;
+        }
+        self.voter_roll(next_round_id).set(self.voter_roll(election_id).get());
+        self.roll_registrations(next_round_id).set(self.roll_registrations(election_id).get());
+        for voter_address in self.registered_voters(election_id).iter() {
+            if !self.voter_weight(election_id, voter_address.clone()).is_empty() {
+                self.voter_weight(next_round_id, voter_address.clone()).set(self.voter_weight(election_id, voter_address).get());
+            }
+        }
+    }
 
-            // redistribute votes
+    /// Evaluates the results of a Referendum election.
+    ///
+    /// The turnout (all votes, including abstentions and blank ballots) must reach the quorum, a percentage of the electorate:
+    /// the registered voters, and if the election has a voter roll, the voters of the roll who did not register.
+    /// The proposal then passes if the Yes votes reach the pass threshold, a fraction of the Yes and No votes,
+    /// and outnumber the No votes. The counts and the outcome are stored in `referendum_result`, and `ranking`
+    /// holds `REFERENDUM_YES` if the proposal passed, `REFERENDUM_NO` if it was rejected, and nothing if the
+    /// quorum was not met.
+    ///
+    /// The function returns a `ManagedVec` of `VotingResult` which holds the votes of each choice.
+    fn evaluate_referendum(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let rules = self.referendum_rules(election_id).get();
+        let vote_counts = self.evaluate_plurality_or_approval(election_id);
+        let votes_for = |choice: CandidateID| {
+            vote_counts.iter().find(|r| r.candidate == choice).map_or(0, |r| r.count.to_u64().unwrap_or_default())
+        };
+        let yes_votes = votes_for(REFERENDUM_YES);
+        let no_votes = votes_for(REFERENDUM_NO);
+        let abstain_votes = votes_for(REFERENDUM_ABSTAIN);
+        let mut registered_voters = self.registered_voters(election_id).len() as u64;
+        if !self.voter_roll(election_id).is_empty() {
+            registered_voters += self.voter_roll(election_id).get().voters.saturating_sub(self.roll_registrations(election_id).get());
+        }
 
-            // for each vote
-            for i in 0..votes_copy.len() {
-                let mut vote = votes_copy.get_mut(i);
-                
-                // if the first candidate is the worst
-                // remove the first candidate and add the vote to the next first candidate
-                if vote.candidates.get(0) == worst {
+        let turnout = yes_votes + no_votes + abstain_votes + self.blank_ballots(election_id).get();
+        let outcome = if turnout * 100 < rules.quorum_percent * registered_voters {
+            ReferendumOutcome::QuorumNotMet
+        } else if yes_votes > no_votes && yes_votes * rules.threshold_denominator >= (yes_votes + no_votes) * rules.threshold_numerator {
+            self.ranking(election_id).push(&REFERENDUM_YES);
+            ReferendumOutcome::Passed
+        } else {
+            self.ranking(election_id).push(&REFERENDUM_NO);
+            ReferendumOutcome::Rejected
+        };
 
-                    // remove the first candidate
-                    vote.candidates.remove(0);
+        self.referendum_result(election_id).set(ReferendumResult { yes_votes, no_votes, abstain_votes, registered_voters, outcome });
+        vote_counts
+    }
 
-                    // find the next first candidate
-                    let next_c = vote.candidates.get(0);
+    /// Evaluates the results of a Party-List election.
+    ///
+    /// Each vote is for a single party. Parties that received less than the electoral threshold
+    /// (a percentage of all votes) do not receive seats. The seats are then allocated one by one
+    /// to the party with the highest quotient:
+    /// - D'Hondt: `votes / (seats + 1)`;
+    /// - Sainte-Laguë: `votes / (2 * seats + 1)`;
+    /// where `seats` is the number of seats already allocated to the party. A party cannot receive more seats
+    /// than it has candidates. Parties tied for the highest quotient receive their seats in registration order,
+    /// unless they outnumber the remaining seats: the tie is then broken by `break_tie`.
+    ///
+    /// Each seat is taken by the next candidate of the party list, the elected candidates are stored in `ranking`
+    /// in the order their seats were allocated. The votes and seats of each party are stored in `party_results`.
+    ///
+    /// The function returns a `ManagedVec` of `VotingResult` which holds, for each elected candidate,
+    /// the votes of their party.
+    fn evaluate_party_list(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let seats = self.election_data(election_id).get().seats;
+        let rules = self.party_list_rules(election_id).get();
+        let parties: ManagedVec<PartyID> = self.party_id_list(election_id).iter().collect();
+        let party_votes = self.evaluate_plurality_or_approval(election_id);
+        let total_votes = self.total_vote_weight(election_id);
+
+        // the votes of each party reaching the threshold (0 otherwise) and the seats allocated to it
+        let mut votes: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        let mut allocated: ManagedVec<u32> = ManagedVec::new();
+        for party in parties.iter() {
+            let count = party_votes.iter().find(|r| r.candidate == party).map_or(BigUint::zero(), |r| r.count);
+            let eligible = &count * 100u64 >= &total_votes * rules.threshold_percent;
+            votes.push(VotingResult { candidate: party, count: if eligible { count } else { BigUint::zero() } });
+            allocated.push(0);
+        }
 
-                    // add the vote to the next first candidate
-                    self.count_candidate(&mut vote_counts, next_c);
+        let mut vote_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        for seat in 0..seats {
+            // find the parties with the highest quotient, comparing votes_a / divisor_a > votes_b / divisor_b
+            // as votes_a * divisor_b > votes_b * divisor_a
+            let mut best: ManagedVec<usize> = ManagedVec::new();
+            let mut best_votes = BigUint::zero();
+            let mut best_divisor = 1u64;
+            for (i, party) in parties.iter().enumerate() {
+                let party_votes = votes.get(i).count;
+                if party_votes == 0u64 || allocated.get(i) as usize >= self.party_list(election_id, party).len() {
+                    continue;
                 }
-                // remove the worst candidate if it has remained otherwise
-                if let Some(index) = vote.candidates.iter().position(|c| c == worst) {
-                    vote.candidates.remove(index);
+                let divisor = match rules.method {
+                    SeatAllocationMethod::DHondt => allocated.get(i) as u64 + 1,
+                    SeatAllocationMethod::SainteLague => 2 * allocated.get(i) as u64 + 1,
+                };
+                let quotient = &party_votes * best_divisor;
+                let best_quotient = &best_votes * divisor;
+                if best.is_empty() || quotient > best_quotient {
+                    best = ManagedVec::from_single_item(i);
+                    best_votes = party_votes;
+                    best_divisor = divisor;
+                } else if quotient == best_quotient {
+                    best.push(i);
                 }
             }
+            if best.is_empty() {
+                break;
+            }
+
+            // tied parties all receive a seat unless they outnumber the remaining seats
+            let i = if best.len() as u32 <= seats - seat {
+                best.get(0)
+            } else {
+                let tied_parties: ManagedVec<PartyID> = best.iter().map(|i| parties.get(i)).collect();
+                let Some(party) = self.break_tie(election_id, tied_parties, false) else { return vote_counts };
+                parties.iter().position(|p| p == party).unwrap_or_default()
+            };
+            let party = parties.get(i);
+            let _ = allocated.set(i, allocated.get(i) + 1);
+            let candidate = self.party_list(election_id, party).get(allocated.get(i) as usize);
+            self.ranking(election_id).push(&candidate);
+            vote_counts.push(VotingResult { candidate, count: votes.get(i).count });
         }
-    }
 
+        self.party_results(election_id).clear();
+        for (i, party) in parties.iter().enumerate() {
+            let count = party_votes.iter().find(|r| r.candidate == party).map_or(BigUint::zero(), |r| r.count);
+            self.party_results(election_id).push(&PartyResult { party, votes: count, seats: allocated.get(i) });
+        }
+        vote_counts
+    }
 
+    /// Evaluates the results of a Single Transferable Vote election.
+    /// 
+    /// The Single Transferable Vote (STV) system is a proportional representation voting system
+    /// designed to achieve proportional representation through the use of multiple seats and vote transfer.
+    /// With a single seat, it is the same as instant-runoff voting (IRV).
+    /// 
+    /// The algorithm works as follows:
+    /// 1. Count each vote for its most preferred candidate that is still in the count (continuing).
+    ///    A vote without continuing candidates is exhausted and is no longer counted.
+    /// 2. Compute the quota: with a single seat, a majority of the continuing (not exhausted) votes,
+    ///    otherwise the Droop quota `floor(votes / (seats + 1)) + 1`.
+    /// 3. If a candidate reaches the quota, elect the candidate with the most votes and transfer its surplus:
+    ///    every vote counted for the elected candidate continues to its next preference, with its value
+    ///    multiplied by `surplus / candidate votes` (Gregory method).
+    /// 4. Otherwise, eliminate the candidate with the least votes; its votes continue to their next preference
+    ///    with their current value.
+    /// 5. Repeat from step 1 until all seats are filled, or until the continuing candidates
+    ///    can fill the remaining seats, in which case they are all elected.
+    ///
+    /// Ties are resolved as follows:
+    /// - when several candidates reaching the quota have the most votes, they are elected in registration order,
+    ///   unless they outnumber the remaining seats: the elected candidate is then chosen by `break_tie`;
+    /// - when several candidates have the least votes, the one with the least votes in the latest previous
+    ///   round where their votes differ is eliminated; if they had the same votes in every round,
+    ///   the eliminated candidate is chosen by `break_tie` (the one registered last by default).
+    ///
+    /// Vote values are fixed-point numbers (see `STV_VOTE_PRECISION`). Every round is stored in `stv_rounds`
+    /// and the elected candidates are stored in `ranking`, in the order they were elected.
+    ///
+    /// The function returns a `ManagedVec` of `VotingResult` which holds, for each candidate, the votes
+    /// it held in the last count before it was elected or eliminated (or in the final count).
+    fn evaluate_single_transferable_vote(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let seats = self.election_data(election_id).get().seats as usize;
+        let candidates: ManagedVec<CandidateID> = self.candidate_id_list(election_id).iter().collect();
+        let n = candidates.len();
+        let votes = self.counted_votes(election_id);
+        self.stv_rounds(election_id).clear();
+
+        // candidate state: 0 = continuing, 1 = elected, 2 = eliminated
+        let mut state: ManagedVec<u8> = ManagedVec::new();
+        let mut totals: ManagedVec<u64> = ManagedVec::new();
+        for _ in 0..n {
+            state.push(0);
+            totals.push(0);
+        }
 
+        // value of each vote
+        let mut values: ManagedVec<u64> = ManagedVec::new();
+        let mut valid_votes = 0u64;
+        for vote in votes.iter() {
+            values.push(STV_VOTE_PRECISION);
+            if !vote.candidates.is_empty() {
+                valid_votes += 1;
+            }
+        }
+        let droop_quota = (valid_votes / (seats as u64 + 1) + 1) * STV_VOTE_PRECISION;
 
-    #[view(result_vector)]
-    #[storage_mapper("result_vector")]
-    fn result_vector(&self, election_id: ElectionID, candidate_id: CandidateID) -> SingleValueMapper<u64>;
+        // the votes of each candidate in every previous round, `n` values per round
+        let mut history: ManagedVec<u64> = ManagedVec::new();
+        let mut elected = 0;
+        while elected < seats {
 
-    #[storage_mapper("finished_election")]
-    fn finished_election(&self, election_id: ElectionID) -> SingleValueMapper<bool>;
+            // count each vote for its most preferred continuing candidate
+            let mut round_totals: ManagedVec<u64> = ManagedVec::new();
+            for _ in 0..n {
+                round_totals.push(0);
+            }
+            let mut exhausted_votes = 0u64;
+            let mut exhausted_value = 0u64;
+            let mut continuing_value = 0u64;
+            for (i, vote) in votes.iter().enumerate() {
+                match self.stv_preferred_candidate(&vote, &candidates, &state) {
+                    Some(c) => {
+                        let _ = round_totals.set(c, round_totals.get(c) + values.get(i));
+                        continuing_value += values.get(i);
+                    },
+                    None => {
+                        exhausted_votes += 1;
+                        exhausted_value += values.get(i);
+                    }
+                }
+            }
 
-    /// Determines the winning candidate of an election based on its type.
-    ///
-    /// This function verifies that the specified election has ended and then evaluates
-    /// the votes according to the election type. It supports Plurality, Approval, and 
-    /// Single Transferable Vote election types. The function returns the candidate ID 
-    /// with the highest number of votes.
-    ///
-    /// # Arguments
-    ///
-    /// * `election_id` - The identifier of the election whose results should be computed.
-    ///
-    /// # Returns
-    ///
-    /// The candidate ID of the winning candidate.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the election does not exist or has not ended yet.
-    #[view(results)]
-    fn results(&self, election_id: ElectionID) -> CandidateID {
-        require!(self.election_id_list().contains(&election_id), "Election does not exist");
-        require!(self.election_data(election_id).get().end_time > self.blockchain().get_block_timestamp(), "Election has not ended yet");
+            let continuing: ManagedVec<usize> = (0..n).filter(|&c| state.get(c) == 0).collect();
+            if continuing.is_empty() {
+                break;
+            }
+            let mut counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+            for c in continuing.iter() {
+                let _ = totals.set(c, round_totals.get(c));
+                counts.push(VotingResult { candidate: candidates.get(c), count: BigUint::from(round_totals.get(c)) });
+            }
 
-        if !self.finished_election(election_id).get() {
-            let vote_counts = match self.election_data(election_id).get().election_type {
-                ElectionType::Plurality => self.evaluate_plurality_or_approval(election_id),
-                ElectionType::Approval => self.evaluate_plurality_or_approval(election_id),
-                ElectionType::SingleTransferableVote => self.evaluate_single_transferable_vote(election_id),
+            let quota = if seats == 1 { continuing_value / 2 + 1 } else { droop_quota };
+            let mut round = StvRound {
+                counts,
+                quota,
+                exhausted_votes,
+                exhausted_value,
+                elected: ManagedVec::new(),
+                eliminated: 0,
             };
 
-
-            for result in vote_counts.iter() {
-                self.result_vector(election_id, result.candidate).set(&result.count);
+            // the continuing candidates can fill the remaining seats, elect them by number of votes
+            if elected + continuing.len() <= seats {
+                let mut remaining = continuing;
+                while !remaining.is_empty() {
+                    let mut best = 0;
+                    for k in 1..remaining.len() {
+                        if round_totals.get(remaining.get(k)) > round_totals.get(remaining.get(best)) {
+                            best = k;
+                        }
+                    }
+                    let c = remaining.take(best);
+                    let _ = state.set(c, 1);
+                    round.elected.push(candidates.get(c));
+                    self.ranking(election_id).push(&candidates.get(c));
+                }
+                self.stv_rounds(election_id).push(&round);
+                break;
             }
-            self.finished_election(election_id).set(&true);
-        }
 
-        let vote_counts : ManagedVec<VotingResult> = self.candidate_id_list(election_id).iter().map(|c_id| VotingResult {candidate: c_id, count: self.result_vector(election_id, c_id).get()}).collect();
+            // find the continuing candidate with the most votes
+            let mut most = continuing.get(0);
+            for c in continuing.iter() {
+                if round_totals.get(c) > round_totals.get(most) {
+                    most = c;
+                }
+            }
 
-        // find the candidate with the most votes
+            if round_totals.get(most) >= quota {
+                // candidates tied for the most votes are all elected in turn, unless they outnumber the remaining seats
+                let tied: ManagedVec<CandidateID> = continuing.iter()
+                    .filter(|&c| round_totals.get(c) == round_totals.get(most))
+                    .map(|c| candidates.get(c))
+                    .collect();
+                if tied.len() > seats - elected {
+                    let Some(chosen) = self.break_tie(election_id, tied, false) else { break };
+                    most = candidates.iter().position(|c| c == chosen).unwrap_or(most);
+                }
 
-        let mut max_votes = 0;
-        let mut winning_candidate = 0;
-        for result in vote_counts.iter() {
-            if result.count > max_votes {
-                max_votes = result.count;
-                winning_candidate = result.candidate;
+                // elect the candidate and transfer its surplus
+                let total = round_totals.get(most);
+                let surplus = total - quota;
+                for (i, vote) in votes.iter().enumerate() {
+                    if self.stv_preferred_candidate(&vote, &candidates, &state) == Some(most) {
+                        let value = (values.get(i) as u128 * surplus as u128 / total as u128) as u64;
+                        let _ = values.set(i, value);
+                    }
+                }
+                let _ = state.set(most, 1);
+                round.elected.push(candidates.get(most));
+                self.ranking(election_id).push(&candidates.get(most));
+                elected += 1;
+            } else {
+                // eliminate the candidate, its votes are transferred with their current value
+                let least_candidates = self.stv_least_candidates(&continuing, &round_totals, &history, n);
+                let least = if least_candidates.len() == 1 {
+                    least_candidates.get(0)
+                } else {
+                    let tied: ManagedVec<CandidateID> = least_candidates.iter().map(|c| candidates.get(c)).collect();
+                    let Some(chosen) = self.break_tie(election_id, tied, true) else { break };
+                    candidates.iter().position(|c| c == chosen).unwrap_or_default()
+                };
+                let _ = state.set(least, 2);
+                round.eliminated = candidates.get(least);
             }
-        }
 
-        return winning_candidate
+            history.append_vec(round_totals);
+            self.stv_rounds(election_id).push(&round);
+        }
 
+        let mut vote_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        for c in 0..n {
+            vote_counts.push(VotingResult { candidate: candidates.get(c), count: BigUint::from(totals.get(c) / STV_VOTE_PRECISION) });
+        }
+        vote_counts
     }
 
-    #[view(electionList)]
-    fn election_list(&self) -> MultiValueEncoded<ElectionData<Self::Api>> {
-        let election_ids = self.election_id_list();
-        let election_data_iter = election_ids.iter().map(|id| self.election_data(id).get());
-        MultiValueEncoded::from_iter(election_data_iter)
+    /// Finds the continuing candidates to eliminate in a Single Transferable Vote count.
+    ///
+    /// These are the candidates with the least votes in the current round. Among tied candidates, only the ones with
+    /// the least votes in the latest previous round where their votes differ are kept. Candidates that had the same
+    /// votes in every round are all returned, in registration order, and the tie between them is broken by `break_tie`.
+    fn stv_least_candidates(&self, continuing: &ManagedVec<usize>, round_totals: &ManagedVec<u64>, history: &ManagedVec<u64>, n: usize) -> ManagedVec<usize> {
+        let least_votes = continuing.iter().map(|c| round_totals.get(c)).min().unwrap_or_default();
+        let mut least: ManagedVec<usize> = continuing.iter().filter(|&c| round_totals.get(c) == least_votes).collect();
+
+        let mut round = history.len() / n;
+        while least.len() > 1 && round > 0 {
+            round -= 1;
+            let fewest_votes = least.iter().map(|c| history.get(round * n + c)).min().unwrap_or_default();
+            least = least.iter().filter(|&c| history.get(round * n + c) == fewest_votes).collect();
+        }
+        least
     }
 
-    
+    /// Finds the most preferred continuing candidate of a vote in a Single Transferable Vote count.
+    ///
+    /// Returns the index of the candidate in `candidates`, or `None` if the vote has no continuing candidate.
+    fn stv_preferred_candidate(&self, vote: &Vote<Self::Api>, candidates: &ManagedVec<CandidateID>, state: &ManagedVec<u8>) -> Option<usize> {
+        for c in vote.candidates.iter() {
+            if let Some(index) = candidates.iter().position(|candidate| candidate == c) {
+                if state.get(index) == 0 {
+                    return Some(index);
+                }
+            }
+        }
+        None
+    }
 
-    /// Generates a unique election ID.
+    /// Evaluates the results of a Borda count election.
+    ///
+    /// Each vote is an ordered list of candidates. With `n` registered candidates, the first
+    /// candidate of a vote receives `n - 1` points, the second `n - 2` points and so on.
+    /// Candidates missing from a vote receive no points from it. The points are multiplied by the weight of the vote.
+    ///
+    /// The function returns a `ManagedVec` of `VotingResult` which holds the point totals for each candidate.
+    fn evaluate_borda(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let candidate_count = self.candidate_id_list(election_id).len() as u64;
 
-    /// This function generates a random election ID and ensures that it is not already in use.
-    fn generate_election_id(&self) -> ElectionID {
+        let mut vote_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
 
-        let mut rand_source = RandomnessSource::new();
-        loop {
-            let election_id = rand_source.next_u64() as ElectionID;
-            if !self.election_id_list().contains(&election_id) {
-                return election_id;
+        for vote in self.counted_votes(election_id).iter() {
+            for (position, c) in vote.candidates.iter().enumerate() {
+                let points = candidate_count.saturating_sub(position as u64 + 1);
+                self.count_candidate_points(&mut vote_counts, c, &vote.weight * points);
             }
         }
+        vote_counts
     }
 
-    /// Generates a unique candidate ID for the given election.
+    /// Evaluates the results of a Score election.
+    ///
+    /// Each vote rates candidates on the election's rating scale. A candidate that is not rated
+    /// in a vote receives the lowest rating of the scale from that vote. The ratings are multiplied by the weight of the vote.
+    /// The average rating of each candidate (over all votes) is stored in `result_average`.
+    ///
+    /// The function returns a `ManagedVec` of `VotingResult` which holds the total rating of each candidate.
+    fn evaluate_score(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let min_rating = self.rating_scale(election_id).get().min;
+        let total_votes = self.total_vote_weight(election_id);
+
+        // sum of the explicit ratings and weight of the votes that rated each candidate
+        let mut rating_sums: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        let mut rating_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        for vote in self.counted_votes(election_id).iter() {
+            for (c, rating) in vote.candidates.iter().zip(vote.ratings.iter()) {
+                self.count_candidate_points(&mut rating_sums, c, &vote.weight * rating as u64);
+                self.count_candidate(&mut rating_counts, c, &vote.weight);
+            }
+        }
 
-    /// This function generates a random candidate ID and ensures that it is not already in use
-    /// by either the list of candidates or the list of potential candidates for the given election.
-    fn generate_candidate_id(&self, election_id: ElectionID) -> CandidateID {
+        let mut vote_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        for c in self.candidate_id_list(election_id).iter() {
+            let rated_sum = rating_sums.iter().find(|r| r.candidate == c).map_or(BigUint::zero(), |r| r.count);
+            let rated_count = rating_counts.iter().find(|r| r.candidate == c).map_or(BigUint::zero(), |r| r.count);
+            let total = rated_sum + (&total_votes - &rated_count) * min_rating;
 
-        let mut rand_source = RandomnessSource::new();
-        loop {
-            let candidate_id = rand_source.next_u64() as CandidateID;
-            if !self.candidate_id_list(election_id).contains(&candidate_id) && !self.potential_candidate_id_list(election_id).contains(&candidate_id) {
-                return candidate_id;
-            }
+            let average = if total_votes == 0u64 { BigUint::zero() } else { &total * AVERAGE_PRECISION / &total_votes };
+            self.result_average(election_id, c).set(average.to_u64().unwrap_or(0));
+
+            vote_counts.push(VotingResult { candidate: c, count: total });
         }
+        vote_counts
     }
 
-    /// Generates a unique dispute ID for the given election.
+    /// Evaluates the results of a Cumulative election.
     ///
-    /// This function generates a random dispute ID and ensures that it is not already in use by
-    /// the list of disputes for the given election.
-    fn generate_dispute_id(&self, election_id: ElectionID) -> DisputeID {
-
-        let mut rand_source = RandomnessSource::new();
-        loop {
-            let dispute_id = rand_source.next_u64() as DisputeID;
-            if !self.dispute_id_list(election_id).contains(&dispute_id) {
-                return dispute_id;
+    /// Each vote spreads at most the election's point budget across candidates.
+    ///
+    /// The function returns a `ManagedVec` of `VotingResult` which holds the total points of each candidate.
+    fn evaluate_cumulative(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let mut vote_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        for vote in self.counted_votes(election_id).iter() {
+            for (c, points) in vote.candidates.iter().zip(vote.ratings.iter()) {
+                self.count_candidate_points(&mut vote_counts, c, &vote.weight * points as u64);
             }
         }
+        vote_counts
     }
 
+    /// Evaluates the results of a Quadratic election.
+    ///
+    /// Each vote gives `k` votes for (or, with a negative rating, against) a candidate for `k^2` voice credits,
+    /// without spending more than the election's voice credit budget. The net votes of each candidate (votes for
+    /// minus votes against, multiplied by the weight of the votes) are stored in `net_votes`, and the candidate
+    /// with the most net votes wins (ties are broken by the election's tie-break policy, see `break_tie`).
+    ///
+    /// The function returns a `ManagedVec` of `VotingResult` which holds the net votes of each candidate,
+    /// 0 for candidates with more votes against than for them.
+    fn evaluate_quadratic(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        for c in self.candidate_id_list(election_id).iter() {
+            self.net_votes(election_id, c).clear();
+        }
+        for vote in self.counted_votes(election_id).iter() {
+            let weight = BigInt::from_biguint(Sign::Plus, vote.weight.clone());
+            for (c, votes) in vote.candidates.iter().zip(vote.ratings.iter()) {
+                self.net_votes(election_id, c).update(|net_votes| *net_votes += &weight * &BigInt::from(votes));
+            }
+        }
 
-    
+        if let Some(winner) = self.most_voted_candidate(election_id, |c| self.net_votes(election_id, c).get()) {
+            self.ranking(election_id).push(&winner);
+        }
 
+        self.candidate_id_list(election_id).iter().map(|c| VotingResult {
+            candidate: c,
+            count: self.net_votes(election_id, c).get().into_big_uint().into_option().unwrap_or_default(),
+        }).collect()
+    }
 
-    #[endpoint(registerElection)]
-    fn register_election(&self, name: ManagedBuffer, description: ManagedBuffer, election_type: u64, start_time: u64, end_time: u64) -> ElectionID {
+    /// Returns the total weight of the votes of an election (their number, unless the election is token-weighted).
+    fn total_vote_weight(&self, election_id: ElectionID) -> BigUint {
+        let mut total = BigUint::zero();
+        for vote in self.counted_votes(election_id).iter() {
+            total += &vote.weight;
+        }
+        total
+    }
 
-        let election_id = self.generate_election_id();
-        require!(name.len() > 0, "Name cannot be empty");
-        require!(name.len() <= 50, "Name cannot be longer than 50 characters");
-        require!(description.len() > 0, "Description cannot be empty");
-        require!(description.len() <= 200, "Description cannot be longer than 200 characters");
-        require!(election_type < 3, "Invalid election type");
-        let election_type: ElectionType = match election_type {
-            0 => ElectionType::Plurality,
-            1 => ElectionType::Approval,
-            2 => ElectionType::SingleTransferableVote,
-            _ => ElectionType::Plurality
-        };
-        let election_data = ElectionData {
-            id: election_id,
-            name,
-            description,
-            election_type: election_type,
-            start_time,
-            end_time,
-            ended: false,
-            admin: self.blockchain().get_caller(),
+    /// Returns the rating given to a candidate in a vote, or `default` if the vote does not rate the candidate.
+    fn vote_rating(&self, vote: &Vote<Self::Api>, candidate_id: CandidateID, default: u64) -> u64 {
+        match vote.candidates.iter().position(|c| c == candidate_id) {
+            Some(index) => vote.ratings.get(index) as u64,
+            None => default,
+        }
+    }
+
+    /// Evaluates the results of a STAR (Score Then Automatic Runoff) election.
+    ///
+    /// The algorithm works as follows:
+    /// 1. Score stage: the ratings are added up as in a Score election.
+    /// 2. The two candidates with the highest total rating become finalists
+    ///    (a tie for a place in the runoff is broken by `break_tie`, see `most_voted_candidates`).
+    /// 3. Runoff stage: each vote supports the finalist it rated higher (votes rating both finalists
+    ///    the same have no preference). The finalist supported by more votes wins. If the runoff is tied,
+    ///    the finalist with the higher total rating wins, and if their totals are also equal, the tie is broken by `break_tie`.
+    ///
+    /// Both stages are stored in `star_result` and the winner in `ranking`.
+    /// The function returns a `ManagedVec` of `VotingResult` which holds the total rating of each candidate.
+    fn evaluate_star(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let scores = self.evaluate_score(election_id);
+        let min_rating = self.rating_scale(election_id).get().min;
+
+        // find the two finalists (no winner is stored while the admin's casting vote is pending, and none without candidates)
+        let Some(finalists) = self.most_voted_candidates(election_id, &scores, 2) else { return scores };
+        if finalists.is_empty() {
+            return scores;
+        }
+        let first_finalist = finalists.get(0);
+        let second_finalist = if finalists.len() > 1 { finalists.get(1) } else { 0 };
+
+        // automatic runoff between the finalists
+        let mut first_finalist_votes = 0;
+        let mut second_finalist_votes = 0;
+        let mut no_preference = 0;
+        for vote in self.counted_votes(election_id).iter() {
+            let first_rating = self.vote_rating(&vote, first_finalist, min_rating);
+            let second_rating = self.vote_rating(&vote, second_finalist, min_rating);
+            if first_rating > second_rating {
+                first_finalist_votes += 1;
+            } else if second_rating > first_rating {
+                second_finalist_votes += 1;
+            } else {
+                no_preference += 1;
+            }
+        }
+
+        // a tied runoff is won by the finalist with the higher total rating, the first finalist unless their totals are equal
+        let total_rating = |candidate: CandidateID| scores.iter().find(|r| r.candidate == candidate).map_or(BigUint::zero(), |r| r.count);
+        let winner = if second_finalist_votes > first_finalist_votes {
+            second_finalist
+        } else if first_finalist_votes > second_finalist_votes || second_finalist == 0 || total_rating(first_finalist) > total_rating(second_finalist) {
+            first_finalist
+        } else {
+            let tied: ManagedVec<CandidateID> = self.candidate_id_list(election_id).iter()
+                .filter(|&c| c == first_finalist || c == second_finalist)
+                .collect();
+            let Some(winner) = self.break_tie(election_id, tied, false) else { return scores };
+            winner
         };
-        self.election_id_list().insert(election_id);
-        self.election_data(election_id).set(&election_data);
 
-        return election_id;
+        self.star_result(election_id).set(StarResult {
+            scores: scores.clone(),
+            first_finalist,
+            second_finalist,
+            first_finalist_votes,
+            second_finalist_votes,
+            no_preference,
+            winner,
+        });
+        self.ranking(election_id).push(&winner);
+        scores
+    }
+
+    /// Evaluates the results of a Majority Judgment election.
+    ///
+    /// Each vote grades candidates on the election's grades (ratings are grade indexes, 0 being the worst grade).
+    /// A candidate that is not graded in a vote receives the worst grade from that vote.
+    ///
+    /// The majority grade of a candidate is its lower median grade. The candidate with the best majority grade wins.
+    /// Ties are broken with the standard procedure: one vote with the majority grade is removed from each
+    /// tied candidate and their majority grades are compared again, until they differ. If they never differ,
+    /// the tie is broken by `break_tie`.
+    ///
+    /// The grade histogram and majority grade of each candidate are stored in `grade_results`.
+    /// The function returns a `ManagedVec` of `VotingResult` which holds the majority grade of each candidate.
+    fn evaluate_majority_judgment(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let grade_count = self.grades(election_id).len();
+        let votes = self.counted_votes(election_id);
+        let total_votes = votes.len() as u64;
+        self.grade_results(election_id).clear();
+
+        let mut vote_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        // the candidates tied for the best majority grade, and the histogram of the first of them
+        let mut best: ManagedVec<CandidateID> = ManagedVec::new();
+        let mut best_histogram: ManagedVec<u64> = ManagedVec::new();
+        for c in self.candidate_id_list(election_id).iter() {
+            let mut histogram: ManagedVec<u64> = ManagedVec::new();
+            for _ in 0..grade_count {
+                histogram.push(0);
+            }
+            for vote in votes.iter() {
+                let grade = self.vote_rating(&vote, c, 0) as usize;
+                let _ = histogram.set(grade, histogram.get(grade) + 1);
+            }
+
+            let result = GradeResult { candidate: c, median_grade: self.median_grade(&histogram, total_votes) as u64, histogram };
+            self.grade_results(election_id).push(&result);
+            vote_counts.push(VotingResult { candidate: c, count: BigUint::from(result.median_grade) });
+
+            if best.is_empty() || self.majority_judgment_beats(&result.histogram, &best_histogram, total_votes) {
+                best = ManagedVec::from_single_item(c);
+                best_histogram = result.histogram;
+            } else if !self.majority_judgment_beats(&best_histogram, &result.histogram, total_votes) {
+                best.push(c);
+            }
+        }
+
+        let winner = match best.len() {
+            0 => None,
+            1 => Some(best.get(0)),
+            _ => self.break_tie(election_id, best, false),
+        };
+        if let Some(winner) = winner {
+            self.ranking(election_id).push(&winner);
+        }
+        vote_counts
+    }
+
+    /// Returns the index of the lower median grade of a grade histogram holding `total_votes` votes.
+    fn median_grade(&self, histogram: &ManagedVec<u64>, total_votes: u64) -> usize {
+        let mut votes = 0;
+        for (grade, count) in histogram.iter().enumerate() {
+            votes += count;
+            // the lower median is the vote at index (total_votes - 1) / 2, counting from the worst grade
+            if votes * 2 >= total_votes {
+                return grade;
+            }
+        }
+        0
+    }
+
+    /// Returns true if the candidate with the grade histogram `a` beats the candidate with the grade histogram `b`
+    /// in a Majority Judgment election, breaking ties between majority grades by removing them one vote at a time.
+    fn majority_judgment_beats(&self, a: &ManagedVec<u64>, b: &ManagedVec<u64>, total_votes: u64) -> bool {
+        let mut a = a.clone();
+        let mut b = b.clone();
+        let mut remaining = total_votes;
+        while remaining > 0 {
+            let median_a = self.median_grade(&a, remaining);
+            let median_b = self.median_grade(&b, remaining);
+            if median_a != median_b {
+                return median_a > median_b;
+            }
+            let _ = a.set(median_a, a.get(median_a) - 1);
+            let _ = b.set(median_b, b.get(median_b) - 1);
+            remaining -= 1;
+        }
+        false
+    }
+
+    /// Builds the pairwise preference matrix of a ranked election.
+    ///
+    /// The matrix is stored as a flat `ManagedVec` of size `n * n`, where `n` is the number of
+    /// candidates. The element at `i * n + j` holds the number of votes that rank `candidates[i]`
+    /// above `candidates[j]`. A ranked candidate is preferred to every candidate missing from the vote,
+    /// while candidates missing from the vote are not preferred to one another.
+    ///
+    /// # Arguments
+    ///
+    /// * `election_id` - The identifier of the election whose votes should be counted.
+    /// * `candidates` - The candidates of the election, giving the order of the rows and columns.
+    fn pairwise_matrix(&self, election_id: ElectionID, candidates: &ManagedVec<CandidateID>) -> ManagedVec<u64> {
+        let n = candidates.len();
+
+        let mut matrix: ManagedVec<u64> = ManagedVec::new();
+        for _ in 0..n * n {
+            matrix.push(0);
+        }
+
+        for vote in self.counted_votes(election_id).iter() {
+
+            // position of each candidate in the vote (u64::MAX if not ranked)
+            let mut rank: ManagedVec<u64> = ManagedVec::new();
+            for _ in 0..n {
+                rank.push(u64::MAX);
+            }
+            for (position, c) in vote.candidates.iter().enumerate() {
+                if let Some(index) = candidates.iter().position(|candidate| candidate == c) {
+                    if rank.get(index) == u64::MAX {
+                        let _ = rank.set(index, position as u64);
+                    }
+                }
+            }
+
+            for i in 0..n {
+                for j in 0..n {
+                    if i != j && rank.get(i) < rank.get(j) {
+                        let _ = matrix.set(i * n + j, matrix.get(i * n + j) + 1);
+                    }
+                }
+            }
+        }
+        matrix
+    }
+
+    /// Evaluates the results of a Schulze election.
+    ///
+    /// The algorithm works as follows:
+    /// 1. Build the pairwise preference matrix `d`, where `d[i][j]` is the number of votes ranking `i` above `j`.
+    /// 2. Compute the strength of the strongest path `p[i][j]` from each candidate to every other candidate,
+    ///    where the strength of a path is the weakest head-to-head victory along it.
+    /// 3. Candidate `i` beats candidate `j` if `p[i][j] > p[j][i]`.
+    ///
+    /// The function returns a `ManagedVec` of `VotingResult` which holds, for each candidate, the number
+    /// of other candidates it beats. The winner beats every other candidate, so it has the highest count.
+    fn evaluate_schulze(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let candidates: ManagedVec<CandidateID> = self.candidate_id_list(election_id).iter().collect();
+        let n = candidates.len();
+        let d = self.pairwise_matrix(election_id, &candidates);
+
+        // strength of the direct links
+        let mut p: ManagedVec<u64> = ManagedVec::new();
+        for i in 0..n {
+            for j in 0..n {
+                let d_ij = d.get(i * n + j);
+                if i != j && d_ij > d.get(j * n + i) {
+                    p.push(d_ij);
+                } else {
+                    p.push(0);
+                }
+            }
+        }
+
+        // strength of the strongest paths
+        for i in 0..n {
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                for k in 0..n {
+                    if i == k || j == k {
+                        continue;
+                    }
+                    let through_i = core::cmp::min(p.get(j * n + i), p.get(i * n + k));
+                    if through_i > p.get(j * n + k) {
+                        let _ = p.set(j * n + k, through_i);
+                    }
+                }
+            }
+        }
+
+        let mut vote_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        for i in 0..n {
+            let mut wins = 0;
+            for j in 0..n {
+                if i != j && p.get(i * n + j) > p.get(j * n + i) {
+                    wins += 1;
+                }
+            }
+            vote_counts.push(VotingResult { candidate: candidates.get(i), count: BigUint::from(wins as u64) });
+        }
+        vote_counts
+    }
+
+    /// Evaluates the results of a Ranked Pairs (Tideman) election.
+    ///
+    /// The algorithm works as follows:
+    /// 1. Build the pairwise preference matrix and collect every head-to-head victory.
+    /// 2. Sort the victories by their margin (largest first). Equal margins are ordered by the number of
+    ///    votes for the winner (largest first).
+    /// 3. Lock the victories one by one, skipping any victory that would create a cycle with the ones already locked.
+    ///    Equally strong victories are locked in registration order, unless some of them create a cycle: the order
+    ///    then decides which ones are skipped, so the victories of the winner chosen by `break_tie` are locked first.
+    /// 4. The locked victories form an acyclic graph that gives the final ordering of the candidates:
+    ///    the first candidate is the one that no remaining candidate is locked over, and so on.
+    ///    A tie for the first place is broken by `break_tie`, candidates tied for a later place are ordered by registration.
+    ///
+    /// The locked victories are stored in `locked_pairs` and the final ordering in `ranking`.
+    /// The function returns a `ManagedVec` of `VotingResult` which holds, for each candidate, the number
+    /// of candidates ranked below it in the final ordering.
+    fn evaluate_ranked_pairs(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        let candidates: ManagedVec<CandidateID> = self.candidate_id_list(election_id).iter().collect();
+        let n = candidates.len();
+        let d = self.pairwise_matrix(election_id, &candidates);
+
+        // collect the head-to-head victories as (winner index, loser index)
+        let mut pairs: ManagedVec<MultiValue2<usize, usize>> = ManagedVec::new();
+        for i in 0..n {
+            for j in 0..n {
+                if i != j && d.get(i * n + j) > d.get(j * n + i) {
+                    pairs.push(MultiValue2::from((i, j)));
+                }
+            }
+        }
+
+        // sort the victories (insertion sort, the earliest collected pair comes first on equality)
+        for k in 1..pairs.len() {
+            let mut position = k;
+            while position > 0 {
+                let (i, j) = pairs.get(position).into_tuple();
+                let (prev_i, prev_j) = pairs.get(position - 1).into_tuple();
+                let margin = d.get(i * n + j) - d.get(j * n + i);
+                let prev_margin = d.get(prev_i * n + prev_j) - d.get(prev_j * n + prev_i);
+                let stronger = margin > prev_margin
+                    || (margin == prev_margin && d.get(i * n + j) > d.get(prev_i * n + prev_j));
+                if !stronger {
+                    break;
+                }
+                let current = pairs.get(position);
+                let _ = pairs.set(position, pairs.get(position - 1));
+                let _ = pairs.set(position - 1, current);
+                position -= 1;
+            }
+        }
+
+        // lock the victories that do not create a cycle, a group of equally strong victories at a time
+        let mut locked: ManagedVec<bool> = ManagedVec::new();
+        for _ in 0..n * n {
+            locked.push(false);
+        }
+        self.locked_pairs(election_id).clear();
+        let mut vote_counts: ManagedVec<VotingResult<Self::Api>> = ManagedVec::new();
+        let strength = |pair: &MultiValue2<usize, usize>| {
+            let (i, j) = pair.clone().into_tuple();
+            (d.get(i * n + j) - d.get(j * n + i), d.get(i * n + j))
+        };
+        let mut start = 0;
+        while start < pairs.len() {
+            let mut group: ManagedVec<MultiValue2<usize, usize>> = ManagedVec::new();
+            while start < pairs.len() && (group.is_empty() || strength(&pairs.get(start)) == strength(&group.get(0))) {
+                group.push(pairs.get(start));
+                start += 1;
+            }
+
+            while !group.is_empty() {
+                // the order in which a group is locked only matters if some of its victories create a cycle:
+                // the victories of the candidate chosen by `break_tie` among their winners are then locked first
+                let mut next = group.clone();
+                if !self.can_lock_all(&locked, n, &group) {
+                    let winners: ManagedVec<CandidateID> = (0..n)
+                        .filter(|&i| group.iter().any(|pair| pair.into_tuple().0 == i))
+                        .map(|i| candidates.get(i))
+                        .collect();
+                    if winners.len() > 1 {
+                        let Some(chosen) = self.break_tie(election_id, winners, false) else { return vote_counts };
+                        next = group.iter().filter(|pair| candidates.get(pair.clone().into_tuple().0) == chosen).collect();
+                    }
+                }
+                group = group.iter().filter(|pair| !next.iter().any(|p| p.clone().into_tuple() == pair.clone().into_tuple())).collect();
+
+                for pair in next.iter() {
+                    let (winner, loser) = pair.into_tuple();
+                    if self.is_reachable(&locked, n, loser, winner) {
+                        continue;
+                    }
+                    let _ = locked.set(winner * n + loser, true);
+                    self.locked_pairs(election_id).push(&LockedPair {
+                        winner: candidates.get(winner),
+                        loser: candidates.get(loser),
+                        margin: d.get(winner * n + loser) - d.get(loser * n + winner),
+                    });
+                }
+            }
+        }
+
+        // order the candidates: repeatedly take the first candidate not locked under a remaining candidate
+        // (a tie for the first place is broken by `break_tie`)
+        let mut placed: ManagedVec<bool> = ManagedVec::new();
+        for _ in 0..n {
+            placed.push(false);
+        }
+        for position in 0..n {
+            let sources: ManagedVec<usize> = (0..n).filter(|&j| {
+                !placed.get(j) && !(0..n).any(|i| !placed.get(i) && locked.get(i * n + j))
+            }).collect();
+            if sources.is_empty() {
+                break;
+            }
+            let j = if position == 0 && sources.len() > 1 {
+                let tied: ManagedVec<CandidateID> = sources.iter().map(|j| candidates.get(j)).collect();
+                let Some(chosen) = self.break_tie(election_id, tied, false) else { return vote_counts };
+                candidates.iter().position(|c| c == chosen).unwrap_or_default()
+            } else {
+                sources.get(0)
+            };
+            let _ = placed.set(j, true);
+            self.ranking(election_id).push(&candidates.get(j));
+            vote_counts.push(VotingResult { candidate: candidates.get(j), count: BigUint::from((n - 1 - position) as u64) });
+        }
+        vote_counts
+    }
+
+    /// Checks whether all the victories of a group can be locked without creating a cycle.
+    fn can_lock_all(&self, locked: &ManagedVec<bool>, n: usize, group: &ManagedVec<MultiValue2<usize, usize>>) -> bool {
+        let mut locked = locked.clone();
+        for pair in group.iter() {
+            let (winner, loser) = pair.into_tuple();
+            if self.is_reachable(&locked, n, loser, winner) {
+                return false;
+            }
+            let _ = locked.set(winner * n + loser, true);
+        }
+        true
+    }
+
+    /// Checks whether the `to` candidate can be reached from the `from` candidate following locked victories.
+    ///
+    /// `locked` is a flat `n * n` matrix, where the element at `i * n + j` is `true` if the victory
+    /// of candidate `i` over candidate `j` is locked.
+    fn is_reachable(&self, locked: &ManagedVec<bool>, n: usize, from: usize, to: usize) -> bool {
+        let mut visited: ManagedVec<bool> = ManagedVec::new();
+        for _ in 0..n {
+            visited.push(false);
+        }
+        let mut stack: ManagedVec<usize> = ManagedVec::new();
+        stack.push(from);
+        let _ = visited.set(from, true);
+
+        while !stack.is_empty() {
+            let current = stack.take(stack.len() - 1);
+            if current == to {
+                return true;
+            }
+            for next in 0..n {
+                if locked.get(current * n + next) && !visited.get(next) {
+                    let _ = visited.set(next, true);
+                    stack.push(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns the pairwise preference matrix of an election.
+    ///
+    /// Each entry holds the number of votes that rank `candidate` above `opponent`,
+    /// so that every head-to-head count used by the Schulze method can be audited.
+    #[view(getPairwiseMatrix)]
+    fn get_pairwise_matrix(&self, election_id: ElectionID) -> MultiValueEncoded<PairwisePreference> {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+
+        let candidates: ManagedVec<CandidateID> = self.candidate_id_list(election_id).iter().collect();
+        let n = candidates.len();
+        let matrix = self.pairwise_matrix(election_id, &candidates);
+
+        let mut result = MultiValueEncoded::new();
+        for i in 0..n {
+            for j in 0..n {
+                if i != j {
+                    result.push(PairwisePreference {
+                        candidate: candidates.get(i),
+                        opponent: candidates.get(j),
+                        count: matrix.get(i * n + j),
+                    });
+                }
+            }
+        }
+        result
+    }
+
+
+
+
+    #[view(result_vector)]
+    #[storage_mapper("result_vector")]
+    fn result_vector(&self, election_id: ElectionID, candidate_id: CandidateID) -> SingleValueMapper<BigUint>;
+
+    #[view(result_average)]
+    #[storage_mapper("result_average")]
+    // the average rating of each candidate, for election types that use ratings (see `AVERAGE_PRECISION`)
+    fn result_average(&self, election_id: ElectionID, candidate_id: CandidateID) -> SingleValueMapper<u64>;
+
+    #[view(getStarResult)]
+    #[storage_mapper("star_result")]
+    // the score totals and the runoff counts of a STAR election
+    fn star_result(&self, election_id: ElectionID) -> SingleValueMapper<StarResult<Self::Api>>;
+
+    #[storage_mapper("finished_election")]
+    fn finished_election(&self, election_id: ElectionID) -> SingleValueMapper<bool>;
+
+    #[view(getRanking)]
+    #[storage_mapper("ranking")]
+    // the final ordering of the candidates for each finished election (only the winner for single-winner election types)
+    fn ranking(&self, election_id: ElectionID) -> VecMapper<CandidateID>;
+
+    #[view(getGradeResults)]
+    #[storage_mapper("grade_results")]
+    // the grade histogram and majority grade of each candidate of a Majority Judgment election
+    fn grade_results(&self, election_id: ElectionID) -> VecMapper<GradeResult<Self::Api>>;
+
+    #[view(getPartyResults)]
+    #[storage_mapper("party_results")]
+    // the votes and seats of each party of a Party-List election
+    fn party_results(&self, election_id: ElectionID) -> VecMapper<PartyResult<Self::Api>>;
+
+    #[view(getReferendumResult)]
+    #[storage_mapper("referendum_result")]
+    // the vote counts, turnout and outcome of a Referendum election
+    fn referendum_result(&self, election_id: ElectionID) -> SingleValueMapper<ReferendumResult>;
+
+    #[storage_mapper("tie_breaks")]
+    // the ties that decided the results of the election and how they were broken, in the order the count met them
+    fn tie_breaks(&self, election_id: ElectionID) -> VecMapper<TieBreak<Self::Api>>;
+
+    #[storage_mapper("tie_break_index")]
+    // the number of ties met by the count in progress, to replay the ties already broken when the count is repeated
+    fn tie_break_index(&self, election_id: ElectionID) -> SingleValueMapper<usize>;
+
+    /// Returns the ties that decided the results of the election and how they were broken,
+    /// in the order the count met them (nothing if no tie occurred).
+    #[view(getTieBreaks)]
+    fn get_tie_breaks(&self, election_id: ElectionID) -> MultiValueEncoded<TieBreak<Self::Api>> {
+        self.tie_breaks(election_id).iter().collect()
+    }
+
+    #[view(getStvRounds)]
+    #[storage_mapper("stv_rounds")]
+    // the counts of every round of a Single Transferable Vote election
+    fn stv_rounds(&self, election_id: ElectionID) -> VecMapper<StvRound<Self::Api>>;
+
+    #[view(getLockedPairs)]
+    #[storage_mapper("locked_pairs")]
+    // the head-to-head victories locked by a Ranked Pairs election, in the order they were locked
+    fn locked_pairs(&self, election_id: ElectionID) -> VecMapper<LockedPair>;
+
+    /// Determines the winning candidate of an election based on its type.
+    ///
+    /// This function verifies that the specified election has ended and then finalises it
+    /// (see `finalize_election`). The votes are only evaluated once, the final ordering is then stored in `ranking`.
+    ///
+    /// # Arguments
+    ///
+    /// * `election_id` - The identifier of the election whose results should be computed.
+    ///
+    /// # Returns
+    ///
+    /// For Ranked Pairs elections, the full ordering of the candidates (winner first).
+    /// For STAR elections, the winner of the automatic runoff.
+    /// For Majority Judgment elections, the candidate with the best majority grade.
+    /// For Single Transferable Vote elections, the elected candidates, in the order they were elected.
+    /// For Party-List elections, the elected candidates, in the order their seats were allocated.
+    /// For Referendum elections, `REFERENDUM_YES` if the proposal passed, `REFERENDUM_NO` if it was rejected,
+    /// and nothing if the quorum was not met (the outcome is detailed in `getReferendumResult`).
+    /// For Two-Round elections, the winner if they obtained an absolute majority of the votes,
+    /// otherwise the two candidates of the second round.
+    /// For the other election types, the candidate ID of the candidate with the highest number
+    /// of votes (or points, or head-to-head wins for Schulze, or total rating for Score).
+    /// Ties that decide the results are broken by the election's tie-break policy and reported by `getTieBreaks`;
+    /// nothing is returned while the admin's casting vote is pending.
+    /// If "None of the above" (`NONE_OF_THE_ABOVE`) comes first, the election is marked void and can be rerun with `rerunElection`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the election does not exist or has not ended yet, or if the tally of an encrypted election has not been published.
+    #[view(results)]
+    fn results(&self, election_id: ElectionID) -> MultiValueEncoded<CandidateID> {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        let election_data = self.election_data(election_id).get();
+        require!(election_data.ended || election_data.end_time <= self.blockchain().get_block_timestamp(), "Election has not ended yet");
+        // the votes of a commit-reveal election can only be counted once the reveal period is over
+        let reveal_period = self.reveal_period(election_id).get();
+        require!(reveal_period == 0 || election_data.end_time + reveal_period < self.blockchain().get_block_timestamp(), "Reveal period has not ended yet");
+        // the votes of an encrypted election are counted by its trustees (see `publishTally`)
+        require!(self.trustees(election_id).is_empty() || self.tally_published(election_id).get(), "Tally has not been published yet");
+
+        if !self.finished_election(election_id).get() {
+            self.finalize_election(election_id);
+        }
+        self.settle_expired_tie_break(election_id);
+
+        self.ranking(election_id).iter().collect()
+    }
+
+    /// Evaluates the votes of an election according to its type and stores its results.
+    ///
+    /// It supports Plurality, Approval, Single Transferable Vote, Borda, Schulze, Ranked Pairs, Score, STAR,
+    /// Cumulative, Quadratic, Majority Judgment, Two-Round, Party-List and Referendum election types. The votes of each candidate are stored
+    /// in `result_vector` and the final ordering in `ranking`. Finalising a Two-Round election without an absolute
+    /// majority creates its second round. The votes of encrypted elections were already decrypted by `publishTally`.
+    /// Ties that decide the results are broken by `break_tie`. A tie waiting for the admin's casting vote stops the count,
+    /// which is repeated by `castTieBreakingVote` (or by `results` once the casting vote deadline has passed).
+    fn finalize_election(&self, election_id: ElectionID) {
+        // election types that decide the ordering of the candidates themselves store it in `ranking`
+        self.ranking(election_id).clear();
+        self.tie_break_index(election_id).clear();
+
+        let vote_counts = match self.election_data(election_id).get().election_type {
+            _ if !self.trustees(election_id).is_empty() => self.published_tally(election_id),
+            ElectionType::Plurality => self.evaluate_plurality_or_approval(election_id),
+            ElectionType::Approval => self.evaluate_plurality_or_approval(election_id),
+            ElectionType::SingleTransferableVote => self.evaluate_single_transferable_vote(election_id),
+            ElectionType::Borda => self.evaluate_borda(election_id),
+            ElectionType::Schulze => self.evaluate_schulze(election_id),
+            ElectionType::RankedPairs => self.evaluate_ranked_pairs(election_id),
+            ElectionType::Score => self.evaluate_score(election_id),
+            ElectionType::Star => self.evaluate_star(election_id),
+            ElectionType::Cumulative => self.evaluate_cumulative(election_id),
+            ElectionType::Quadratic => self.evaluate_quadratic(election_id),
+            ElectionType::MajorityJudgment => self.evaluate_majority_judgment(election_id),
+            ElectionType::TwoRound => self.evaluate_two_round(election_id),
+            ElectionType::PartyList => self.evaluate_party_list(election_id),
+            ElectionType::Referendum => self.evaluate_referendum(election_id),
+        };
+
+
+        for result in vote_counts.iter() {
+            self.result_vector(election_id, result.candidate).set(&result.count);
+        }
+
+        if self.tie_break_pending(election_id) {
+            // the count stopped at a tie waiting for the admin's casting vote, it is repeated once the tie is broken
+            self.ranking(election_id).clear();
+        } else if self.ranking(election_id).is_empty() && self.election_data(election_id).get().election_type != ElectionType::Referendum {
+            // for the other election types, the candidate with the most votes wins
+            if let Some(winning_candidate) = self.winning_candidate(election_id) {
+                self.ranking(election_id).push(&winning_candidate);
+            }
+        }
+
+        // the election is void if "None of the above" wins it (and no runoff decides it)
+        if !self.ranking(election_id).is_empty() && self.ranking(election_id).get(1) == NONE_OF_THE_ABOVE {
+            self.election_data(election_id).update(|election_data| {
+                if election_data.next_round.is_none() {
+                    election_data.void = true;
+                }
+            });
+        }
+        self.finished_election(election_id).set(&true);
+    }
+
+    /// Returns the decrypted tally of an encrypted election, stored in `result_vector` by `publishTally`.
+    fn published_tally(&self, election_id: ElectionID) -> ManagedVec<VotingResult<Self::Api>> {
+        self.candidate_id_list(election_id).iter().map(|c_id| VotingResult {candidate: c_id, count: self.result_vector(election_id, c_id).get()}).collect()
+    }
+
+    /// Finds the candidate with the most votes, as stored in `result_vector`.
+    ///
+    /// If several candidates have the most votes (including when nobody has votes), the tie is broken
+    /// according to the election's tie-break policy (see `break_tie`).
+    /// Returns `None` if the election has no candidates, or while the admin's casting vote is pending.
+    fn winning_candidate(&self, election_id: ElectionID) -> Option<CandidateID> {
+        self.most_voted_candidate(election_id, |c_id| BigInt::from_biguint(Sign::Plus, self.result_vector(election_id, c_id).get()))
+    }
+
+    /// Finds the candidate with the most votes, as given by `votes` for each candidate of the election.
+    /// A tie is broken as in `winning_candidate`.
+    fn most_voted_candidate(&self, election_id: ElectionID, votes: impl Fn(CandidateID) -> BigInt) -> Option<CandidateID> {
+        // find the candidates with the most votes, in the order they were registered
+
+        let mut max_votes = BigInt::zero();
+        let mut tied_candidates: ManagedVec<CandidateID> = ManagedVec::new();
+        for c_id in self.candidate_id_list(election_id).iter() {
+            let count = votes(c_id);
+            if count > max_votes || tied_candidates.is_empty() {
+                max_votes = count;
+                tied_candidates = ManagedVec::from_single_item(c_id);
+            } else if count == max_votes {
+                tied_candidates.push(c_id);
+            }
+        }
+
+        match tied_candidates.len() {
+            0 => None,
+            1 => Some(tied_candidates.get(0)),
+            _ => self.break_tie(election_id, tied_candidates, false),
+        }
+    }
+
+    /// Breaks a tie that decides the results of an election and records it in `tie_breaks`.
+    ///
+    /// `tied_candidates` are given in registration order. `elimination` tells whether the chosen candidate is
+    /// eliminated (a Single Transferable Vote elimination) or preferred to the other tied candidates (every other tie).
+    /// - EarliestRegistration: the tied candidate registered first is preferred (the one registered last is eliminated).
+    /// - Random: the candidate is drawn from the tied candidates using the block random seed, which is recorded
+    ///   so that anyone can recompute the draw (first 8 bytes of the seed, big-endian, modulo the number of tied candidates).
+    /// - AdminCastingVote: no candidate is chosen and the count stops, the admin has `CASTING_VOTE_PERIOD` seconds to choose one
+    ///   with `castTieBreakingVote`, after which the EarliestRegistration choice applies. The count is then repeated.
+    ///
+    /// A repeated count does not break the ties it already met again, it replays the choices recorded in `tie_breaks`.
+    /// Returns the chosen candidate, or `None` while the admin's casting vote is pending.
+    fn break_tie(&self, election_id: ElectionID, tied_candidates: ManagedVec<CandidateID>, elimination: bool) -> Option<CandidateID> {
+        let index = self.tie_break_index(election_id).get() + 1;
+        self.tie_break_index(election_id).set(index);
+        if index <= self.tie_breaks(election_id).len() {
+            let chosen = self.tie_breaks(election_id).get(index).chosen;
+            return if chosen == 0 { None } else { Some(chosen) };
+        }
+
+        let mut tie_break = TieBreak {
+            candidates: tied_candidates,
+            elimination,
+            policy: self.election_data(election_id).get().tie_break_policy,
+            seed: ManagedBuffer::new(),
+            deadline: 0,
+            chosen: 0,
+        };
+        match tie_break.policy {
+            TieBreakPolicy::EarliestRegistration => {
+                tie_break.chosen = tie_break.earliest_registration_choice();
+            },
+            TieBreakPolicy::Random => {
+                tie_break.seed = self.blockchain().get_block_random_seed().as_managed_buffer().clone();
+                let mut draw = [0u8; 8];
+                let _ = tie_break.seed.load_slice(0, &mut draw);
+                tie_break.chosen = tie_break.candidates.get((u64::from_be_bytes(draw) % tie_break.candidates.len() as u64) as usize);
+            },
+            TieBreakPolicy::AdminCastingVote => {
+                tie_break.deadline = self.blockchain().get_block_timestamp() + CASTING_VOTE_PERIOD;
+            },
+        }
+
+        let chosen = tie_break.chosen;
+        self.tie_breaks(election_id).push(&tie_break);
+        if chosen == 0 { None } else { Some(chosen) }
+    }
+
+    /// Returns true if the count of the election stopped at a tie waiting for the admin's casting vote.
+    fn tie_break_pending(&self, election_id: ElectionID) -> bool {
+        let tie_breaks = self.tie_breaks(election_id);
+        !tie_breaks.is_empty() && tie_breaks.get(tie_breaks.len()).chosen == 0
+    }
+
+    /// Settles a tie left pending by the admin once the casting vote deadline has passed
+    /// (the EarliestRegistration choice applies), then repeats the count, which marks the election void
+    /// if "None of the above" wins it.
+    fn settle_expired_tie_break(&self, election_id: ElectionID) {
+        if !self.tie_break_pending(election_id) {
+            return;
+        }
+        let index = self.tie_breaks(election_id).len();
+        let mut tie_break = self.tie_breaks(election_id).get(index);
+        if tie_break.deadline < self.blockchain().get_block_timestamp() {
+            tie_break.chosen = tie_break.earliest_registration_choice();
+            self.tie_breaks(election_id).set(index, &tie_break);
+            self.finalize_election(election_id);
+        }
+    }
+
+    /// Casts the admin's deciding vote on the pending tie of an election (see `break_tie`), then repeats the count.
+    /// The repeated count finalises the election as `results` does, so if "None of the above" wins the tie,
+    /// the election is marked void.
+    #[endpoint(castTieBreakingVote)]
+    fn cast_tie_breaking_vote(&self, election_id: ElectionID, candidate_id: CandidateID) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can cast the tie-breaking vote");
+        require!(self.tie_break_pending(election_id), "No tie to break");
+
+        let index = self.tie_breaks(election_id).len();
+        let mut tie_break = self.tie_breaks(election_id).get(index);
+        require!(tie_break.deadline >= self.blockchain().get_block_timestamp(), "Casting vote deadline has passed");
+        require!(tie_break.candidates.iter().any(|c| c == candidate_id), "Candidate is not tied");
+
+        tie_break.chosen = candidate_id;
+        self.tie_breaks(election_id).set(index, &tie_break);
+        self.finalize_election(election_id);
+    }
+
+    #[view(electionList)]
+    fn election_list(&self) -> MultiValueEncoded<ElectionData<Self::Api>> {
+        let election_ids = self.election_id_list();
+        let election_data_iter = election_ids.iter().map(|id| self.election_data(id).get());
+        MultiValueEncoded::from_iter(election_data_iter)
+    }
+
+    
+
+    /// Generates a unique election ID.
+
+    /// This function generates a random election ID and ensures that it is not already in use.
+    fn generate_election_id(&self) -> ElectionID {
+
+        let mut rand_source = RandomnessSource::new();
+        loop {
+            let election_id = rand_source.next_u64() as ElectionID;
+            if !self.election_id_list().contains(&election_id) {
+                return election_id;
+            }
+        }
+    }
+
+    /// Generates a unique candidate ID for the given election.
+
+    /// This function generates a random candidate ID and ensures that it is not already in use
+    /// by either the list of candidates or the list of potential candidates for the given election.
+    /// The ID is never 0, which stands for "no candidate" (such as a pending tie-break), nor `NONE_OF_THE_ABOVE`.
+    fn generate_candidate_id(&self, election_id: ElectionID) -> CandidateID {
+
+        let mut rand_source = RandomnessSource::new();
+        loop {
+            let candidate_id = rand_source.next_u64() as CandidateID;
+            if candidate_id != 0 && candidate_id != NONE_OF_THE_ABOVE && !self.candidate_id_list(election_id).contains(&candidate_id) && !self.potential_candidate_id_list(election_id).contains(&candidate_id) {
+                return candidate_id;
+            }
+        }
+    }
+
+    /// Generates a unique dispute ID for the given election.
+    ///
+    /// This function generates a random dispute ID and ensures that it is not already in use by
+    /// the list of disputes for the given election.
+    fn generate_dispute_id(&self, election_id: ElectionID) -> DisputeID {
+
+        let mut rand_source = RandomnessSource::new();
+        loop {
+            let dispute_id = rand_source.next_u64() as DisputeID;
+            if !self.dispute_id_list(election_id).contains(&dispute_id) {
+                return dispute_id;
+            }
+        }
+    }
+
+    /// Generates a unique party ID for the given election.
+    ///
+    /// This function generates a random party ID and ensures that it is not already in use by
+    /// the list of parties for the given election. The ID is never 0, which stands for "no party" in a tie-break.
+    fn generate_party_id(&self, election_id: ElectionID) -> PartyID {
+
+        let mut rand_source = RandomnessSource::new();
+        loop {
+            let party_id = rand_source.next_u64() as PartyID;
+            if party_id != 0 && !self.party_id_list(election_id).contains(&party_id) {
+                return party_id;
+            }
+        }
+    }
+
+
+    
+
+
+    #[endpoint(registerElection)]
+    fn register_election(&self, name: ManagedBuffer, description: ManagedBuffer, election_type: u64, start_time: u64, end_time: u64, tie_break_policy: OptionalValue<u64>) -> ElectionID {
+
+        let election_id = self.generate_election_id();
+        require!(name.len() > 0, "Name cannot be empty");
+        require!(name.len() <= 50, "Name cannot be longer than 50 characters");
+        require!(description.len() > 0, "Description cannot be empty");
+        require!(description.len() <= 200, "Description cannot be longer than 200 characters");
+        require!(election_type < 14, "Invalid election type");
+        let election_type: ElectionType = match election_type {
+            0 => ElectionType::Plurality,
+            1 => ElectionType::Approval,
+            2 => ElectionType::SingleTransferableVote,
+            3 => ElectionType::Borda,
+            4 => ElectionType::Schulze,
+            5 => ElectionType::RankedPairs,
+            6 => ElectionType::Score,
+            7 => ElectionType::Star,
+            8 => ElectionType::Cumulative,
+            9 => ElectionType::Quadratic,
+            10 => ElectionType::MajorityJudgment,
+            11 => ElectionType::TwoRound,
+            12 => ElectionType::PartyList,
+            13 => ElectionType::Referendum,
+            _ => ElectionType::Plurality
+        };
+        // the runoff of a Two-Round election lasts as long as its first round
+        require!(election_type != ElectionType::TwoRound || start_time < end_time, "Election must end after it starts");
+        // the tied candidate registered first wins by default
+        let tie_break_policy = tie_break_policy.into_option().unwrap_or_default();
+        require!(tie_break_policy < 3, "Invalid tie-break policy");
+        let tie_break_policy = match tie_break_policy {
+            1 => TieBreakPolicy::Random,
+            2 => TieBreakPolicy::AdminCastingVote,
+            _ => TieBreakPolicy::EarliestRegistration
+        };
+        let election_data = ElectionData {
+            id: election_id,
+            name,
+            description,
+            election_type: election_type,
+            start_time,
+            end_time,
+            ended: false,
+            admin: self.blockchain().get_caller(),
+            seats: 1,
+            previous_round: None,
+            next_round: None,
+            tie_break_policy,
+            turnout: 0,
+            void: false,
+        };
+        if matches!(election_data.election_type, ElectionType::Plurality | ElectionType::TwoRound | ElectionType::PartyList | ElectionType::Referendum) {
+            self.ballot_rules(election_id).set(BallotRules { min_selections: 1, max_selections: 1, full_ranking: false });
+        } else if !election_data.election_type.uses_ratings() {
+            self.ballot_rules(election_id).set(BallotRules::default());
+        }
+        match election_data.election_type {
+            ElectionType::Score => self.rating_scale(election_id).set(RatingScale { min: 0, max: 10 }),
+            ElectionType::Star => self.rating_scale(election_id).set(RatingScale { min: 0, max: 5 }),
+            ElectionType::Cumulative => self.point_budget(election_id).set(10),
+            ElectionType::Quadratic => self.voice_credit_budget(election_id).set(100),
+            ElectionType::PartyList => self.party_list_rules(election_id).set(PartyListRules::default()),
+            ElectionType::Referendum => self.referendum_rules(election_id).set(ReferendumRules { quorum_percent: 0, threshold_numerator: 1, threshold_denominator: 2 }),
+            ElectionType::MajorityJudgment => {
+                for grade in ["Reject", "Poor", "Acceptable", "Good", "Very Good", "Excellent"] {
+                    self.grades(election_id).push(&ManagedBuffer::from(grade));
+                }
+            },
+            _ => {},
+        }
+        self.election_id_list().insert(election_id);
+        self.election_data(election_id).set(&election_data);
+
+        return election_id;
+    }
+
+    #[endpoint(setRatingScale)]
+    fn set_rating_scale(&self, election_id: ElectionID, min: u64, max: u64) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the rating scale");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.election_data(election_id).get().election_type.uses_rating_scale(), "Election type does not use a rating scale");
+        require!(min < max, "Invalid rating scale");
+
+        self.rating_scale(election_id).set(RatingScale { min, max });
+    }
+
+
+    #[endpoint(setPointBudget)]
+    fn set_point_budget(&self, election_id: ElectionID, budget: u64) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the point budget");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.election_data(election_id).get().election_type == ElectionType::Cumulative, "Election type does not use a point budget");
+        require!(budget > 0, "Point budget must be positive");
+
+        self.point_budget(election_id).set(budget);
+    }
+
+    #[endpoint(setVoiceCreditBudget)]
+    fn set_voice_credit_budget(&self, election_id: ElectionID, budget: u64) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the voice credit budget");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.election_data(election_id).get().election_type == ElectionType::Quadratic, "Election type does not use voice credits");
+        require!(budget > 0, "Voice credit budget must be positive");
+
+        self.voice_credit_budget(election_id).set(budget);
+    }
+
+    #[endpoint(setGrades)]
+    fn set_grades(&self, election_id: ElectionID, grades: MultiValueEncoded<ManagedBuffer>) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the grades");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.election_data(election_id).get().election_type == ElectionType::MajorityJudgment, "Election type does not use grades");
+        require!(grades.len() >= 2, "At least two grades are required");
+
+        self.grades(election_id).clear();
+        for grade in grades.into_iter() {
+            require!(!grade.is_empty(), "Grade name cannot be empty");
+            self.grades(election_id).push(&grade);
+        }
+    }
+
+    #[endpoint(setRunoffSchedule)]
+    fn set_runoff_schedule(&self, election_id: ElectionID, start_time: u64, end_time: u64) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the runoff schedule");
+        require!(self.election_data(election_id).get().election_type == ElectionType::TwoRound, "Election type does not have a runoff");
+        require!(!self.finished_election(election_id).get(), "Election has been finalised");
+        require!(start_time >= self.election_data(election_id).get().end_time, "Runoff cannot start before the first round ends");
+        require!(start_time < end_time, "Runoff must start before it ends");
+
+        self.runoff_schedule(election_id).set(RunoffSchedule { start_time, end_time });
+    }
+
+    #[endpoint(setBallotRules)]
+    fn set_ballot_rules(&self, election_id: ElectionID, min_selections: u32, max_selections: u32, full_ranking: bool) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the ballot rules");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+
+        let election_type = self.election_data(election_id).get().election_type;
+        require!(!election_type.uses_ratings(), "Election type does not use ballot rules");
+        require!(!matches!(election_type, ElectionType::Plurality | ElectionType::TwoRound | ElectionType::PartyList | ElectionType::Referendum), "Election type only allows one candidate");
+        require!(max_selections == 0 || min_selections <= max_selections, "Minimum selections cannot exceed maximum selections");
+        require!(!full_ranking || election_type == ElectionType::SingleTransferableVote, "Full ranking is only supported for Single Transferable Vote");
+        require!(self.trustees(election_id).is_empty(), "Encrypted elections do not support ballot rules");
+
+        self.ballot_rules(election_id).set(BallotRules { min_selections, max_selections, full_ranking });
+    }
+
+    #[endpoint(setVotingToken)]
+    fn set_voting_token(&self, election_id: ElectionID, token_id: TokenIdentifier) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the voting token");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.election_data(election_id).get().election_type.supports_token_weights(), "Election type does not support token-weighted votes");
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token identifier");
+        require!(self.trustees(election_id).is_empty(), "Encrypted elections do not support token-weighted votes");
+        require!(!self.has_weighted_voter_roll(election_id), "Election is weighted by its voter roll");
+        require!(!self.revoting_allowed(election_id).get(), "Revoting is not available with token-weighted votes");
+        require!(!self.delegation_allowed(election_id).get(), "Delegation is not available with token-weighted votes");
+
+        self.voting_token(election_id).set(token_id);
+    }
+
+    #[endpoint(setReferendumRules)]
+    fn set_referendum_rules(&self, election_id: ElectionID, quorum_percent: u64, threshold_numerator: u64, threshold_denominator: u64) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the referendum rules");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.election_data(election_id).get().election_type == ElectionType::Referendum, "Election type is not a referendum");
+        require!(quorum_percent <= 100, "Quorum cannot exceed 100 percent");
+        require!(threshold_denominator > 0, "Threshold denominator cannot be zero");
+        require!(threshold_numerator <= threshold_denominator, "Threshold cannot exceed 1");
+
+        self.referendum_rules(election_id).set(ReferendumRules { quorum_percent, threshold_numerator, threshold_denominator });
+    }
+
+    #[endpoint(setNoneOfTheAbove)]
+    fn set_none_of_the_above(&self, election_id: ElectionID, enabled: bool) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the None of the above option");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        let election_type = self.election_data(election_id).get().election_type;
+        require!(!matches!(election_type, ElectionType::PartyList | ElectionType::Referendum), "Election type does not support None of the above");
+
+        if enabled {
+            self.add_none_of_the_above(election_id);
+        } else if self.candidate_id_list(election_id).contains(&NONE_OF_THE_ABOVE) {
+            // rebuild the list rather than swap the last candidate into the option's place, to keep the
+            // registration order the EarliestRegistration tie-break relies on
+            let candidates: ManagedVec<CandidateID> = self.candidate_id_list(election_id).iter().filter(|c| *c != NONE_OF_THE_ABOVE).collect();
+            self.candidate_id_list(election_id).clear();
+            for c in candidates.iter() {
+                self.candidate_id_list(election_id).insert(c);
+            }
+            self.candidate(election_id, NONE_OF_THE_ABOVE).clear();
+        }
+    }
+
+    /// Adds the reserved "None of the above" option to the candidates of an election.
+    fn add_none_of_the_above(&self, election_id: ElectionID) {
+        self.candidate_id_list(election_id).insert(NONE_OF_THE_ABOVE);
+        self.candidate(election_id, NONE_OF_THE_ABOVE).set(Candidate {
+            id: NONE_OF_THE_ABOVE,
+            name: ManagedBuffer::from("None of the above"),
+            description: ManagedBuffer::from("Reserved option, the election is void if it wins"),
+            creator: self.election_data(election_id).get().admin,
+        });
+    }
+
+    #[endpoint(setRevealPeriod)]
+    fn set_reveal_period(&self, election_id: ElectionID, reveal_period: u64) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the reveal period");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.trustees(election_id).is_empty(), "Encrypted elections do not use committed votes");
+        require!(reveal_period == 0 || !self.revoting_allowed(election_id).get(), "Revoting is not available with committed votes");
+        require!(reveal_period == 0 || !self.delegation_allowed(election_id).get(), "Delegation is not available with committed votes");
+
+        self.reveal_period(election_id).set(reveal_period);
+    }
+
+    /// Makes the election an encrypted election, whose ballots are ElGamal-encrypted to the joint public key
+    /// of the given trustees (see `voteEncrypted`), and whose tally is decrypted by any `threshold` of them (t-of-n).
+    /// Each trustee must then register their key with `registerTrusteeKey` before the election starts.
+    /// Calling it with no trustees makes the election a plain election again.
+    #[endpoint(setTrustees)]
+    fn set_trustees(&self, election_id: ElectionID, threshold: usize, trustees: MultiValueEncoded<ManagedAddress>) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the trustees");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        let election_type = self.election_data(election_id).get().election_type;
+        require!(matches!(election_type, ElectionType::Plurality | ElectionType::Approval), "Election type does not support encrypted votes");
+        require!(self.voting_token(election_id).is_empty(), "Encrypted elections do not support token-weighted votes");
+        require!(self.reveal_period(election_id).get() == 0, "Encrypted elections do not use committed votes");
+        require!(!self.has_weighted_voter_roll(election_id), "Encrypted elections do not support weighted votes");
+        require!(trustees.is_empty() || !self.revoting_allowed(election_id).get(), "Revoting is not available with encrypted votes");
+        require!(trustees.is_empty() || !self.delegation_allowed(election_id).get(), "Delegation is not available with encrypted votes");
+        if election_type == ElectionType::Approval {
+            let ballot_rules = self.ballot_rules(election_id).get();
+            require!(ballot_rules.min_selections == 0 && ballot_rules.max_selections == 0, "Encrypted elections do not support ballot rules");
+        }
+
+        require!(trustees.is_empty() || (threshold >= 1 && threshold <= trustees.len()), "Threshold must be between 1 and the number of trustees");
+
+        for trustee in self.trustees(election_id).iter() {
+            self.trustee_key(election_id, trustee.clone()).clear();
+            self.coefficient_commitments(election_id, trustee).clear();
+        }
+        self.trustees(election_id).clear();
+        self.trustee_threshold(election_id).clear();
+        if trustees.is_empty() {
+            return;
+        }
+        for trustee in trustees {
+            require!(self.trustees(election_id).insert(trustee), "Trustee listed more than once");
+        }
+        self.trustee_threshold(election_id).set(threshold);
+    }
+
+    /// Registers the ElGamal public key `h = g^a_0 (mod p)` of the calling trustee, with a Schnorr proof that
+    /// they know the secret key `a_0`: `g^response == commitment * h^c (mod p)`, where `c` is the challenge of the
+    /// public key and the commitment (see `challenge`).
+    ///
+    /// The secret key is the constant coefficient of a secret polynomial `f(x) = a_0 + a_1 x + ... + a_(t-1) x^(t-1) (mod q)`
+    /// of degree `threshold - 1`, whose other coefficients are committed to with `coefficients`: `g^a_1, ..., g^a_(t-1)`.
+    /// Off-chain, the trustee sends `f(j)` privately to the trustee at position `j` in `getTrustees` (from 1), who checks it
+    /// against these commitments: `g^f(j) == h * C_1^j * ... * C_(t-1)^(j^(t-1))`. The sum of the values a trustee receives
+    /// (their own included) is their share of the joint secret key (see `getTrusteeVerificationKey`).
+    #[endpoint(registerTrusteeKey)]
+    fn register_trustee_key(&self, election_id: ElectionID, public_key: BigUint, commitment: BigUint, response: BigUint, coefficients: MultiValueEncoded<BigUint>) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        let trustee = self.blockchain().get_caller();
+        require!(self.trustees(election_id).contains(&trustee), "Only trustees can register a key");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(public_key != 1u64 && self.is_group_element(&public_key), "Invalid public key");
+
+        let c = self.challenge(election_id, &trustee, &[&public_key, &commitment]);
+        let g = self.elgamal_generator();
+        require!(self.mod_pow(&g, &response) == self.mod_mul(&commitment, &self.mod_pow(&public_key, &c)), "Invalid proof of knowledge of the secret key");
+        require!(coefficients.len() + 1 == self.trustee_threshold(election_id).get(), "Key must commit to threshold - 1 coefficients");
+        let coefficients = coefficients.to_vec();
+        for coefficient in coefficients.iter() {
+            require!(self.is_group_element(&coefficient), "Invalid coefficient commitment");
+        }
+
+        self.trustee_key(election_id, trustee.clone()).set(public_key);
+        self.coefficient_commitments(election_id, trustee).set(coefficients);
+    }
+
+    /// Commits the Merkle root of the voter roll of the election, so that its voters register themselves with
+    /// a proof of membership instead of being registered one by one (see `registerSelf` and `voteWithProof`).
+    /// `voters` is the number of voters in the roll, which counts in the electorate of a referendum until they register.
+    /// If `weighted` is true, each vote is weighted by the weight of the voter in the roll.
+    #[endpoint(setVoterRoll)]
+    fn set_voter_roll(&self, election_id: ElectionID, root: ManagedByteArray<Self::Api, 32>, voters: u64, weighted: bool) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the voter roll");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(voters > 0, "Voter roll cannot be empty");
+        if weighted {
+            require!(self.election_data(election_id).get().election_type.supports_token_weights(), "Election type does not support weighted votes");
+            require!(self.voting_token(election_id).is_empty(), "Election is weighted by its voting token");
+            require!(self.trustees(election_id).is_empty(), "Encrypted elections do not support weighted votes");
+        }
+
+        self.voter_roll(election_id).set(VoterRoll { root, voters, weighted });
+    }
+
+    fn has_weighted_voter_roll(&self, election_id: ElectionID) -> bool {
+        !self.voter_roll(election_id).is_empty() && self.voter_roll(election_id).get().weighted
+    }
+
+    #[endpoint(setBlankBallotsAllowed)]
+    fn set_blank_ballots_allowed(&self, election_id: ElectionID, allowed: bool) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can allow blank ballots");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+
+        self.blank_ballots_allowed(election_id).set(allowed);
+    }
+
+    /// Lets the voters of the election recast their ballot any number of times before `end_time`: each ballot
+    /// replaces the previous ballot of the voter, which stays in the ledger but is no longer counted.
+    #[endpoint(setRevotingAllowed)]
+    fn set_revoting_allowed(&self, election_id: ElectionID, allowed: bool) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can allow revoting");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        if allowed {
+            require!(self.reveal_period(election_id).get() == 0, "Revoting is not available with committed votes");
+            require!(self.trustees(election_id).is_empty(), "Revoting is not available with encrypted votes");
+            require!(self.voting_token(election_id).is_empty(), "Revoting is not available with token-weighted votes");
+        }
+
+        self.revoting_allowed(election_id).set(allowed);
+    }
+
+    /// Issues the token of the voter credentials (an NFT collection). The issue cost must be paid in EGLD.
+    /// The roles of the contract must then be set with `setVoterCredentialRoles`.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueVoterCredential)]
+    fn issue_voter_credential(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        require!(self.voter_credential().is_empty(), "Voter credential token already issued");
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        let callback = self.callbacks().voter_credential_issue_callback(self.blockchain().get_caller());
+        self.voter_credential().issue(EsdtTokenType::NonFungible, issue_cost, token_display_name, token_ticker, 0, Some(callback));
+    }
+
+    #[callback]
+    fn voter_credential_issue_callback(&self, caller: ManagedAddress, #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => self.voter_credential().set_token_id(token_id),
+            ManagedAsyncCallResult::Err(_) => {
+                // the issue cost is returned to the contract, send it back to the owner
+                let returned = self.call_value().egld_value().clone_value();
+                if returned > 0u64 {
+                    self.tx().to(&caller).egld(&returned).transfer();
+                }
+            },
+        }
+    }
+
+    /// Gives the contract the roles to mint and burn voter credentials and makes it the only holder of the transfer
+    /// role, so that voters cannot transfer their credential, except back to the contract.
+    #[only_owner]
+    #[endpoint(setVoterCredentialRoles)]
+    fn set_voter_credential_roles(&self) {
+        self.voter_credential().require_issued_or_set();
+        self.voter_credential().set_local_roles(&[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn, EsdtLocalRole::Transfer], None);
+    }
+
+    /// Requires the voters of the election to hold a voter credential, minted when they register and sent
+    /// with their vote (see `use_voter_credential`). Voters must not be registered yet.
+    #[endpoint(setCredentialRequired)]
+    fn set_credential_required(&self, election_id: ElectionID, required: bool) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can require voter credentials");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(!required || !self.voter_credential().is_empty(), "Voter credential token not issued");
+        require!(self.registered_voters(election_id).is_empty(), "Voters are already registered");
+
+        self.credential_required(election_id).set(required);
+    }
+
+    /// Revokes the voter credential of a voter: they can no longer vote or delegate, and the weight they delegated
+    /// is no longer counted. A ballot they already cast stays counted.
+    #[endpoint(revokeVoterCredential)]
+    fn revoke_voter_credential(&self, election_id: ElectionID, voter_address: ManagedAddress) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can revoke voter credentials");
+        require!(!self.election_data(election_id).get().ended, "Election has already ended");
+        require!(self.credential_required(election_id).get(), "Election does not use voter credentials");
+        require!(self.registered_voters(election_id).contains(&voter_address), "Voter is not registered");
+        require!(!self.revoked_voters(election_id).contains(&voter_address), "Voter credential already revoked");
+
+        self.voter_eligible(election_id, voter_address.clone()).set(false);
+        self.revoked_voters(election_id).insert(voter_address);
+    }
+
+    /// Mints the voter credential of a voter who registered in an election that requires one, and sends it to them.
+    fn mint_voter_credential(&self, election_id: ElectionID, voter_address: &ManagedAddress) {
+        if !self.credential_required(election_id).get() {
+            return;
+        }
+        let attributes = VoterCredential { election_id, voter: voter_address.clone() };
+        let credential = self.voter_credential().nft_create_and_send(voter_address, BigUint::from(1u64), &attributes);
+        self.voter_credential_nonce(election_id, voter_address.clone()).set(credential.token_nonce);
+    }
+
+    /// Checks that the first payment of a vote is the voter credential of the caller and removes it from the payments.
+    /// The credential is burned, except in an election with revoting, where it is sent back for the next ballot.
+    fn use_voter_credential(&self, election_id: ElectionID, voter_address: &ManagedAddress, payments: &mut ManagedVec<EsdtTokenPayment<Self::Api>>) {
+        require!(!payments.is_empty(), "Voter credential required");
+        let credential = payments.get(0);
+        require!(
+            credential.token_identifier == self.voter_credential().get_token_id()
+                && credential.token_nonce == self.voter_credential_nonce(election_id, voter_address.clone()).get()
+                && credential.amount == 1u64,
+            "Invalid voter credential"
+        );
+        payments.remove(0);
+
+        if self.revoting_allowed(election_id).get() {
+            self.voter_credential().send_payment(voter_address, &credential);
+        } else {
+            self.voter_credential().nft_burn(credential.token_nonce, &credential.amount);
+        }
+    }
+
+    /// Lets the registered voters of the election delegate their vote to another registered voter (see `delegateVote`).
+    #[endpoint(setDelegationAllowed)]
+    fn set_delegation_allowed(&self, election_id: ElectionID, allowed: bool) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can allow delegation");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        if allowed {
+            require!(self.election_data(election_id).get().election_type.supports_token_weights(), "Election type does not support weighted votes");
+            require!(self.reveal_period(election_id).get() == 0, "Delegation is not available with committed votes");
+            require!(self.trustees(election_id).is_empty(), "Delegation is not available with encrypted votes");
+            require!(self.voting_token(election_id).is_empty(), "Delegation is not available with token-weighted votes");
+        }
+
+        self.delegation_allowed(election_id).set(allowed);
+    }
+
+    #[endpoint(rerunElection)]
+    fn rerun_election(&self, election_id: ElectionID, start_time: u64, end_time: u64) -> ElectionID {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        let election_data = self.election_data(election_id).get();
+        require!(self.blockchain().get_caller() == election_data.admin, "Only admin can rerun the election");
+        require!(election_data.void, "Election is not void");
+        require!(election_data.next_round.is_none(), "Election has already been rerun");
+        require!(start_time > self.blockchain().get_block_timestamp(), "Rerun must start in the future");
+        require!(start_time < end_time, "Rerun must end after it starts");
+
+        let rerun_id = self.generate_election_id();
+        let rerun = ElectionData {
+            id: rerun_id,
+            name: election_data.name,
+            description: election_data.description,
+            start_time,
+            end_time,
+            election_type: election_data.election_type,
+            ended: false,
+            admin: election_data.admin,
+            seats: election_data.seats,
+            previous_round: Some(election_id),
+            next_round: None,
+            tie_break_policy: election_data.tie_break_policy,
+            turnout: 0,
+            void: false,
+        };
+        self.election_id_list().insert(rerun_id);
+        self.election_data(rerun_id).set(&rerun);
+        self.election_data(election_id).update(|election_data| {
+            election_data.next_round = Some(rerun_id);
+        });
+
+        // the rerun keeps the settings and the voters of the void election, candidates register again
+        if !self.ballot_rules(election_id).is_empty() {
+            self.ballot_rules(rerun_id).set(self.ballot_rules(election_id).get());
+        }
+        if !self.rating_scale(election_id).is_empty() {
+            self.rating_scale(rerun_id).set(self.rating_scale(election_id).get());
+        }
+        self.point_budget(rerun_id).set(self.point_budget(election_id).get());
+        self.voice_credit_budget(rerun_id).set(self.voice_credit_budget(election_id).get());
+        for grade in self.grades(election_id).iter() {
+            self.grades(rerun_id).push(&grade);
+        }
+        self.copy_election_settings(election_id, rerun_id);
+        rerun_id
+    }
+
+    #[endpoint(setPartyListRules)]
+    fn set_party_list_rules(&self, election_id: ElectionID, method: u64, threshold_percent: u64) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the party list rules");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.election_data(election_id).get().election_type == ElectionType::PartyList, "Election type does not use party lists");
+        require!(method < 2, "Invalid seat allocation method");
+        require!(threshold_percent <= 100, "Threshold cannot exceed 100 percent");
+
+        let method = match method {
+            1 => SeatAllocationMethod::SainteLague,
+            _ => SeatAllocationMethod::DHondt,
+        };
+        self.party_list_rules(election_id).set(PartyListRules { method, threshold_percent });
+    }
+
+    #[endpoint(registerParty)]
+    fn register_party(&self, election_id: ElectionID, name: ManagedBuffer) -> PartyID {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can register parties");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.election_data(election_id).get().election_type == ElectionType::PartyList, "Election type does not use party lists");
+        require!(!name.is_empty(), "Name cannot be empty");
+        require!(name.len() <= 50, "Name cannot be longer than 50 characters");
+
+        let party_id = self.generate_party_id(election_id);
+        self.party_id_list(election_id).insert(party_id);
+        self.party(election_id, party_id).set(Party { id: party_id, name });
+        party_id
+    }
+
+    #[endpoint(addCandidateToParty)]
+    fn add_candidate_to_party(&self, election_id: ElectionID, party_id: PartyID, candidate_id: CandidateID) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can add candidates to parties");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.party_id_list(election_id).contains(&party_id), "Party does not exist");
+        require!(self.candidate_id_list(election_id).contains(&candidate_id), "Invalid candidate");
+        require!(self.candidate_party(election_id, candidate_id).is_empty(), "Candidate already on a party list");
+
+        // candidates are added at the end of the list
+        self.party_list(election_id, party_id).push(&candidate_id);
+        self.candidate_party(election_id, candidate_id).set(party_id);
+    }
+
+    #[endpoint(setSeats)]
+    fn set_seats(&self, election_id: ElectionID, seats: u32) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can set the number of seats");
+        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(matches!(self.election_data(election_id).get().election_type, ElectionType::SingleTransferableVote | ElectionType::PartyList), "Election type does not support multiple seats");
+        require!(seats > 0, "Number of seats must be positive");
+
+        self.election_data(election_id).update(|election_data| {
+            election_data.seats = seats;
+        });
     }
 
 
@@ -378,6 +2575,7 @@ pub trait BackendSc {
         require!(self.election_id_list().contains(&election_id), "Election does not exist");
         require!(self.election_data(election_id).get().ended == false, "Election has already ended");
         require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        require!(self.election_data(election_id).get().election_type != ElectionType::Referendum, "Referendum elections have no candidates");
 
 
         // manage fee
@@ -431,6 +2629,10 @@ pub trait BackendSc {
         return verification_data.len() > 8;
     }
 
+    /// Registers the caller as a voter of the election.
+    ///
+    /// If the election has a voter roll, `verification_data` is the proof of membership of the caller
+    /// (see `register_from_voter_roll`), and voters can register until the election ends.
     #[endpoint(registerSelf)]
     fn register_self(&self, election_id: ElectionID, verification_data: ManagedBuffer) {
 
@@ -438,17 +2640,70 @@ pub trait BackendSc {
 
         require!(self.election_id_list().contains(&election_id), "Election does not exist");
         require!(self.election_data(election_id).get().ended == false, "Election has already ended");
-        require!(self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
+        let has_voter_roll = !self.voter_roll(election_id).is_empty();
+        require!(has_voter_roll || self.election_data(election_id).get().start_time > self.blockchain().get_block_timestamp(), "Election has started");
         require!(!self.registered_voters(election_id).contains(&voter_address), "Already registered");
 
+        if has_voter_roll {
+            self.register_from_voter_roll(election_id, &verification_data);
+            return;
+        }
+
         // perform verification logic here
         require!(self.verification_logic(election_id, verification_data), "Invalid verification data");
 
         // register the voter
         self.registered_voters(election_id).insert(self.blockchain().get_caller());
+        self.mint_voter_credential(election_id, &voter_address);
         self.voter_eligible(election_id, voter_address).set(&true);
     }
 
+    /// Registers the caller from a Merkle proof of their membership of the voter roll of the election,
+    /// and marks their leaf of the roll as used.
+    ///
+    /// `verification_data` is the weight of the voter (8 bytes, big-endian; 1 in unweighted rolls) followed by
+    /// the sibling hashes (32 bytes each) on the path from their leaf to the root. The leaf is `sha256(address || weight)`
+    /// and each parent is the sha256 of its two children, the smaller one first.
+    fn register_from_voter_roll(&self, election_id: ElectionID, verification_data: &ManagedBuffer) {
+        let voter_address = self.blockchain().get_caller();
+        let voter_roll = self.voter_roll(election_id).get();
+        require!(verification_data.len() >= 8 && (verification_data.len() - 8) % 32 == 0, "Invalid verification data");
+
+        let mut weight = [0u8; 8];
+        let _ = verification_data.load_slice(0, &mut weight);
+        let mut leaf_data = voter_address.as_managed_buffer().clone();
+        leaf_data.append_bytes(&weight);
+        let leaf = self.crypto().sha256(&leaf_data);
+
+        let mut node = leaf.to_byte_array();
+        for offset in (8..verification_data.len()).step_by(32) {
+            let mut sibling = [0u8; 32];
+            let _ = verification_data.load_slice(offset, &mut sibling);
+            let mut pair = ManagedBuffer::new();
+            if node <= sibling {
+                pair.append_bytes(&node);
+                pair.append_bytes(&sibling);
+            } else {
+                pair.append_bytes(&sibling);
+                pair.append_bytes(&node);
+            }
+            node = self.crypto().sha256(&pair).to_byte_array();
+        }
+        require!(node == voter_roll.root.to_byte_array(), "Invalid voter roll proof");
+        require!(!self.roll_leaf_used(election_id, leaf.clone()).get(), "Voter roll entry already used");
+
+        let weight = u64::from_be_bytes(weight);
+        if voter_roll.weighted {
+            require!(weight > 0, "Voter weight cannot be zero");
+            self.voter_weight(election_id, voter_address.clone()).set(BigUint::from(weight));
+        }
+        self.roll_leaf_used(election_id, leaf).set(true);
+        self.roll_registrations(election_id).update(|registrations| *registrations += 1);
+        self.registered_voters(election_id).insert(voter_address.clone());
+        self.mint_voter_credential(election_id, &voter_address);
+        self.voter_eligible(election_id, voter_address).set(true);
+    }
+
     #[endpoint(registerVoter)]
     fn register_voter(&self, election_id: ElectionID, voter_address: ManagedAddress) {
         require!(self.election_id_list().contains(&election_id), "Election does not exist");
@@ -457,6 +2712,7 @@ pub trait BackendSc {
 
 
         self.registered_voters(election_id).insert(voter_address.clone());
+        self.mint_voter_credential(election_id, &voter_address);
         self.voter_eligible(election_id, voter_address).set(&true);
 
 
@@ -465,32 +2721,633 @@ pub trait BackendSc {
     
 
     #[endpoint(vote)]
-    fn vote(&self, election_id: ElectionID, vote: MultiValueEncoded<CandidateID>) {
+    #[payable("*")]
+    fn vote(&self, election_id: ElectionID, vote: MultiValueEncoded<CandidateID>) -> OptionalValue<Receipt<Self::Api>> {
 
-        let voter_address = self.blockchain().get_caller();
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.reveal_period(election_id).get() == 0, "Election requires committed votes");
+        require!(self.trustees(election_id).is_empty(), "Election requires encrypted votes");
 
+        // an empty vote is a blank ballot, if the election allows them (blank ballots are not in the ledger, so they have no receipt)
+        if vote.is_empty() && self.blank_ballots_allowed(election_id).get() {
+            self.cast_blank_ballot(election_id);
+            return OptionalValue::None;
+        }
+
+        let x = self.build_vote(election_id, vote);
+        OptionalValue::Some(self.cast_vote(election_id, x))
+    }
+
+    /// Registers the caller from the voter roll of the election (see `registerSelf`) and casts their vote,
+    /// in a single transaction.
+    #[endpoint(voteWithProof)]
+    #[payable("*")]
+    fn vote_with_proof(&self, election_id: ElectionID, verification_data: ManagedBuffer, vote: MultiValueEncoded<CandidateID>) -> OptionalValue<Receipt<Self::Api>> {
         require!(self.election_id_list().contains(&election_id), "Election does not exist");
-        require!(self.election_data(election_id).get().ended == false, "Election has already ended");
-        require!(self.registered_voters(election_id).contains(&voter_address), "You are not registered as a voter");
-        require!(self.voter_eligible(election_id, voter_address).get(), "You are not eligible to vote");
+        require!(!self.voter_roll(election_id).is_empty(), "Election has no voter roll");
+        require!(!self.election_data(election_id).get().ended, "Election has already ended");
+        require!(!self.registered_voters(election_id).contains(&self.blockchain().get_caller()), "Already registered");
+        require!(!self.credential_required(election_id).get(), "Election requires a voter credential, register with registerSelf first");
+
+        self.register_from_voter_roll(election_id, &verification_data);
+        self.vote(election_id, vote)
+    }
+
+    /// Validates a vote listing candidates against the election type and the ballot rules of the election.
+    fn build_vote(&self, election_id: ElectionID, vote: MultiValueEncoded<CandidateID>) -> Vote<Self::Api> {
+        require!(!self.election_data(election_id).get().election_type.uses_ratings(), "Election requires rated votes");
         
         if self.election_data(election_id).get().election_type == ElectionType::Plurality {
             require!(vote.len() == 1, "Plurality election can only have one candidate");
         }
+        if self.election_data(election_id).get().election_type == ElectionType::TwoRound {
+            require!(vote.len() == 1, "Two-Round election can only have one candidate");
+        }
+        if self.election_data(election_id).get().election_type == ElectionType::PartyList {
+            require!(vote.len() == 1, "Party-List election can only have one party");
+        }
+        if self.election_data(election_id).get().election_type == ElectionType::Referendum {
+            require!(vote.len() == 1, "Referendum election can only have one choice");
+        }
+
+        let candidates = vote.to_vec();
+        for (i, c_id) in candidates.iter().enumerate() {
+            // the vote of a Party-List election is for a party, the vote of a Referendum election for a choice
+            if self.election_data(election_id).get().election_type == ElectionType::PartyList {
+                require!(self.party_id_list(election_id).contains(&c_id), "Invalid party");
+            } else if self.election_data(election_id).get().election_type == ElectionType::Referendum {
+                require!((REFERENDUM_YES..=REFERENDUM_ABSTAIN).contains(&c_id), "Invalid referendum choice");
+            } else {
+                require!(self.candidate_id_list(election_id).contains(&c_id), "Invalid candidate");
+            }
+            require!(!candidates.iter().take(i).any(|c| c == c_id), "Candidate selected more than once");
+        }
+
+        let ballot_rules = self.ballot_rules(election_id).get();
+        require!(candidates.len() >= ballot_rules.min_selections as usize, "Not enough candidates selected");
+        require!(ballot_rules.max_selections == 0 || candidates.len() <= ballot_rules.max_selections as usize, "Too many candidates selected");
+        if ballot_rules.full_ranking {
+            require!(candidates.len() == self.candidate_id_list(election_id).len(), "All candidates must be ranked");
+        }
+
+        Vote {candidates, ratings: ManagedVec::new(), credits: 0, weight: BigUint::from(1u64)}
+    }
+
+    #[endpoint(voteRated)]
+    #[payable("*")]
+    fn vote_rated(&self, election_id: ElectionID, ratings: MultiValueEncoded<MultiValue2<CandidateID, i64>>) -> Receipt<Self::Api> {
+
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.reveal_period(election_id).get() == 0, "Election requires committed votes");
+
+        let x = self.build_rated_vote(election_id, ratings);
+        let credits = x.credits;
+        let receipt = self.cast_vote(election_id, x);
+        if credits > 0 {
+            self.voice_credits_spent(election_id, self.blockchain().get_caller()).update(|spent| *spent += credits);
+        }
+        receipt
+    }
+
+    /// Delegates the vote of the caller to another registered voter, until voting ends. The delegated weight
+    /// follows the delegations of the delegate up to the first voter who votes directly; a delegator who votes
+    /// directly overrides their delegation.
+    #[endpoint(delegateVote)]
+    fn delegate_vote(&self, election_id: ElectionID, delegate: ManagedAddress) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.delegation_allowed(election_id).get(), "Election does not allow delegation");
+        let election_data = self.election_data(election_id).get();
+        require!(!election_data.ended && election_data.end_time > self.blockchain().get_block_timestamp(), "Voting has ended");
+
+        let voter_address = self.blockchain().get_caller();
+        require!(self.registered_voters(election_id).contains(&voter_address), "You are not registered as a voter");
+        require!(self.voter_eligible(election_id, voter_address.clone()).get(), "You are not eligible to vote");
+        require!(self.current_ballot(election_id, voter_address.clone()).is_empty(), "You have already voted");
+        require!(self.registered_voters(election_id).contains(&delegate), "Delegate is not a registered voter");
+        require!(delegate != voter_address, "Cannot delegate to yourself");
+
+        let mut next = delegate.clone();
+        while !self.delegate(election_id, next.clone()).is_empty() {
+            next = self.delegate(election_id, next).get();
+            require!(next != voter_address, "Delegation would create a cycle");
+        }
+
+        self.delegate(election_id, voter_address.clone()).set(delegate);
+        self.delegators(election_id).insert(voter_address);
+    }
+
+    #[endpoint(revokeDelegation)]
+    fn revoke_delegation(&self, election_id: ElectionID) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        let election_data = self.election_data(election_id).get();
+        require!(!election_data.ended && election_data.end_time > self.blockchain().get_block_timestamp(), "Voting has ended");
+
+        let voter_address = self.blockchain().get_caller();
+        require!(self.delegators(election_id).contains(&voter_address), "No delegation to revoke");
+
+        self.delegate(election_id, voter_address.clone()).clear();
+        self.delegators(election_id).swap_remove(&voter_address);
+    }
 
-        for c_id in vote.clone() {
+    /// Validates a vote rating candidates against the election type and its rating scale, point budget,
+    /// grades or voice credit budget.
+    fn build_rated_vote(&self, election_id: ElectionID, ratings: MultiValueEncoded<MultiValue2<CandidateID, i64>>) -> Vote<Self::Api> {
+        require!(self.election_data(election_id).get().election_type.uses_ratings(), "Election does not use rated votes");
+
+        let election_type = self.election_data(election_id).get().election_type;
+        let rating_scale = if election_type.uses_rating_scale() { self.rating_scale(election_id).get() } else { RatingScale::default() };
+        let point_budget = self.point_budget(election_id).get();
+        // with revoting, the ballot replaces the current ballot of the voter, whose credits are refunded
+        let voice_credits_spent = if self.revoting_allowed(election_id).get() { 0 } else { self.voice_credits_spent(election_id, self.blockchain().get_caller()).get() };
+        let voice_credits = self.voice_credit_budget(election_id).get() - voice_credits_spent;
+        let mut points_spent = 0u64;
+        let mut x = Vote {candidates: ManagedVec::new(), ratings: ManagedVec::new(), credits: 0, weight: BigUint::from(1u64)};
+        for item in ratings.into_iter() {
+            let (c_id, rating) = item.into_tuple();
             require!(self.candidate_id_list(election_id).contains(&c_id), "Invalid candidate");
+            require!(!x.candidates.contains(&c_id), "Candidate rated more than once");
+            // only a Quadratic vote can be against a candidate
+            require!(rating >= 0 || election_type == ElectionType::Quadratic, "Rating cannot be negative");
+            let amount = rating.unsigned_abs();
+            if election_type.uses_rating_scale() {
+                require!(amount >= rating_scale.min && amount <= rating_scale.max, "Rating out of bounds");
+            }
+            if election_type == ElectionType::Cumulative {
+                require!(amount <= point_budget - points_spent, "Points exceed the budget");
+                points_spent += amount;
+            }
+            if election_type == ElectionType::MajorityJudgment {
+                require!(amount < self.grades(election_id).len() as u64, "Invalid grade");
+            }
+            if election_type == ElectionType::Quadratic {
+                // k votes for or against a candidate cost k^2 voice credits
+                let cost = amount.checked_mul(amount);
+                require!(cost.is_some_and(|cost| cost <= voice_credits - x.credits), "Voice credits exceed the budget");
+                x.credits += cost.unwrap_or(0);
+            }
+
+            x.candidates.push(c_id);
+            x.ratings.push(rating);
+        }
+        x
+    }
+
+    /// Commits the vote of the caller in a commit-reveal election.
+    ///
+    /// The commitment is `sha256(election_id || voter || ballot || salt)` with a random 32-byte salt (see `ballot_hash`),
+    /// so it cannot be replayed in another election or by another voter; the vote is only counted once it is
+    /// revealed with `revealVote` or `revealVoteRated` during the reveal period. In a token-weighted election,
+    /// the stake is paid and locked when committing.
+    #[endpoint(commitVote)]
+    #[payable("*")]
+    fn commit_vote(&self, election_id: ElectionID, hash: ManagedByteArray<Self::Api, 32>) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.reveal_period(election_id).get() > 0, "Election does not use committed votes");
+        require!(self.election_data(election_id).get().end_time > self.blockchain().get_block_timestamp(), "Voting has ended");
+
+        let weight = self.use_voting_right(election_id);
+        let voter_address = self.blockchain().get_caller();
+        self.commitment(election_id, voter_address.clone()).set(Commitment { hash, weight });
+        self.unrevealed_voters(election_id).insert(voter_address);
+    }
+
+    #[endpoint(revealVote)]
+    fn reveal_vote(&self, election_id: ElectionID, salt: ManagedByteArray<Self::Api, 32>, vote: MultiValueEncoded<CandidateID>) -> OptionalValue<Receipt<Self::Api>> {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+
+        // an empty vote is a blank ballot, if the election allows them
+        if vote.is_empty() && self.blank_ballots_allowed(election_id).get() {
+            let blank = Vote {candidates: ManagedVec::new(), ratings: ManagedVec::new(), credits: 0, weight: BigUint::zero()};
+            self.check_commitment(election_id, &blank, &salt);
+            self.blank_ballots(election_id).update(|blank_ballots| *blank_ballots += 1);
+            self.election_data(election_id).update(|election_data| election_data.turnout += 1);
+            return OptionalValue::None;
+        }
+
+        let mut x = self.build_vote(election_id, vote);
+        x.weight = self.check_commitment(election_id, &x, &salt);
+        OptionalValue::Some(self.record_vote(election_id, x))
+    }
+
+    #[endpoint(revealVoteRated)]
+    fn reveal_vote_rated(&self, election_id: ElectionID, salt: ManagedByteArray<Self::Api, 32>, ratings: MultiValueEncoded<MultiValue2<CandidateID, i64>>) -> Receipt<Self::Api> {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+
+        let mut x = self.build_rated_vote(election_id, ratings);
+        x.weight = self.check_commitment(election_id, &x, &salt);
+        let credits = x.credits;
+        let receipt = self.record_vote(election_id, x);
+        if credits > 0 {
+            self.voice_credits_spent(election_id, self.blockchain().get_caller()).update(|spent| *spent += credits);
+        }
+        receipt
+    }
+
+    /// Checks that a revealed vote matches the commitment of the caller, during the reveal period,
+    /// and marks the commitment as revealed.
+    ///
+    /// Returns the weight locked with the commitment.
+    fn check_commitment(&self, election_id: ElectionID, vote: &Vote<Self::Api>, salt: &ManagedByteArray<Self::Api, 32>) -> BigUint {
+        let election_data = self.election_data(election_id).get();
+        let now = self.blockchain().get_block_timestamp();
+        let voter_address = self.blockchain().get_caller();
+
+        require!(self.reveal_period(election_id).get() > 0, "Election does not use committed votes");
+        require!(election_data.ended || election_data.end_time <= now, "Election has not ended yet");
+        require!(now <= election_data.end_time + self.reveal_period(election_id).get(), "Reveal period has ended");
+        require!(self.unrevealed_voters(election_id).contains(&voter_address), "No committed vote to reveal");
+
+        let commitment = self.commitment(election_id, voter_address.clone()).get();
+        require!(self.ballot_hash(election_id, &voter_address, vote, salt) == commitment.hash, "Vote does not match the commitment");
+
+        self.unrevealed_voters(election_id).swap_remove(&voter_address);
+        commitment.weight
+    }
+
+    /// Computes the commitment of a vote: `sha256(election_id || voter || ballot || salt)`, where the election ID
+    /// is 8 bytes (big-endian), the voter is their 32-byte address, the ballot is the list of candidate IDs
+    /// (2 bytes each, big-endian) of the vote, each followed by its rating (8 bytes, big-endian) for rated votes,
+    /// and the salt is 32 bytes. A blank ballot is empty. As every other part has a fixed length, the ballot
+    /// cannot be shifted into the salt, and the commitment is only valid for this election and this voter.
+    fn ballot_hash(&self, election_id: ElectionID, voter_address: &ManagedAddress, vote: &Vote<Self::Api>, salt: &ManagedByteArray<Self::Api, 32>) -> ManagedByteArray<Self::Api, 32> {
+        let mut ballot = ManagedBuffer::new_from_bytes(&election_id.to_be_bytes());
+        ballot.append(voter_address.as_managed_buffer());
+        for (i, c_id) in vote.candidates.iter().enumerate() {
+            ballot.append_bytes(&c_id.to_be_bytes());
+            if !vote.ratings.is_empty() {
+                ballot.append_bytes(&vote.ratings.get(i).to_be_bytes());
+            }
+        }
+        ballot.append(salt.as_managed_buffer());
+        self.crypto().sha256(&ballot)
+    }
+
+    /// Records the vote of the caller, after checking that they are allowed to vote.
+    ///
+    /// In a token-weighted election, the vote must be paid with the voting token: the payment is locked
+    /// in the contract as the stake of the voter (see `withdrawStake`) and becomes the weight of the vote.
+    fn cast_vote(&self, election_id: ElectionID, mut vote: Vote<Self::Api>) -> Receipt<Self::Api> {
+        vote.weight = self.use_voting_right(election_id);
+        self.record_vote(election_id, vote)
+    }
+
+    /// Checks that the caller is allowed to vote and marks them as having voted. In an election with revoting,
+    /// the caller stays eligible and their current ballot, if any, is superseded instead.
+    ///
+    /// Returns the weight of their vote: the stake paid with the voting token in a token-weighted election,
+    /// the weight of the voter in a weighted voter roll, 1 otherwise.
+    fn use_voting_right(&self, election_id: ElectionID) -> BigUint {
+
+        let voter_address = self.blockchain().get_caller();
+
+        require!(self.election_data(election_id).get().ended == false, "Election has already ended");
+        require!(self.registered_voters(election_id).contains(&voter_address), "You are not registered as a voter");
+        require!(self.voter_eligible(election_id, voter_address.clone()).get(), "You are not eligible to vote");
+        if self.revoting_allowed(election_id).get() {
+            self.supersede_current_ballot(election_id, &voter_address);
+        }
+
+        let mut payments = self.call_value().all_esdt_transfers().clone_value();
+        if self.credential_required(election_id).get() {
+            self.use_voter_credential(election_id, &voter_address, &mut payments);
+        }
+
+        let weight = if self.voting_token(election_id).is_empty() {
+            require!(*self.call_value().egld_value() == 0u64 && payments.is_empty(), "Election does not accept payments");
+            self.voter_roll_weight(election_id, &voter_address)
+        } else {
+            require!(payments.len() == 1, "The stake must be paid in a single transfer of the voting token");
+            let payment = payments.get(0);
+            require!(payment.token_identifier == self.voting_token(election_id).get(), "Invalid stake token");
+            require!(payment.amount > 0u64, "Stake cannot be zero");
+
+            self.stake(election_id, voter_address.clone()).set(&payment.amount);
+            payment.amount
+        };
+
+        if !self.revoting_allowed(election_id).get() {
+            self.voter_eligible(election_id, voter_address).set(&false);
+        }
+        weight
+    }
+
+    /// Withdraws the current ballot of a voter from the results of an election with revoting, before they cast
+    /// a new ballot: the ballot stays in the ledger but is marked as superseded (a blank ballot is no longer
+    /// counted), and it no longer counts toward the turnout.
+    fn supersede_current_ballot(&self, election_id: ElectionID, voter_address: &ManagedAddress) {
+        require!(self.election_data(election_id).get().end_time > self.blockchain().get_block_timestamp(), "Voting has ended");
+
+        let current_ballot = self.current_ballot(election_id, voter_address.clone()).get();
+        if current_ballot == 0 {
+            return;
+        }
+        if current_ballot == BLANK_BALLOT {
+            self.blank_ballots(election_id).update(|blank_ballots| *blank_ballots -= 1);
+        } else {
+            let credits = self.ballots(election_id).get(current_ballot as usize).vote.credits;
+            self.voice_credits_spent(election_id, voter_address.clone()).update(|spent| *spent -= credits);
+            self.superseded_ballots(election_id).insert(current_ballot);
+        }
+        self.current_ballot(election_id, voter_address.clone()).clear();
+        self.election_data(election_id).update(|election_data| election_data.turnout -= 1);
+    }
+
+    /// Appends a vote to the ballot ledger of the election, with the next ballot ID and the current block.
+    ///
+    /// Returns the receipt of the ballot.
+    fn record_vote(&self, election_id: ElectionID, vote: Vote<Self::Api>) -> Receipt<Self::Api> {
+        let ballot = Ballot {
+            id: self.ballots(election_id).len() as BallotID + 1,
+            vote,
+            timestamp: self.blockchain().get_block_timestamp(),
+            block_nonce: self.blockchain().get_block_nonce(),
+        };
+        self.ballots(election_id).push(&ballot);
+        let mut salt = [0u8; 32];
+        RandomnessSource::new().next_bytes(32).load_to_byte_array(&mut salt);
+        let salt = ManagedByteArray::new_from_bytes(&salt);
+        let hash = self.receipt_hash(election_id, &ballot, &salt);
+        self.receipt_hashes(election_id).push(&hash);
+        if self.tracks_current_ballots(election_id) {
+            self.current_ballot(election_id, self.blockchain().get_caller()).set(ballot.id);
+        }
+        self.election_data(election_id).update(|election_data| election_data.turnout += 1);
+        Receipt { ballot_id: ballot.id, salt, hash }
+    }
+
+    /// Computes the hash of the receipt of a ballot: `sha256(election ID || ballot || salt)`, where the election ID
+    /// is 8 bytes, big-endian, the ballot is encoded as returned by `getBallot` (ballot ID, vote, timestamp and
+    /// block nonce), so the hash commits to both the vote and its position in the ledger, and the salt is the
+    /// 32 random bytes of the receipt.
+    fn receipt_hash(&self, election_id: ElectionID, ballot: &Ballot<Self::Api>, salt: &ManagedByteArray<Self::Api, 32>) -> ManagedByteArray<Self::Api, 32> {
+        let mut data = ManagedBuffer::new_from_bytes(&election_id.to_be_bytes());
+        let mut encoded_ballot = ManagedBuffer::new();
+        require!(ballot.top_encode(&mut encoded_ballot).is_ok(), "Failed to encode the ballot");
+        data.append(&encoded_ballot);
+        data.append(salt.as_managed_buffer());
+        self.crypto().sha256(&data)
+    }
+
+    /// Records a blank ballot: it counts toward the turnout of the election, but not toward any candidate (it is not in the ballot ledger).
+    fn cast_blank_ballot(&self, election_id: ElectionID) {
+
+        let voter_address = self.blockchain().get_caller();
+
+        require!(!self.election_data(election_id).get().ended, "Election has already ended");
+        require!(self.registered_voters(election_id).contains(&voter_address), "You are not registered as a voter");
+        require!(self.voter_eligible(election_id, voter_address.clone()).get(), "You are not eligible to vote");
+        let mut payments = self.call_value().all_esdt_transfers().clone_value();
+        if self.credential_required(election_id).get() {
+            self.use_voter_credential(election_id, &voter_address, &mut payments);
+        }
+        require!(*self.call_value().egld_value() == 0u64 && payments.is_empty(), "Blank ballots do not accept payments");
+
+        self.blank_ballots(election_id).update(|blank_ballots| *blank_ballots += 1);
+        if self.revoting_allowed(election_id).get() {
+            self.supersede_current_ballot(election_id, &voter_address);
+        } else {
+            self.voter_eligible(election_id, voter_address.clone()).set(false);
+        }
+        if self.tracks_current_ballots(election_id) {
+            self.current_ballot(election_id, voter_address).set(BLANK_BALLOT);
+        }
+        self.election_data(election_id).update(|election_data| election_data.turnout += 1);
+    }
+
+    /// Casts an encrypted ballot in an encrypted election.
+    ///
+    /// The ballot holds one encrypted choice per candidate: an ElGamal encryption of 1 if the candidate is selected,
+    /// 0 otherwise, under the joint public key of the trustees (see `getJointPublicKey`), with a proof that it
+    /// encrypts 0 or 1. A Plurality ballot also needs `sum_proof`, proving that the product of its ciphertexts
+    /// encrypts 0 or 1, i.e. that at most one candidate is selected. The proofs are bound to the voter's address,
+    /// so a ballot cannot be replayed by another voter.
+    ///
+    /// The ciphertexts are multiplied into the encrypted tally of each candidate (see `getEncryptedTally`),
+    /// which the trustees decrypt after the election (see `postDecryptionShares` and `publishTally`).
+    #[endpoint(voteEncrypted)]
+    #[payable("*")]
+    fn vote_encrypted(&self, election_id: ElectionID, ballot: ManagedVec<EncryptedChoice<Self::Api>>, sum_proof: OptionalValue<BallotProof<Self::Api>>) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(!self.trustees(election_id).is_empty(), "Election does not use encrypted votes");
+        require!(ballot.len() == self.candidate_id_list(election_id).len(), "Ballot must contain every candidate");
+
+        let voter_address = self.blockchain().get_caller();
+        let joint_key = self.joint_public_key(election_id);
+        let mut product = Ciphertext { a: BigUint::from(1u64), b: BigUint::from(1u64) };
+        for (i, choice) in ballot.iter().enumerate() {
+            require!(self.candidate_id_list(election_id).contains(&choice.candidate), "Invalid candidate");
+            require!(!ballot.iter().take(i).any(|c| c.candidate == choice.candidate), "Candidate selected more than once");
+            require!(self.verify_ballot_proof(election_id, &voter_address, &joint_key, &choice.ciphertext, &choice.proof), "Invalid ballot proof");
+            product.a = self.mod_mul(&product.a, &choice.ciphertext.a);
+            product.b = self.mod_mul(&product.b, &choice.ciphertext.b);
+        }
+        if self.election_data(election_id).get().election_type == ElectionType::Plurality {
+            let sum_proof = sum_proof.into_option();
+            require!(sum_proof.is_some(), "Plurality ballots require a proof that at most one candidate is selected");
+            require!(self.verify_ballot_proof(election_id, &voter_address, &joint_key, &product, &sum_proof.unwrap()), "Invalid ballot proof");
+        }
+
+        self.use_voting_right(election_id);
+
+        for choice in ballot.iter() {
+            let tally = self.get_encrypted_tally(election_id, choice.candidate);
+            self.encrypted_tally(election_id, choice.candidate).set(Ciphertext {
+                a: self.mod_mul(&tally.a, &choice.ciphertext.a),
+                b: self.mod_mul(&tally.b, &choice.ciphertext.b),
+            });
+        }
+        self.encrypted_ballots(election_id).push(&ballot);
+        self.election_data(election_id).update(|election_data| election_data.turnout += 1);
+    }
+
+    /// Posts the decryption shares of the calling trustee, once the election has ended.
+    ///
+    /// For each candidate, the share is `d = A^s (mod p)`, where `(A, B)` is the encrypted tally of the candidate
+    /// and `s` the trustee's share of the joint secret key, with a Chaum-Pedersen proof that it uses the same secret as
+    /// the trustee's verification key `v` (see `getTrusteeVerificationKey`): `g^response == t_g * v^c` and
+    /// `A^response == t_a * d^c (mod p)`.
+    #[endpoint(postDecryptionShares)]
+    fn post_decryption_shares(&self, election_id: ElectionID, shares: MultiValueEncoded<DecryptionShare<Self::Api>>) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        let election_data = self.election_data(election_id).get();
+        require!(election_data.ended || election_data.end_time <= self.blockchain().get_block_timestamp(), "Election has not ended yet");
+        let trustee = self.blockchain().get_caller();
+        require!(self.trustees(election_id).contains(&trustee), "Only trustees can post decryption shares");
+        require!(!self.decrypting_trustees(election_id).contains(&trustee), "Decryption shares already posted");
+
+        let shares = shares.to_vec();
+        require!(shares.len() == self.candidate_id_list(election_id).len(), "Shares must cover every candidate");
+        let g = self.elgamal_generator();
+        let public_key = self.trustee_verification_key(election_id, trustee.clone());
+        for (i, share) in shares.iter().enumerate() {
+            require!(self.candidate_id_list(election_id).contains(&share.candidate), "Invalid candidate");
+            require!(!shares.iter().take(i).any(|s| s.candidate == share.candidate), "Candidate shared more than once");
+            require!(self.is_group_element(&share.share), "Invalid decryption share");
+
+            let a = self.get_encrypted_tally(election_id, share.candidate).a;
+            let c = self.challenge(election_id, &trustee, &[&public_key, &a, &share.share, &share.t_g, &share.t_a]);
+            require!(self.mod_pow(&g, &share.response) == self.mod_mul(&share.t_g, &self.mod_pow(&public_key, &c)), "Invalid decryption share proof");
+            require!(self.mod_pow(&a, &share.response) == self.mod_mul(&share.t_a, &self.mod_pow(&share.share, &c)), "Invalid decryption share proof");
+
+            self.decryption_share(election_id, trustee.clone(), share.candidate).set(share.share);
+        }
+        self.decrypting_trustees(election_id).insert(trustee);
+    }
+
+    /// Publishes the decrypted tally of an encrypted election, once `threshold` trustees have posted their decryption shares.
+    ///
+    /// Anyone can publish it: each count is checked against the encrypted tally `(A, B)` of the candidate and the shares
+    /// `d_j` of the first `threshold` trustees who posted them, combined by Lagrange interpolation at 0:
+    /// `g^count * prod_j d_j^l_j == B (mod p)`, where `l_j = prod_(m != j) m / (m - j) (mod q)` over their positions.
+    /// The counts are then stored in `result_vector` and the election can be finalised by `results`.
+    #[endpoint(publishTally)]
+    fn publish_tally(&self, election_id: ElectionID, counts: MultiValueEncoded<MultiValue2<CandidateID, u64>>) {
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(!self.trustees(election_id).is_empty(), "Election does not use encrypted votes");
+        let threshold = self.trustee_threshold(election_id).get();
+        require!(self.decrypting_trustees(election_id).len() >= threshold, "Decryption shares are missing");
+        require!(!self.tally_published(election_id).get(), "Tally has already been published");
+        require!(counts.len() == self.candidate_id_list(election_id).len(), "Tally must cover every candidate");
+
+        let counts: ManagedVec<VotingResult<Self::Api>> = counts.into_iter().map(|x| {
+            let (candidate, count) = x.into_tuple();
+            VotingResult { candidate, count: BigUint::from(count) }
+        }).collect();
+        let decrypting_trustees: ManagedVec<ManagedAddress> = self.decrypting_trustees(election_id).iter().take(threshold).collect();
+        let mut indexes = ManagedVec::<Self::Api, u64>::new();
+        for trustee in decrypting_trustees.iter() {
+            indexes.push(self.trustees(election_id).get_index(&trustee) as u64);
+        }
+        let lagrange_coefficients: ManagedVec<BigUint> = indexes.iter().map(|index| self.lagrange_coefficient(&indexes, index)).collect();
+
+        let g = self.elgamal_generator();
+        for (i, result) in counts.iter().enumerate() {
+            require!(self.candidate_id_list(election_id).contains(&result.candidate), "Invalid candidate");
+            require!(!counts.iter().take(i).any(|r| r.candidate == result.candidate), "Candidate counted more than once");
+
+            let mut decrypted = self.mod_pow(&g, &result.count);
+            for (trustee, coefficient) in decrypting_trustees.iter().zip(lagrange_coefficients.iter()) {
+                let share = self.decryption_share(election_id, trustee.clone_value(), result.candidate).get();
+                decrypted = self.mod_mul(&decrypted, &self.mod_pow(&share, &coefficient));
+            }
+            require!(decrypted == self.get_encrypted_tally(election_id, result.candidate).b, "Tally does not match the decryption shares");
+
+            self.result_vector(election_id, result.candidate).set(&result.count);
+        }
+        self.tally_published(election_id).set(true);
+    }
+
+    /// Computes the Lagrange coefficient at 0 of the trustee at position `index` among the trustees at `indexes`:
+    /// `prod_(m != index) m / (m - index) (mod q)`, the division being a multiplication by the inverse mod `q`.
+    fn lagrange_coefficient(&self, indexes: &ManagedVec<u64>, index: u64) -> BigUint {
+        let q = self.elgamal_order();
+        let mut numerator = BigUint::from(1u64);
+        let mut denominator = BigUint::from(1u64);
+        for m in indexes.iter().filter(|m| *m != index) {
+            numerator = numerator * m % &q;
+            denominator = if m > index {
+                denominator * (m - index) % &q
+            } else {
+                denominator * (&q - (index - m)) % &q
+            };
+        }
+        // q is prime, so the inverse of the denominator is denominator^(q - 2) (mod q)
+        numerator * self.pow_mod(&denominator, &(&q - 2u64), &q) % &q
+    }
+
+    /// Verifies a disjunctive Chaum-Pedersen proof that the ciphertext `(a, b)` encrypts 0 or 1 under the public key `h`.
+    ///
+    /// The proof holds a real proof for the encrypted value and a simulated one for the other value, with
+    /// `c0 + c1 == c (mod q)`, where `c` is the challenge of the ciphertext and the commitments:
+    /// `g^s0 == t_a0 * a^c0`, `h^s0 == t_b0 * b^c0` (encrypts 0) and `g^s1 == t_a1 * a^c1`, `h^s1 * g^c1 == t_b1 * b^c1` (encrypts 1).
+    fn verify_ballot_proof(&self, election_id: ElectionID, voter_address: &ManagedAddress, public_key: &BigUint, ciphertext: &Ciphertext<Self::Api>, proof: &BallotProof<Self::Api>) -> bool {
+        if !self.is_group_element(&ciphertext.a) || !self.is_group_element(&ciphertext.b) {
+            return false;
+        }
+        let q = self.elgamal_order();
+        if proof.c0 >= q || proof.c1 >= q {
+            return false;
+        }
+        let c = self.challenge(election_id, voter_address, &[&ciphertext.a, &ciphertext.b, &proof.t_a0, &proof.t_b0, &proof.t_a1, &proof.t_b1]);
+        if (&proof.c0 + &proof.c1) % &q != c {
+            return false;
+        }
+
+        let g = self.elgamal_generator();
+        self.mod_pow(&g, &proof.s0) == self.mod_mul(&proof.t_a0, &self.mod_pow(&ciphertext.a, &proof.c0))
+            && self.mod_pow(public_key, &proof.s0) == self.mod_mul(&proof.t_b0, &self.mod_pow(&ciphertext.b, &proof.c0))
+            && self.mod_pow(&g, &proof.s1) == self.mod_mul(&proof.t_a1, &self.mod_pow(&ciphertext.a, &proof.c1))
+            && self.mod_mul(&self.mod_pow(public_key, &proof.s1), &self.mod_pow(&g, &proof.c1)) == self.mod_mul(&proof.t_b1, &self.mod_pow(&ciphertext.b, &proof.c1))
+    }
+
+    /// Computes the Fiat-Shamir challenge of a proof: `sha256(election_id || address || values) mod q`, where the election ID
+    /// is 8 bytes big-endian, the address is the prover's (32 bytes) and each value is 256 bytes big-endian.
+    fn challenge(&self, election_id: ElectionID, address: &ManagedAddress, values: &[&BigUint]) -> BigUint {
+        let mut data = ManagedBuffer::new();
+        data.append_bytes(&election_id.to_be_bytes());
+        data.append(address.as_managed_buffer());
+        for value in values {
+            let bytes = value.to_bytes_be_buffer();
+            require!(bytes.len() <= ELGAMAL_PRIME.len(), "Value is too large");
+            data.append_bytes(&[0u8; ELGAMAL_PRIME.len()][bytes.len()..]);
+            data.append(&bytes);
+        }
+        BigUint::from_bytes_be_buffer(self.crypto().sha256(&data).as_managed_buffer()) % &self.elgamal_order()
+    }
+
+    /// Checks that a number belongs to the subgroup of order `q` of the ElGamal group.
+    fn is_group_element(&self, x: &BigUint) -> bool {
+        *x > 0u64 && *x < self.elgamal_prime() && self.mod_pow(x, &self.elgamal_order()) == 1u64
+    }
+
+    fn elgamal_prime(&self) -> BigUint {
+        BigUint::from_bytes_be(&ELGAMAL_PRIME)
+    }
 
+    /// The order `q` of the subgroup votes are encrypted in.
+    fn elgamal_order(&self) -> BigUint {
+        BigUint::from_bytes_be(&ELGAMAL_ORDER)
+    }
+
+    fn elgamal_generator(&self) -> BigUint {
+        BigUint::from_bytes_be(&ELGAMAL_GENERATOR)
+    }
+
+    fn mod_mul(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        x * y % &self.elgamal_prime()
+    }
+
+    /// Computes `base^exponent (mod p)` by square-and-multiply. Exponents are reduced modulo `q`, so they have at most
+    /// 256 bits: the VM has no modular exponentiation, each of the up to 256 steps costs one or two multiplications.
+    fn mod_pow(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        self.pow_mod(base, exponent, &self.elgamal_prime())
+    }
+
+    /// Computes `base^exponent (mod modulus)` by square-and-multiply, for exponents of at most 256 bits.
+    fn pow_mod(&self, base: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        let base = base % modulus;
+        let exponent = exponent.to_bytes_be_buffer();
+        let mut bytes = [0u8; ELGAMAL_ORDER.len()];
+        require!(exponent.len() <= bytes.len(), "Exponent is too large");
+        let bytes = &mut bytes[..exponent.len()];
+        let _ = exponent.load_slice(0, bytes);
+
+        let mut result = BigUint::from(1u64);
+        for byte in bytes.iter() {
+            for bit in (0..8).rev() {
+                result = &result * &result % modulus;
+                if (byte >> bit) & 1 == 1 {
+                    result = &result * &base % modulus;
+                }
+            }
         }
-        let x = Vote {candidates: vote.to_vec()};
-        self.votes(election_id).insert(x);
-        self.voter_eligible(election_id, self.blockchain().get_caller()).set(&false);
+        result
     }
 
     #[endpoint(endElection)]
     fn end_election(&self, election_id: ElectionID) {
-        require!(self.blockchain().get_caller() == self.blockchain().get_caller(), "Only admin can end election");
         require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(self.blockchain().get_caller() == self.election_data(election_id).get().admin, "Only admin can end election");
         require!(self.election_data(election_id).get().ended == false, "Election has already ended");
 
         self.election_data(election_id).update(|election_data| {
@@ -498,6 +3355,19 @@ pub trait BackendSc {
         });
     }
 
+    #[endpoint(withdrawStake)]
+    fn withdraw_stake(&self, election_id: ElectionID) {
+        let voter_address = self.blockchain().get_caller();
+        let election_data = self.election_data(election_id).get();
+
+        require!(self.election_id_list().contains(&election_id), "Election does not exist");
+        require!(election_data.ended || election_data.end_time <= self.blockchain().get_block_timestamp(), "Election has not ended yet");
+        require!(!self.stake(election_id, voter_address.clone()).is_empty(), "No stake to withdraw");
+
+        let amount = self.stake(election_id, voter_address.clone()).take();
+        self.send().direct_esdt(&voter_address, &self.voting_token(election_id).get(), 0, &amount);
+    }
+
     #[endpoint(makeDispute)]
     fn make_dispute(&self, election_id: ElectionID, dispute_name: ManagedBuffer, dispute_description: ManagedBuffer) -> DisputeID {
 
@@ -8,7 +8,30 @@ pub enum ElectionType {
     Plurality = 0, // single vote, candidate with most votes wins
     Approval = 1, // any number of candidates, candidate with most votes wins
     SingleTransferableVote = 2, // gives ordering of candidates, candidate receive votes from voter if they are the most favoured
-                        // if candidate has least number of votes, they are eliminated and their votes are redistributed
+                        // candidates reaching the quota are elected and their surplus is transferred
+                        // if no candidate reaches the quota, the candidate with least number of votes is eliminated and their votes are redistributed
+    Borda = 3, // gives ordering of candidates, each position is worth points (n-1 for the first, n-2 for the second, ...)
+               // candidate with most points wins
+    Schulze = 4, // gives ordering of candidates, candidates are compared head-to-head using the strongest paths
+                 // between them, candidate that is not beaten by any other candidate wins (Condorcet winner if it exists)
+    RankedPairs = 5, // gives ordering of candidates, head-to-head victories are locked in order of their margin
+                     // unless they create a cycle, the locked victories give the final ordering of the candidates
+    Score = 6, // each candidate is rated on the election's rating scale (0 to 10 by default)
+               // candidate with the highest total rating wins
+    Star = 7, // each candidate is rated on the election's rating scale (0 to 5 by default), the two candidates with
+              // the highest total rating go to an automatic runoff, finalist rated higher by more voters wins
+    Cumulative = 8, // each voter spreads the election's point budget across candidates
+                    // candidate with most points wins
+    Quadratic = 9, // each voter spends voice credits from the election's budget, k votes for or against a candidate cost k^2 credits
+                   // candidate with most net votes (votes for minus votes against) wins
+    MajorityJudgment = 10, // each candidate is graded on the election's named grades (Reject to Excellent by default)
+                           // candidate with the best median grade wins, ties are broken by removing median grades
+    TwoRound = 11, // single vote, candidate with an absolute majority of votes wins
+                   // otherwise, a second round (Plurality election) is created between the two candidates with most votes
+    PartyList = 12, // single vote for a party, seats are allocated to parties proportionally to their votes (D'Hondt or Sainte-Laguë)
+                    // and filled by the candidates of each party in the order of the party list
+    Referendum = 13, // single vote for Yes, No or Abstain, no candidates
+                     // the proposal passes if the turnout reaches the quorum and the Yes votes reach the pass threshold
 }
 impl ElectionType {
     pub fn from_string(s: &str) -> Option<Self> {
@@ -16,15 +39,153 @@ impl ElectionType {
             "Plurality" => Some(ElectionType::Plurality),
             "Approval" => Some(ElectionType::Approval),
             "SingleTransferableVote" => Some(ElectionType::SingleTransferableVote),
+            "Borda" => Some(ElectionType::Borda),
+            "Schulze" => Some(ElectionType::Schulze),
+            "RankedPairs" => Some(ElectionType::RankedPairs),
+            "Score" => Some(ElectionType::Score),
+            "Star" => Some(ElectionType::Star),
+            "Cumulative" => Some(ElectionType::Cumulative),
+            "Quadratic" => Some(ElectionType::Quadratic),
+            "MajorityJudgment" => Some(ElectionType::MajorityJudgment),
+            "TwoRound" => Some(ElectionType::TwoRound),
+            "PartyList" => Some(ElectionType::PartyList),
+            "Referendum" => Some(ElectionType::Referendum),
             _ => None,
         }
     }
+
+    /// Returns true if the votes of this election type rate candidates instead of listing them.
+    pub fn uses_ratings(&self) -> bool {
+        matches!(self, ElectionType::Score | ElectionType::Star | ElectionType::Cumulative | ElectionType::Quadratic | ElectionType::MajorityJudgment)
+    }
+
+    /// Returns true if the votes of this election type can be weighted by the voter's token stake.
+    pub fn supports_token_weights(&self) -> bool {
+        matches!(self, ElectionType::Plurality | ElectionType::Approval | ElectionType::Borda |
+            ElectionType::Score | ElectionType::Cumulative | ElectionType::TwoRound | ElectionType::PartyList)
+    }
+
+    /// Returns true if the ratings of this election type must be on the election's rating scale.
+    pub fn uses_rating_scale(&self) -> bool {
+        matches!(self, ElectionType::Score | ElectionType::Star)
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
+pub enum SeatAllocationMethod {
+    #[default]
+    DHondt = 0, // the next seat goes to the party with the highest votes / (seats + 1)
+    SainteLague = 1, // the next seat goes to the party with the highest votes / (2 * seats + 1)
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
+pub enum TieBreakPolicy {
+    #[default]
+    EarliestRegistration = 0, // the tied candidate registered first is preferred
+    Random = 1, // the preferred (or eliminated) candidate is drawn from the tied candidates using the block random seed of the finalisation
+    AdminCastingVote = 2, // the admin chooses before the casting vote deadline, otherwise the tied candidate registered first is preferred
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
+pub enum ReferendumOutcome {
+    #[default]
+    Passed = 0, // the turnout reached the quorum and the Yes votes reached the pass threshold
+    Rejected = 1, // the turnout reached the quorum but the Yes votes did not reach the pass threshold
+    QuorumNotMet = 2, // the turnout did not reach the quorum
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, PartialEq, Debug)]
+pub enum ReceiptStatus {
+    #[default]
+    Invalid = 0, // no ballot of the ledger matches the receipt
+    Counted = 1, // the ballot is in the ledger and counts toward the results
+    Superseded = 2, // the ballot is in the ledger but was replaced by a later ballot of the same voter, and is not counted
 }
 
 
 pub type ElectionID = u64;
 pub type CandidateID = u16;
 pub type DisputeID = u16;
+pub type PartyID = u16;
+pub type BallotID = u64;
+
+/// Averages are stored as fixed-point numbers with two decimals (a value of 750 means 7.50).
+pub const AVERAGE_PRECISION: u64 = 100;
+
+/// Vote values in Single Transferable Vote counts are fixed-point numbers with six decimals,
+/// so that surpluses can be transferred as fractions of a vote.
+pub const STV_VOTE_PRECISION: u64 = 1_000_000;
+
+/// The reserved candidate ID of the "None of the above" option.
+pub const NONE_OF_THE_ABOVE: CandidateID = CandidateID::MAX;
+
+/// The current ballot of a voter whose latest ballot in an election with revoting is a blank ballot,
+/// which has no ballot ID.
+pub const BLANK_BALLOT: BallotID = BallotID::MAX;
+
+/// Time (in seconds) the admin has after the finalisation of a tied election to cast their deciding vote.
+pub const CASTING_VOTE_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// The choices of a Referendum vote, cast with `vote` in place of a candidate.
+pub const REFERENDUM_YES: CandidateID = 1;
+pub const REFERENDUM_NO: CandidateID = 2;
+pub const REFERENDUM_ABSTAIN: CandidateID = 3;
+
+/// The prime modulus `p` of the ElGamal group used by encrypted elections (big-endian). Votes are encrypted in its
+/// subgroup of prime order `q` (`ELGAMAL_ORDER`), so every exponent is at most 256 bits.
+///
+/// The group is derived from the seed `s = "backendsc encrypted elections"`, with `H(label, i) = sha256(s || "/" || label || i)`
+/// (`i` as 4 bytes big-endian): `q` is the first prime from `H("q", 0)` with its top and bottom bits set, counting up,
+/// `p` the first prime `p == 1 (mod 2q)` from `X = H("p", 0) || ... || H("p", 7)` with its top bit set, counting up
+/// from `X - (X mod 2q) + 1`, and `g = 2^((p - 1) / q) (mod p)`.
+pub const ELGAMAL_PRIME: [u8; 256] = [
+    0xB6, 0x7C, 0xED, 0x99, 0x58, 0x8E, 0x71, 0xFB, 0x10, 0x76, 0x7B, 0x93, 0xAE, 0x2B, 0xC0, 0x7A,
+    0x26, 0x80, 0xC5, 0x8E, 0x20, 0xC6, 0x1C, 0xA3, 0x3D, 0xDE, 0x14, 0xC7, 0x4A, 0x66, 0xC6, 0x28,
+    0x9C, 0x95, 0x18, 0xB8, 0xCD, 0xD6, 0xB9, 0xF3, 0x2A, 0x5B, 0x5B, 0x73, 0x89, 0x21, 0x75, 0x67,
+    0xF7, 0x56, 0x32, 0x70, 0x80, 0x85, 0x5D, 0x92, 0xE4, 0xE8, 0x0C, 0xF0, 0x4F, 0xD6, 0xD1, 0x4B,
+    0x93, 0x4E, 0xEB, 0x12, 0x7F, 0x54, 0x2B, 0x0D, 0x1F, 0x87, 0x84, 0xFB, 0xA5, 0x00, 0xA6, 0xB2,
+    0xE5, 0x7B, 0x70, 0xDC, 0x34, 0xF4, 0xE0, 0x88, 0xAD, 0x01, 0x96, 0x8E, 0x46, 0x83, 0x03, 0x00,
+    0x8E, 0x99, 0xC4, 0x23, 0xB4, 0x18, 0xB7, 0xFF, 0x7A, 0xDA, 0xB0, 0x25, 0x5C, 0x42, 0xBF, 0xFF,
+    0x4A, 0x8D, 0x13, 0x7D, 0xF4, 0x0D, 0x37, 0x39, 0xDF, 0x7E, 0xD1, 0x5A, 0xC5, 0x24, 0xB0, 0x40,
+    0xB5, 0x02, 0x37, 0xDF, 0xC3, 0x49, 0x77, 0xA5, 0xF7, 0xF1, 0x96, 0x4A, 0x03, 0x62, 0x1E, 0x5A,
+    0x4F, 0x4D, 0x83, 0x93, 0xE6, 0xEE, 0x30, 0x3E, 0x0A, 0xA6, 0xF1, 0xA0, 0xB4, 0xA8, 0x9A, 0xBE,
+    0xEE, 0x1F, 0x9A, 0x5B, 0x3E, 0x27, 0x8E, 0x6C, 0xFF, 0xB5, 0x81, 0xB4, 0x0C, 0xCC, 0x48, 0x42,
+    0x02, 0x93, 0xAE, 0x96, 0x1D, 0xC8, 0x3D, 0x0F, 0x20, 0xC3, 0x2D, 0x5B, 0x1B, 0xD6, 0xD0, 0x80,
+    0x28, 0x4F, 0x03, 0xAB, 0x0E, 0xA6, 0x15, 0xF3, 0x79, 0x6F, 0x6F, 0x70, 0xE0, 0x85, 0x5B, 0xB1,
+    0x14, 0xB1, 0xC2, 0x90, 0x85, 0x04, 0x4B, 0x24, 0xDF, 0xEA, 0x13, 0x7A, 0x97, 0x31, 0x8D, 0xE3,
+    0xC6, 0x05, 0xEE, 0x39, 0x7D, 0xF3, 0x29, 0xAA, 0xCA, 0x8F, 0x27, 0x86, 0x70, 0x69, 0x13, 0x1A,
+    0x99, 0xE1, 0xC3, 0xE6, 0x08, 0x9C, 0x82, 0x5F, 0x57, 0x86, 0x1D, 0xAB, 0x4E, 0x08, 0xBB, 0xEF,
+];
+
+/// The prime order `q` of the subgroup of the ElGamal group votes are encrypted in (big-endian).
+pub const ELGAMAL_ORDER: [u8; 32] = [
+    0x86, 0xDE, 0x58, 0x88, 0xE9, 0xDE, 0x45, 0xE6, 0x20, 0x10, 0x37, 0x94, 0xCF, 0x91, 0x0E, 0x23,
+    0x1C, 0x85, 0x4D, 0x09, 0x62, 0x97, 0x93, 0x5A, 0x45, 0x90, 0xBC, 0x09, 0x01, 0xE0, 0x05, 0x7B,
+];
+
+/// The generator `g` of the subgroup of order `q` of the ElGamal group (big-endian).
+pub const ELGAMAL_GENERATOR: [u8; 256] = [
+    0x2C, 0xBA, 0x88, 0x87, 0xF6, 0x70, 0xFC, 0xD6, 0x14, 0x84, 0x1C, 0xD6, 0x68, 0x3F, 0x84, 0xCB,
+    0x6C, 0x83, 0x26, 0xE8, 0x29, 0x43, 0x5A, 0xF1, 0xBF, 0x70, 0xB7, 0x53, 0x12, 0x20, 0xED, 0x1D,
+    0x66, 0xB8, 0xA1, 0xF9, 0xAA, 0x63, 0x3C, 0xD1, 0xBE, 0xB0, 0x62, 0xAB, 0x23, 0x50, 0x4F, 0x92,
+    0xAB, 0xC1, 0xAB, 0x05, 0xF6, 0x8C, 0x60, 0x9F, 0x6D, 0xDA, 0x3F, 0x0B, 0xA6, 0x20, 0xCE, 0x03,
+    0x1A, 0x6E, 0xF3, 0x60, 0x37, 0x84, 0x4E, 0x71, 0x11, 0xB7, 0xB5, 0x30, 0x7E, 0x93, 0x45, 0xB6,
+    0x66, 0xA7, 0x4A, 0xDC, 0xA8, 0xCD, 0x4D, 0x49, 0x05, 0x14, 0x08, 0x67, 0xD5, 0x50, 0x5D, 0x2B,
+    0x5B, 0x08, 0x9A, 0x2F, 0x6B, 0xF0, 0xFB, 0x87, 0x15, 0xF2, 0xE4, 0x6D, 0x36, 0x94, 0x06, 0x41,
+    0x43, 0x53, 0xE7, 0x5C, 0x7C, 0xA6, 0x17, 0x5C, 0x54, 0x33, 0x2E, 0x08, 0xBE, 0x9A, 0x1F, 0x67,
+    0xF0, 0x3D, 0x48, 0x26, 0xDA, 0x7D, 0xD8, 0x35, 0x8B, 0xFE, 0xC8, 0xE3, 0xD6, 0x26, 0x78, 0xD9,
+    0xB8, 0x2F, 0x27, 0x09, 0x30, 0xA9, 0x1C, 0x24, 0xDB, 0xA9, 0x22, 0x12, 0x96, 0x14, 0xCA, 0x3E,
+    0xA5, 0xC5, 0x03, 0xE2, 0xC7, 0x38, 0x12, 0x62, 0x82, 0x33, 0x7F, 0x62, 0x32, 0x63, 0xF2, 0xAA,
+    0x68, 0xAF, 0x74, 0xD2, 0xC5, 0x96, 0xD1, 0x09, 0xEB, 0x88, 0xDF, 0x1E, 0xDE, 0xB1, 0x6E, 0x2A,
+    0xD8, 0xD5, 0xAA, 0x5D, 0xBE, 0xE7, 0xAB, 0xD2, 0xD4, 0x90, 0x31, 0x09, 0x08, 0xC0, 0xF8, 0xE8,
+    0x3D, 0x99, 0x46, 0x9C, 0xCB, 0x16, 0xA1, 0xE7, 0xAF, 0xB5, 0xE5, 0xF5, 0xAD, 0x6F, 0x2B, 0xA8,
+    0x63, 0x0E, 0xF3, 0xC6, 0x11, 0x05, 0x39, 0x18, 0xB9, 0xE6, 0x69, 0xEA, 0xFB, 0xE1, 0x6D, 0x42,
+    0xCB, 0x39, 0x63, 0x31, 0x5F, 0x54, 0xE1, 0x5B, 0x61, 0x05, 0x45, 0xF7, 0xB9, 0x1A, 0x4B, 0x5A,
+];
 
 
 
@@ -40,15 +201,140 @@ pub struct ElectionData<M: ManagedTypeApi> {
     pub election_type: ElectionType,
     pub ended: bool,
     pub admin: ManagedAddress<M>,
+    pub seats: u32,
+    // the previous election of a runoff or a rerun, and the runoff or rerun of an election, if any
+    pub previous_round: Option<ElectionID>,
+    pub next_round: Option<ElectionID>,
+    // how a tie for the most votes is broken
+    pub tie_break_policy: TieBreakPolicy,
+    // the number of voters who cast a ballot, including blank ballots
+    pub turnout: u64,
+    // whether "None of the above" won the election
+    pub void: bool,
 }
 
 #[type_abi]
 #[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
 pub struct Vote<M: ManagedTypeApi> {
     pub candidates: ManagedVec<M, CandidateID>,
+    // the rating given to each candidate of the vote, empty unless the election type uses ratings
+    // (negative for the votes against a candidate in a Quadratic election)
+    pub ratings: ManagedVec<M, i64>,
+    // the voice credits spent by the vote, 0 unless the election type is Quadratic
+    pub credits: u64,
+    // the weight of the vote: the token stake of the voter for token-weighted elections, 1 otherwise
+    pub weight: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct Ballot<M: ManagedTypeApi> {
+    // the position of the ballot in the ledger of the election, starting at 1
+    pub id: BallotID,
+    pub vote: Vote<M>,
+    // the timestamp and nonce of the block the ballot was cast in
+    pub timestamp: u64,
+    pub block_nonce: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, Clone, Debug)]
+pub struct Receipt<M: ManagedTypeApi> {
+    pub ballot_id: BallotID,
+    // random bytes only returned to the voter, needed to verify the receipt
+    pub salt: ManagedByteArray<M, 32>,
+    // sha256(election ID || ballot || salt), see `receipt_hash`
+    pub hash: ManagedByteArray<M, 32>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, Debug)]
+pub struct Commitment<M: ManagedTypeApi> {
+    // sha256(ballot || salt), see `ballot_hash`
+    pub hash: ManagedByteArray<M, 32>,
+    // the weight of the vote, locked when the vote is committed
+    pub weight: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, Debug)]
+pub struct VoterRoll<M: ManagedTypeApi> {
+    // root of the Merkle tree of the eligible voters, see `register_from_voter_roll`
+    pub root: ManagedByteArray<M, 32>,
+    // number of voters in the roll, counted in the electorate of a referendum whether they register or not
+    pub voters: u64,
+    // whether each vote is weighted by the weight of the voter in the roll
+    pub weighted: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct Ciphertext<M: ManagedTypeApi> {
+    // ElGamal encryption of `m` with randomness `r`: a = g^r, b = g^m * h^r (mod p), where h is the joint public key
+    pub a: BigUint<M>,
+    pub b: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct BallotProof<M: ManagedTypeApi> {
+    // disjunctive Chaum-Pedersen proof that a ciphertext encrypts 0 or 1, see `verify_ballot_proof`
+    pub t_a0: BigUint<M>,
+    pub t_b0: BigUint<M>,
+    pub t_a1: BigUint<M>,
+    pub t_b1: BigUint<M>,
+    pub c0: BigUint<M>,
+    pub c1: BigUint<M>,
+    pub s0: BigUint<M>,
+    pub s1: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct EncryptedChoice<M: ManagedTypeApi> {
+    pub candidate: CandidateID,
+    // encryption of 1 if the candidate is selected, 0 otherwise
+    pub ciphertext: Ciphertext<M>,
+    pub proof: BallotProof<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct DecryptionShare<M: ManagedTypeApi> {
+    pub candidate: CandidateID,
+    // d = A^x (mod p), where A is the first component of the candidate's encrypted tally and x the trustee's secret key
+    pub share: BigUint<M>,
+    // Chaum-Pedersen proof that the share uses the same secret key as the trustee's public key
+    pub t_g: BigUint<M>,
+    pub t_a: BigUint<M>,
+    pub response: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct RatingScale {
+    pub min: u64,
+    pub max: u64
 }
 
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct BallotRules {
+    // the minimum and maximum number of candidates a vote can select, 0 meaning no maximum
+    pub min_selections: u32,
+    pub max_selections: u32,
+    // whether a vote must rank every candidate (Single Transferable Vote only)
+    pub full_ranking: bool
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct RunoffSchedule {
+    pub start_time: u64,
+    pub end_time: u64
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
 pub struct Candidate<M: ManagedTypeApi> {
@@ -60,6 +346,78 @@ pub struct Candidate<M: ManagedTypeApi> {
 
 
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
+pub struct Party<M: ManagedTypeApi> {
+    pub id: PartyID,
+    pub name: ManagedBuffer<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct PartyListRules {
+    pub method: SeatAllocationMethod,
+    // minimum percentage of the votes a party needs to receive seats
+    pub threshold_percent: u64
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
+pub struct PartyResult<M: ManagedTypeApi> {
+    pub party: PartyID,
+    pub votes: BigUint<M>,
+    pub seats: u32
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct TieBreak<M: ManagedTypeApi> {
+    // the tied candidates (the tied parties for a Party-List seat), in the order they were registered
+    pub candidates: ManagedVec<M, CandidateID>,
+    // true if the tie decides which candidate is eliminated (Single Transferable Vote), false if it decides which one is preferred
+    pub elimination: bool,
+    pub policy: TieBreakPolicy,
+    // the block random seed the chosen candidate was drawn from, empty unless the policy is Random
+    pub seed: ManagedBuffer<M>,
+    // the deadline of the admin's casting vote, 0 unless the policy is AdminCastingVote
+    pub deadline: u64,
+    // the candidate chosen by the tie-break, 0 while the admin's casting vote is pending
+    pub chosen: CandidateID
+}
+
+impl<M: ManagedTypeApi> TieBreak<M> {
+    /// Returns the candidate chosen by the EarliestRegistration policy: the tied candidate registered first
+    /// is preferred, so it wins a tie for a place and the one registered last is eliminated.
+    pub fn earliest_registration_choice(&self) -> CandidateID {
+        if self.elimination {
+            self.candidates.get(self.candidates.len() - 1)
+        } else {
+            self.candidates.get(0)
+        }
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct ReferendumRules {
+    // minimum percentage of the registered voters that must vote (including abstentions)
+    pub quorum_percent: u64,
+    // fraction of the Yes and No votes the Yes votes must reach, e.g. 1/2, 3/5 or 2/3
+    pub threshold_numerator: u64,
+    pub threshold_denominator: u64
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct ReferendumResult {
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub abstain_votes: u64,
+    // the electorate of the quorum: the registered voters, plus the voters of the roll who did not register
+    pub registered_voters: u64,
+    pub outcome: ReferendumOutcome
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
 pub struct Dispute<M: ManagedTypeApi> {
@@ -76,13 +434,82 @@ pub struct Dispute<M: ManagedTypeApi> {
 pub struct Voter<M: ManagedTypeApi> {
     pub address: ManagedAddress<M>,
     pub eligible: bool,
+    // the voter credential token and the nonce of the credential of the voter (empty and 0 if the election
+    // does not use voter credentials)
     pub token: TokenIdentifier<M>,
+    pub credential_nonce: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, NestedEncode, Debug)]
+pub struct VoterCredential<M: ManagedTypeApi> {
+    // the election and the voter the credential was minted for (the attributes of the credential)
+    pub election_id: ElectionID,
+    pub voter: ManagedAddress<M>,
 }
 
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Clone, Debug)]
+pub struct VotingResult<M: ManagedTypeApi> {
+    pub candidate: CandidateID,
+    pub count: BigUint<M>
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
-pub struct VotingResult {
+pub struct PairwisePreference {
     pub candidate: CandidateID,
+    pub opponent: CandidateID,
     pub count: u64
-}
\ No newline at end of file
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, ManagedVecItem, Debug)]
+pub struct LockedPair {
+    pub winner: CandidateID,
+    pub loser: CandidateID,
+    pub margin: u64
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct GradeResult<M: ManagedTypeApi> {
+    pub candidate: CandidateID,
+    // index of the majority grade (lower median) of the candidate, 0 is the worst grade
+    pub median_grade: u64,
+    // number of votes giving each grade to the candidate, from the worst grade to the best
+    pub histogram: ManagedVec<M, u64>
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct StvRound<M: ManagedTypeApi> {
+    // the votes of each continuing candidate at the start of the round (fixed-point, see `STV_VOTE_PRECISION`)
+    pub counts: ManagedVec<M, VotingResult<M>>,
+    // number of votes needed to be elected in this round (fixed-point)
+    pub quota: u64,
+    // number of votes with no continuing candidate left, and their total value (fixed-point)
+    pub exhausted_votes: u64,
+    pub exhausted_value: u64,
+    // the candidates elected in this round
+    pub elected: ManagedVec<M, CandidateID>,
+    // the candidate eliminated in this round, 0 if none
+    pub eliminated: CandidateID
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Default, NestedDecode, NestedEncode, Debug)]
+pub struct StarResult<M: ManagedTypeApi> {
+    // score stage: the total rating of each candidate
+    pub scores: ManagedVec<M, VotingResult<M>>,
+    // runoff stage: the two candidates with the highest total rating
+    pub first_finalist: CandidateID,
+    pub second_finalist: CandidateID,
+    // number of votes that rated one finalist higher than the other
+    pub first_finalist_votes: u64,
+    pub second_finalist_votes: u64,
+    // number of votes that rated both finalists the same
+    pub no_preference: u64,
+    pub winner: CandidateID
+}
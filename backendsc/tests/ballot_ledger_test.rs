@@ -0,0 +1,106 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PLURALITY: u64 = 0;
+
+fn ballot_count(world: &mut ScenarioWorld, id: u64) -> u64 {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_ballot_count(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+fn ballots(world: &mut ScenarioWorld, id: u64, from: u64, count: u64) -> Vec<proxy::Ballot<StaticApi>> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_ballots(id, from, count)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect()
+}
+
+fn ballot(world: &mut ScenarioWorld, id: u64, ballot_id: u64) -> proxy::Ballot<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_ballot(id, ballot_id)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn ballot_ids(ballots: &[proxy::Ballot<StaticApi>]) -> Vec<u64> {
+    ballots.iter().map(|ballot| ballot.id).collect()
+}
+
+/// A plurality election with `n` registered voters, each voting for the first candidate in their own block.
+fn identical_ballots(world: &mut ScenarioWorld, n: usize) -> (u64, Vec<u16>) {
+    let (id, c) = election(world, PLURALITY, 2);
+    register_voters(world, id, n);
+    for v in 0..n {
+        world.current_block().block_timestamp(START + v as u64).block_nonce(10 + v as u64);
+        vote(world, id, v, vec![c[0]]);
+    }
+    (id, c)
+}
+
+#[test]
+fn identical_ballots_are_recorded_in_order() {
+    let mut world = setup();
+    let (id, c) = identical_ballots(&mut world, 3);
+
+    assert_eq!(ballot_count(&mut world, id), 3);
+    let ledger = ballots(&mut world, id, 1, 10);
+    assert_eq!(ballot_ids(&ledger), vec![1, 2, 3]);
+    for (i, ballot) in ledger.iter().enumerate() {
+        assert_eq!((ballot.timestamp, ballot.block_nonce), (START + i as u64, 10 + i as u64));
+        assert_eq!(ballot.vote.candidates.clone().into_vec(), vec![c[0]]);
+    }
+
+    // no ballot is lost to deduplication
+    assert_eq!(results(&mut world, id)[0], c[0]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 3);
+}
+
+#[test]
+fn ledger_is_paginated() {
+    let mut world = setup();
+    let (id, _) = identical_ballots(&mut world, 5);
+
+    assert_eq!(ballot_ids(&ballots(&mut world, id, 2, 2)), vec![2, 3]);
+    assert_eq!(ballot_ids(&ballots(&mut world, id, 4, 10)), vec![4, 5]);
+    assert_eq!(ballot_ids(&ballots(&mut world, id, 6, 1)), Vec::<u64>::new());
+    assert_eq!(ballot_ids(&ballots(&mut world, id, 1, 0)), Vec::<u64>::new());
+    assert_eq!(ballot(&mut world, id, 5).id, 5);
+}
+
+#[test]
+fn missing_ballots_are_rejected() {
+    let mut world = setup();
+    let (id, _) = identical_ballots(&mut world, 2);
+
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_ballots(id, 0u64, 2u64)
+        .returns(ExpectError(4, "Ballot IDs start at 1"))
+        .run();
+    for ballot_id in [0u64, 3] {
+        world
+            .query()
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .get_ballot(id, ballot_id)
+            .returns(ExpectError(4, "Ballot does not exist"))
+            .run();
+    }
+}
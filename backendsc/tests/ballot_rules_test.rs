@@ -0,0 +1,92 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const APPROVAL: u64 = 1;
+const STV: u64 = 2;
+
+fn set_ballot_rules(world: &mut ScenarioWorld, id: u64, min: u32, max: u32, full_ranking: bool) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_ballot_rules(id, min, max, full_ranking)
+        .run();
+}
+
+fn set_ballot_rules_err(world: &mut ScenarioWorld, id: u64, min: u32, max: u32, full_ranking: bool, message: &str) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_ballot_rules(id, min, max, full_ranking)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+#[test]
+fn limited_approval_ballots_respect_the_selection_bounds() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, APPROVAL, 4);
+    set_ballot_rules(&mut world, id, 1, 2, false);
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    vote_err(&mut world, id, 0, vec![], "Not enough candidates selected");
+    vote_err(&mut world, id, 0, vec![c[0], c[1], c[2]], "Too many candidates selected");
+    vote_err(&mut world, id, 0, vec![c[0], c[0]], "Candidate selected more than once");
+    vote(&mut world, id, 0, vec![c[0], c[1]]);
+    vote(&mut world, id, 1, vec![c[1]]);
+    vote(&mut world, id, 2, vec![c[1], c[3]]);
+
+    let rules = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .ballot_rules(id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!((rules.min_selections, rules.max_selections, rules.full_ranking), (1, 2, false));
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[1]), 3);
+}
+
+#[test]
+fn stv_can_require_a_full_ranking() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, STV, 3);
+    set_ballot_rules(&mut world, id, 0, 0, true);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_err(&mut world, id, 0, vec![c[0], c[1]], "All candidates must be ranked");
+    vote(&mut world, id, 0, vec![c[2], c[1], c[0]]);
+    assert_eq!(results(&mut world, id), vec![c[2]]);
+}
+
+#[test]
+fn ballot_rules_are_validated() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, APPROVAL, 4);
+    set_ballot_rules_err(&mut world, id, 1, 2, true, "Full ranking is only supported for Single Transferable Vote");
+    set_ballot_rules_err(&mut world, id, 3, 2, false, "Minimum selections cannot exceed maximum selections");
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_ballot_rules(id, 1u32, 2u32, false)
+        .returns(ExpectError(4, "Only admin can set the ballot rules"))
+        .run();
+
+    let (plurality, _) = election(&mut world, 0, 2);
+    set_ballot_rules_err(&mut world, plurality, 1, 2, false, "Election type only allows one candidate");
+    let (score, _) = election(&mut world, 6, 2);
+    set_ballot_rules_err(&mut world, score, 1, 2, false, "Election type does not use ballot rules");
+
+    world.current_block().block_timestamp(START);
+    set_ballot_rules_err(&mut world, id, 1, 2, false, "Election has started");
+}
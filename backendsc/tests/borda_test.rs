@@ -0,0 +1,42 @@
+mod common;
+
+use common::*;
+
+const BORDA: u64 = 3;
+
+#[test]
+fn borda_points_decide_the_winner() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, BORDA, 3);
+    register_voters(&mut world, id, 5);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![c[0], c[1], c[2]]);
+    vote(&mut world, id, 1, vec![c[0], c[2], c[1]]);
+    vote(&mut world, id, 2, vec![c[1], c[2], c[0]]);
+    // partial rankings only award points to the ranked candidates
+    vote(&mut world, id, 3, vec![c[1], c[2]]);
+    vote(&mut world, id, 4, vec![c[1]]);
+
+    assert_eq!(results(&mut world, id)[0], c[1]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 4);
+    assert_eq!(result_vector(&mut world, id, c[1]), 7);
+    assert_eq!(result_vector(&mut world, id, c[2]), 3);
+}
+
+#[test]
+fn borda_rejects_invalid_ballots() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, BORDA, 3);
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+
+    vote_err(&mut world, id, 0, vec![c[0], c[0]], "Candidate selected more than once");
+    vote_err(&mut world, id, 0, vec![c[0], 999], "Invalid candidate");
+    vote_rated_err(&mut world, id, 0, vec![(c[0], 1)], "Election does not use rated votes");
+    vote_err(&mut world, id, 5, vec![c[0]], "You are not registered as a voter");
+
+    vote(&mut world, id, 0, vec![c[0], c[1]]);
+    vote_err(&mut world, id, 0, vec![c[1]], "You are not eligible to vote");
+    results_err(&mut world, id, "Election has not ended yet");
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::sha256;
+
+const APPROVAL: u64 = 1;
+const SCORE: u64 = 6;
+const REVEAL_PERIOD: u64 = 500;
+
+fn salt(seed: u8) -> [u8; 32] {
+    [seed; 32]
+}
+
+/// Computes the commitment of a ballot: sha256(election_id || voter || ballot || salt).
+fn ballot_hash(id: u64, v: usize, candidates: &[u16], ratings: &[i64], salt: [u8; 32]) -> ManagedByteArray<StaticApi, 32> {
+    let mut ballot = id.to_be_bytes().to_vec();
+    ballot.extend_from_slice(voter(v).to_address().as_bytes());
+    for (i, candidate) in candidates.iter().enumerate() {
+        ballot.extend_from_slice(&candidate.to_be_bytes());
+        if let Some(rating) = ratings.get(i) {
+            ballot.extend_from_slice(&rating.to_be_bytes());
+        }
+    }
+    ballot.extend_from_slice(&salt);
+    ManagedByteArray::new_from_bytes(&sha256(&ballot))
+}
+
+fn commit_reveal_election(world: &mut ScenarioWorld, election_type: u64, n_candidates: usize, n_voters: usize) -> (u64, Vec<u16>) {
+    let (id, c) = election(world, election_type, n_candidates);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_reveal_period(id, REVEAL_PERIOD).run();
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_blank_ballots_allowed(id, true).run();
+    register_voters(world, id, n_voters);
+    world.current_block().block_timestamp(START);
+    (id, c)
+}
+
+fn commit(world: &mut ScenarioWorld, id: u64, v: usize, hash: ManagedByteArray<StaticApi, 32>) {
+    world.tx().from(voter(v)).to(SC).typed(proxy::BackendScProxy).commit_vote(id, hash).run();
+}
+
+fn reveal(world: &mut ScenarioWorld, id: u64, v: usize, salt: [u8; 32], candidates: Vec<u16>) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .reveal_vote(id, ManagedByteArray::<StaticApi, 32>::new_from_bytes(&salt), MultiValueVec::from(candidates))
+        .run();
+}
+
+fn reveal_err(world: &mut ScenarioWorld, id: u64, v: usize, salt: [u8; 32], candidates: Vec<u16>, message: &str) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .reveal_vote(id, ManagedByteArray::<StaticApi, 32>::new_from_bytes(&salt), MultiValueVec::from(candidates))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn results_after_reveal(world: &mut ScenarioWorld, id: u64) -> Vec<u16> {
+    world.current_block().block_timestamp(END + REVEAL_PERIOD + 1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .results(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn revealed_votes_are_counted() {
+    let mut world = setup();
+    let (id, c) = commit_reveal_election(&mut world, APPROVAL, 2, 4);
+
+    vote_err(&mut world, id, 0, vec![c[0]], "Election requires committed votes");
+    commit(&mut world, id, 0, ballot_hash(id, 0, &[c[0], c[1]], &[], salt(0)));
+    commit(&mut world, id, 1, ballot_hash(id, 1, &[c[1]], &[], salt(1)));
+    commit(&mut world, id, 2, ballot_hash(id, 2, &[], &[], salt(2)));
+    commit(&mut world, id, 3, ballot_hash(id, 3, &[c[0]], &[], salt(3)));
+    reveal_err(&mut world, id, 0, salt(0), vec![c[0], c[1]], "Election has not ended yet");
+
+    world.current_block().block_timestamp(END + 1);
+    reveal_err(&mut world, id, 0, salt(9), vec![c[0], c[1]], "Vote does not match the commitment");
+    reveal_err(&mut world, id, 0, salt(0), vec![c[0]], "Vote does not match the commitment");
+    reveal(&mut world, id, 0, salt(0), vec![c[0], c[1]]);
+    reveal_err(&mut world, id, 0, salt(0), vec![c[0], c[1]], "No committed vote to reveal");
+    reveal(&mut world, id, 1, salt(1), vec![c[1]]);
+    reveal(&mut world, id, 2, salt(2), vec![]);
+    results_err(&mut world, id, "Reveal period has not ended yet");
+
+    world.current_block().block_timestamp(END + REVEAL_PERIOD + 1);
+    reveal_err(&mut world, id, 3, salt(3), vec![c[0]], "Reveal period has ended");
+    assert_eq!(results_after_reveal(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 1);
+    assert_eq!(result_vector(&mut world, id, c[1]), 2);
+    let unrevealed = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .unrevealed_voters(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(unrevealed.len(), 1);
+}
+
+#[test]
+fn rated_votes_are_revealed_with_their_ratings() {
+    let mut world = setup();
+    let (id, c) = commit_reveal_election(&mut world, SCORE, 2, 1);
+    commit(&mut world, id, 0, ballot_hash(id, 0, &[c[0], c[1]], &[3, 7], salt(0)));
+
+    world.current_block().block_timestamp(END + 1);
+    let ratings = |first: i64| MultiValueVec::from(vec![MultiValue2::from((c[0], first)), MultiValue2::from((c[1], 7i64))]);
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .reveal_vote_rated(id, ManagedByteArray::<StaticApi, 32>::new_from_bytes(&salt(0)), ratings(4))
+        .returns(ExpectError(4, "Vote does not match the commitment"))
+        .run();
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .reveal_vote_rated(id, ManagedByteArray::<StaticApi, 32>::new_from_bytes(&salt(0)), ratings(3))
+        .run();
+
+    assert_eq!(results_after_reveal(&mut world, id), vec![c[1]]);
+}
+
+#[test]
+fn commitment_cannot_be_copied_by_another_voter() {
+    let mut world = setup();
+    let (id, c) = commit_reveal_election(&mut world, APPROVAL, 2, 2);
+    let hash = ballot_hash(id, 0, &[c[0]], &[], salt(0));
+    commit(&mut world, id, 0, hash.clone());
+    // voter 1 copies the commitment of voter 0, then the ballot and salt once they are revealed
+    commit(&mut world, id, 1, hash);
+
+    world.current_block().block_timestamp(END + 1);
+    reveal(&mut world, id, 0, salt(0), vec![c[0]]);
+    reveal_err(&mut world, id, 1, salt(0), vec![c[0]], "Vote does not match the commitment");
+}
+
+#[test]
+fn commitment_cannot_be_replayed_in_another_election() {
+    let mut world = setup();
+    let (first, _) = commit_reveal_election(&mut world, APPROVAL, 1, 1);
+    world.current_block().block_timestamp(10);
+    let (second, _) = commit_reveal_election(&mut world, APPROVAL, 1, 1);
+
+    // the commitment of a blank ballot in the first election is reused in the second one
+    let hash = ballot_hash(first, 0, &[], &[], salt(0));
+    commit(&mut world, first, 0, hash.clone());
+    commit(&mut world, second, 0, hash);
+
+    world.current_block().block_timestamp(END + 1);
+    reveal(&mut world, first, 0, salt(0), vec![]);
+    reveal_err(&mut world, second, 0, salt(0), vec![], "Vote does not match the commitment");
+}
@@ -0,0 +1,194 @@
+#![allow(dead_code)]
+
+#[path = "../../interactor/src/proxy.rs"]
+pub mod proxy;
+
+use multiversx_sc_scenario::imports::*;
+
+pub const OWNER: TestAddress = TestAddress::new("owner");
+pub const SC: TestSCAddress = TestSCAddress::new("backendsc");
+pub const TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("DAO-123456");
+pub const OTHER: TestTokenIdentifier = TestTokenIdentifier::new("OTH-123456");
+pub const CODE: MxscPath = MxscPath::new("output/backendsc.mxsc.json");
+
+pub const START: u64 = 100;
+pub const END: u64 = 1000;
+pub const VOTERS: usize = 20;
+
+const VOTER_NAMES: [&str; VOTERS] = [
+    "voter0", "voter1", "voter2", "voter3", "voter4", "voter5", "voter6", "voter7", "voter8",
+    "voter9", "voter10", "voter11", "voter12", "voter13", "voter14", "voter15", "voter16",
+    "voter17", "voter18", "voter19",
+];
+
+pub fn voter(i: usize) -> TestAddress<'static> {
+    TestAddress::new(VOTER_NAMES[i])
+}
+
+/// Deploys the contract with no candidate fee and funds the owner and the voters.
+pub fn setup() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.register_contract(CODE, backendsc::ContractBuilder);
+    world.account(OWNER).nonce(1).balance(1_000_000u64);
+    for i in 0..VOTERS {
+        world
+            .account(voter(i))
+            .nonce(1)
+            .balance(1_000u64)
+            .esdt_balance(TOKEN, 1_000u64)
+            .esdt_balance(OTHER, 1_000u64);
+    }
+    world.current_block().block_timestamp(10);
+    world
+        .tx()
+        .from(OWNER)
+        .typed(proxy::BackendScProxy)
+        .init(BigUint::from(0u64))
+        .code(CODE)
+        .new_address(SC)
+        .run();
+    world
+}
+
+/// Registers an election of the given type running from `START` to `END` with `n_candidates` candidates.
+pub fn election(world: &mut ScenarioWorld, election_type: u64, n_candidates: usize) -> (u64, Vec<u16>) {
+    election_with_tie_break(world, election_type, n_candidates, OptionalValue::None)
+}
+
+pub fn election_with_tie_break(world: &mut ScenarioWorld, election_type: u64, n_candidates: usize, tie_break_policy: OptionalValue<u64>) -> (u64, Vec<u16>) {
+    let id = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .register_election("name", "description", election_type, START, END, tie_break_policy)
+        .returns(ReturnsResult)
+        .run();
+    let candidates = (0..n_candidates).map(|_| candidate(world, id)).collect();
+    (id, candidates)
+}
+
+pub fn candidate(world: &mut ScenarioWorld, id: u64) -> u16 {
+    let candidate = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .submit_candidancy(id, "candidate", "description")
+        .returns(ReturnsResult)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .register_candidate(id, candidate)
+        .run();
+    candidate
+}
+
+/// Registers the first `n` voters.
+pub fn register_voters(world: &mut ScenarioWorld, id: u64, n: usize) {
+    for i in 0..n {
+        world
+            .tx()
+            .from(OWNER)
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .register_voter(id, voter(i).to_managed_address())
+            .run();
+    }
+}
+
+pub fn vote(world: &mut ScenarioWorld, id: u64, v: usize, candidates: Vec<u16>) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(candidates))
+        .run();
+}
+
+pub fn vote_err(world: &mut ScenarioWorld, id: u64, v: usize, candidates: Vec<u16>, message: &str) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(candidates))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn ratings(ratings: Vec<(u16, i64)>) -> MultiValueVec<MultiValue2<u16, i64>> {
+    MultiValueVec::from(ratings.into_iter().map(MultiValue2::from).collect::<Vec<_>>())
+}
+
+pub fn vote_rated(world: &mut ScenarioWorld, id: u64, v: usize, rated: Vec<(u16, i64)>) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote_rated(id, ratings(rated))
+        .run();
+}
+
+pub fn vote_rated_err(world: &mut ScenarioWorld, id: u64, v: usize, rated: Vec<(u16, i64)>, message: &str) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote_rated(id, ratings(rated))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+/// Moves past the end of the voting period and returns the full result ordering.
+pub fn results(world: &mut ScenarioWorld, id: u64) -> Vec<u16> {
+    world.current_block().block_timestamp(END);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .results(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+pub fn results_err(world: &mut ScenarioWorld, id: u64, message: &str) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .results(id)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+/// Tally of a candidate, only available once `results` has been computed.
+pub fn result_vector(world: &mut ScenarioWorld, id: u64, candidate: u16) -> u64 {
+    let tally: num_bigint::BigUint = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .result_vector(id, candidate)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    u64::try_from(tally).unwrap()
+}
+
+pub fn election_data(world: &mut ScenarioWorld, id: u64) -> proxy::ElectionData<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .election_data(id)
+        .returns(ReturnsResult)
+        .run()
+}
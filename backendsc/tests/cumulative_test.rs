@@ -0,0 +1,77 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const CUMULATIVE: u64 = 8;
+
+#[test]
+fn cumulative_points_can_be_spread_or_concentrated() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, CUMULATIVE, 3);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_point_budget(id, 6u64).run();
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    vote_rated(&mut world, id, 0, vec![(c[0], 6)]);
+    vote_rated(&mut world, id, 1, vec![(c[1], 3), (c[2], 3)]);
+    // a voter does not have to spend the whole budget
+    vote_rated(&mut world, id, 2, vec![(c[1], 4), (c[2], 1)]);
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 6);
+    assert_eq!(result_vector(&mut world, id, c[1]), 7);
+    assert_eq!(result_vector(&mut world, id, c[2]), 4);
+}
+
+#[test]
+fn cumulative_default_budget_is_ten_points() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, CUMULATIVE, 2);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_rated_err(&mut world, id, 0, vec![(c[0], 6), (c[1], 5)], "Points exceed the budget");
+    vote_rated(&mut world, id, 0, vec![(c[0], 6), (c[1], 4)]);
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+}
+
+#[test]
+fn cumulative_rejects_overspending_and_invalid_budgets() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, CUMULATIVE, 3);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_point_budget(id, 0u64)
+        .returns(ExpectError(4, "Point budget must be positive"))
+        .run();
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_point_budget(id, 6u64)
+        .returns(ExpectError(4, "Only admin can set the point budget"))
+        .run();
+    let (score, _) = election(&mut world, 6, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_point_budget(score, 6u64)
+        .returns(ExpectError(4, "Election type does not use a point budget"))
+        .run();
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_point_budget(id, 6u64).run();
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_rated_err(&mut world, id, 0, vec![(c[1], 4), (c[2], 3)], "Points exceed the budget");
+    // an allocation that would overflow when summed is still rejected
+    vote_rated_err(&mut world, id, 0, vec![(c[1], i64::MAX), (c[2], 3)], "Points exceed the budget");
+    vote_rated_err(&mut world, id, 0, vec![(c[1], 1), (c[1], 1)], "Candidate rated more than once");
+    vote_err(&mut world, id, 0, vec![c[0]], "Election requires rated votes");
+}
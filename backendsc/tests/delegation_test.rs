@@ -0,0 +1,217 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PLURALITY: u64 = 0;
+const REFERENDUM: u64 = 13;
+
+/// A plurality election with delegation and `n` registered voters, at the start of voting.
+fn delegation_election(world: &mut ScenarioWorld, n: usize) -> (u64, Vec<u16>) {
+    let (id, c) = election(world, PLURALITY, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_delegation_allowed(id, true)
+        .run();
+    register_voters(world, id, n);
+    world.current_block().block_timestamp(START);
+    (id, c)
+}
+
+fn delegate(world: &mut ScenarioWorld, id: u64, v: usize, delegate: usize) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .delegate_vote(id, voter(delegate).to_managed_address())
+        .run();
+}
+
+fn delegate_err(world: &mut ScenarioWorld, id: u64, v: usize, delegate: usize, message: &str) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .delegate_vote(id, voter(delegate).to_managed_address())
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn revoke_delegation(world: &mut ScenarioWorld, id: u64, v: usize) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .revoke_delegation(id)
+        .run();
+}
+
+fn delegation_chain(world: &mut ScenarioWorld, id: u64, v: usize) -> Vec<Address> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_delegation_chain(id, voter(v).to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|address| address.to_address())
+        .collect()
+}
+
+fn effective_weight(world: &mut ScenarioWorld, id: u64, v: usize) -> u64 {
+    let weight: num_bigint::BigUint = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_effective_weight(id, voter(v).to_managed_address())
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    u64::try_from(weight).unwrap()
+}
+
+fn addresses(voters: &[usize]) -> Vec<Address> {
+    voters.iter().map(|v| voter(*v).to_address()).collect()
+}
+
+#[test]
+fn delegations_chain_up_to_the_voter_who_votes() {
+    let mut world = setup();
+    let (id, c) = delegation_election(&mut world, 6);
+    delegate(&mut world, id, 1, 2);
+    delegate(&mut world, id, 2, 0);
+    // a delegation to a voter who does not vote is not counted
+    delegate(&mut world, id, 4, 5);
+    vote(&mut world, id, 0, vec![c[0]]);
+    vote(&mut world, id, 3, vec![c[1]]);
+
+    assert_eq!(delegation_chain(&mut world, id, 1), addresses(&[1, 2, 0]));
+    assert_eq!(delegation_chain(&mut world, id, 3), addresses(&[3]));
+    assert_eq!(effective_weight(&mut world, id, 0), 3);
+    assert_eq!(effective_weight(&mut world, id, 1), 0);
+    assert_eq!(effective_weight(&mut world, id, 5), 2);
+
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 3);
+    assert_eq!(result_vector(&mut world, id, c[1]), 1);
+}
+
+#[test]
+fn a_direct_vote_overrides_the_delegation() {
+    let mut world = setup();
+    let (id, c) = delegation_election(&mut world, 3);
+    delegate(&mut world, id, 1, 0);
+    delegate(&mut world, id, 2, 0);
+    vote(&mut world, id, 1, vec![c[1]]);
+    vote(&mut world, id, 0, vec![c[0]]);
+
+    assert_eq!(delegation_chain(&mut world, id, 1), addresses(&[1]));
+    assert_eq!(effective_weight(&mut world, id, 0), 2);
+    assert_eq!(effective_weight(&mut world, id, 1), 1);
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 2);
+    assert_eq!(result_vector(&mut world, id, c[1]), 1);
+}
+
+#[test]
+fn a_revoked_delegation_is_not_counted() {
+    let mut world = setup();
+    let (id, c) = delegation_election(&mut world, 2);
+    delegate(&mut world, id, 1, 0);
+    revoke_delegation(&mut world, id, 1);
+    world
+        .tx()
+        .from(voter(1))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .revoke_delegation(id)
+        .returns(ExpectError(4, "No delegation to revoke"))
+        .run();
+    vote(&mut world, id, 0, vec![c[0]]);
+
+    assert_eq!(effective_weight(&mut world, id, 0), 1);
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 1);
+}
+
+#[test]
+fn invalid_delegations_are_rejected() {
+    let mut world = setup();
+    let (other_id, _) = election(&mut world, PLURALITY, 2);
+    register_voters(&mut world, other_id, 2);
+    let (id, c) = delegation_election(&mut world, 3);
+    delegate_err(&mut world, other_id, 0, 1, "Election does not allow delegation");
+    delegate_err(&mut world, id, 0, 0, "Cannot delegate to yourself");
+    delegate_err(&mut world, id, 0, 3, "Delegate is not a registered voter");
+    delegate_err(&mut world, id, 3, 0, "You are not registered as a voter");
+    delegate(&mut world, id, 0, 1);
+    delegate(&mut world, id, 1, 2);
+    delegate_err(&mut world, id, 2, 0, "Delegation would create a cycle");
+    vote(&mut world, id, 2, vec![c[0]]);
+    delegate_err(&mut world, id, 2, 0, "You are not eligible to vote");
+
+    world.current_block().block_timestamp(END);
+    delegate_err(&mut world, id, 0, 2, "Voting has ended");
+}
+
+#[test]
+fn voters_who_voted_cannot_delegate_with_revoting() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_revoting_allowed(id, true)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_delegation_allowed(id, true)
+        .run();
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![c[0]]);
+    delegate_err(&mut world, id, 0, 1, "You have already voted");
+}
+
+#[test]
+fn delegation_is_rejected_with_incompatible_settings() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, PLURALITY, 2);
+    let set_delegation_allowed_err = |world: &mut ScenarioWorld, from: TestAddress, id: u64, message: &str| {
+        world
+            .tx()
+            .from(from)
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .set_delegation_allowed(id, true)
+            .returns(ExpectError(4, message))
+            .run();
+    };
+    set_delegation_allowed_err(&mut world, voter(0), id, "Only admin can allow delegation");
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_reveal_period(id, 100u64)
+        .run();
+    set_delegation_allowed_err(&mut world, OWNER, id, "Delegation is not available with committed votes");
+
+    let (referendum_id, _) = election(&mut world, REFERENDUM, 0);
+    set_delegation_allowed_err(&mut world, OWNER, referendum_id, "Election type does not support weighted votes");
+
+    world.current_block().block_timestamp(START);
+    set_delegation_allowed_err(&mut world, OWNER, referendum_id, "Election has started");
+}
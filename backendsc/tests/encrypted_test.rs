@@ -0,0 +1,432 @@
+mod common;
+
+#[allow(dead_code)]
+#[path = "../interactor/src/elgamal.rs"]
+mod elgamal;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PLURALITY: u64 = 0;
+const APPROVAL: u64 = 1;
+
+const TRUSTEES: [TestAddress; 3] = [TestAddress::new("trustee0"), TestAddress::new("trustee1"), TestAddress::new("trustee2")];
+
+fn managed(x: &num_bigint::BigUint) -> BigUint<StaticApi> {
+    BigUint::from_bytes_be(&x.to_bytes_be())
+}
+
+fn unmanaged(x: &BigUint<StaticApi>) -> num_bigint::BigUint {
+    num_bigint::BigUint::from_bytes_be(x.to_bytes_be().as_slice())
+}
+
+fn address(account: TestAddress) -> [u8; 32] {
+    *account.to_address().as_array()
+}
+
+fn managed_proof(proof: &elgamal::BallotProof) -> proxy::BallotProof<StaticApi> {
+    proxy::BallotProof {
+        t_a0: managed(&proof.t_a0),
+        t_b0: managed(&proof.t_b0),
+        t_a1: managed(&proof.t_a1),
+        t_b1: managed(&proof.t_b1),
+        c0: managed(&proof.c0),
+        c1: managed(&proof.c1),
+        s0: managed(&proof.s0),
+        s1: managed(&proof.s1),
+    }
+}
+
+fn set_trustees(world: &mut ScenarioWorld, id: u64, threshold: usize) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_trustees(id, threshold, MultiValueVec::from(TRUSTEES.map(|trustee| trustee.to_address())))
+        .run();
+}
+
+fn register_key(world: &mut ScenarioWorld, id: u64, trustee: TestAddress, key: &elgamal::TrusteeKey) {
+    world
+        .tx()
+        .from(trustee)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .register_trustee_key(id, managed(&key.public_key), managed(&key.commitment), managed(&key.response), MultiValueVec::from(key.coefficient_commitments.iter().map(managed).collect::<Vec<_>>()))
+        .run();
+}
+
+fn register_key_err(world: &mut ScenarioWorld, id: u64, trustee: TestAddress, key: &elgamal::TrusteeKey, message: &str) {
+    world
+        .tx()
+        .from(trustee)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .register_trustee_key(id, managed(&key.public_key), managed(&key.commitment), managed(&key.response), MultiValueVec::from(key.coefficient_commitments.iter().map(managed).collect::<Vec<_>>()))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+/// The share of the joint secret key of every trustee: the sum of the key shares the trustees send them off-chain.
+fn secret_key_shares(keys: &[elgamal::TrusteeKey]) -> Vec<num_bigint::BigUint> {
+    (1..=TRUSTEES.len() as u64)
+        .map(|index| elgamal::combine_key_shares(&keys.iter().map(|key| elgamal::key_share(&key.coefficients, index)).collect::<Vec<_>>()))
+        .collect()
+}
+
+/// Registers an encrypted election with `n_candidates` candidates and `n_voters` voters, whose tally is decrypted by
+/// `threshold` of the trustees, and the keys of the trustees. Returns the election, its candidates and the shares of
+/// the joint secret key of the trustees.
+fn encrypted_election(world: &mut ScenarioWorld, election_type: u64, n_candidates: usize, n_voters: usize, threshold: usize) -> (u64, Vec<u16>, Vec<num_bigint::BigUint>) {
+    for trustee in TRUSTEES {
+        world.account(trustee).nonce(1);
+    }
+    let (id, c) = election(world, election_type, n_candidates);
+    register_voters(world, id, n_voters);
+    set_trustees(world, id, threshold);
+
+    let keys = TRUSTEES.map(|trustee| elgamal::generate_key(id, &address(trustee), threshold));
+    for (trustee, key) in TRUSTEES.into_iter().zip(keys.iter()) {
+        register_key(world, id, trustee, key);
+    }
+    world.current_block().block_timestamp(START);
+    (id, c, secret_key_shares(&keys))
+}
+
+fn joint_public_key(world: &mut ScenarioWorld, id: u64) -> num_bigint::BigUint {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .joint_public_key(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+/// Encrypts a ballot of voter `v` selecting, in the order of `candidates`, the candidates marked `true`.
+fn encrypted_ballot(world: &mut ScenarioWorld, id: u64, v: usize, candidates: &[u16], selections: &[bool]) -> (ManagedVec<StaticApi, proxy::EncryptedChoice<StaticApi>>, OptionalValue<proxy::BallotProof<StaticApi>>) {
+    let joint_key = joint_public_key(world, id);
+    let ballot = elgamal::encrypt_ballot(id, &address(voter(v)), &joint_key, selections);
+    let choices = candidates
+        .iter()
+        .zip(ballot.choices.iter())
+        .map(|(candidate, (ciphertext, proof))| proxy::EncryptedChoice {
+            candidate: *candidate,
+            ciphertext: proxy::Ciphertext { a: managed(&ciphertext.a), b: managed(&ciphertext.b) },
+            proof: managed_proof(proof),
+        })
+        .collect::<Vec<_>>();
+    (ManagedVec::from(choices), OptionalValue::from(ballot.sum_proof.as_ref().map(managed_proof)))
+}
+
+fn vote_encrypted(world: &mut ScenarioWorld, id: u64, v: usize, candidates: &[u16], selections: &[bool]) {
+    let (choices, sum_proof) = encrypted_ballot(world, id, v, candidates, selections);
+    world.tx().from(voter(v)).to(SC).typed(proxy::BackendScProxy).vote_encrypted(id, choices, sum_proof).run();
+}
+
+fn vote_encrypted_err(world: &mut ScenarioWorld, id: u64, v: usize, candidates: &[u16], selections: &[bool], message: &str) {
+    let (choices, sum_proof) = encrypted_ballot(world, id, v, candidates, selections);
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote_encrypted(id, choices, sum_proof)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+/// The decryption shares of a trustee for the encrypted tally of every candidate, computed with `secret`.
+fn decryption_shares(world: &mut ScenarioWorld, id: u64, trustee: TestAddress, secret: &num_bigint::BigUint, candidates: &[u16]) -> MultiValueVec<proxy::DecryptionShare<StaticApi>> {
+    let mut shares = Vec::new();
+    for candidate in candidates {
+        let tally = world
+            .query()
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .get_encrypted_tally(id, *candidate)
+            .returns(ReturnsResult)
+            .run();
+        let share = elgamal::decryption_share(id, &address(trustee), secret, &unmanaged(&tally.a));
+        shares.push(proxy::DecryptionShare {
+            candidate: *candidate,
+            share: managed(&share.share),
+            t_g: managed(&share.t_g),
+            t_a: managed(&share.t_a),
+            response: managed(&share.response),
+        });
+    }
+    MultiValueVec::from(shares)
+}
+
+fn post_decryption_shares(world: &mut ScenarioWorld, id: u64, trustee: TestAddress, secret: &num_bigint::BigUint, candidates: &[u16]) {
+    let shares = decryption_shares(world, id, trustee, secret, candidates);
+    world.tx().from(trustee).to(SC).typed(proxy::BackendScProxy).post_decryption_shares(id, shares).run();
+}
+
+/// Decrypts the encrypted tally of every candidate from the decryption shares posted by the given trustees, as the publisher of the tally does.
+fn decrypt_tally(world: &mut ScenarioWorld, id: u64, candidates: &[u16], trustees: &[usize]) -> Vec<(u16, u64)> {
+    let turnout = election_data(world, id).turnout;
+    let mut counts = Vec::new();
+    for candidate in candidates {
+        let tally = world
+            .query()
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .get_encrypted_tally(id, *candidate)
+            .returns(ReturnsResult)
+            .run();
+        // a trustee's position in `getTrustees` starts from 1
+        let shares: Vec<(u64, num_bigint::BigUint)> = trustees
+            .iter()
+            .map(|t| {
+                let share = world
+                    .query()
+                    .to(SC)
+                    .typed(proxy::BackendScProxy)
+                    .decryption_share(id, TRUSTEES[*t].to_address(), *candidate)
+                    .returns(ReturnsResultUnmanaged)
+                    .run();
+                (*t as u64 + 1, share)
+            })
+            .collect();
+        counts.push((*candidate, elgamal::decrypt_count(&unmanaged(&tally.b), &shares, turnout).unwrap()));
+    }
+    counts
+}
+
+fn tally(counts: &[(u16, u64)]) -> MultiValueVec<MultiValue2<u16, u64>> {
+    MultiValueVec::from(counts.iter().map(|count| MultiValue2::from(*count)).collect::<Vec<_>>())
+}
+
+#[test]
+fn encrypted_plurality_tally_is_published_and_counted() {
+    let mut world = setup();
+    let (id, c, secrets) = encrypted_election(&mut world, PLURALITY, 3, 4, 2);
+
+    vote_err(&mut world, id, 0, vec![c[0]], "Election requires encrypted votes");
+    vote_encrypted(&mut world, id, 0, &c, &[true, false, false]);
+    vote_encrypted(&mut world, id, 1, &c, &[false, true, false]);
+    vote_encrypted(&mut world, id, 2, &c, &[false, true, false]);
+    vote_encrypted(&mut world, id, 3, &c, &[false, false, false]);
+
+    world.current_block().block_timestamp(END + 1);
+    results_err(&mut world, id, "Tally has not been published yet");
+    post_decryption_shares(&mut world, id, TRUSTEES[0], &secrets[0], &c);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .publish_tally(id, tally(&[(c[0], 1), (c[1], 2), (c[2], 0)]))
+        .returns(ExpectError(4, "Decryption shares are missing"))
+        .run();
+    // the second trustee never posts their shares: two of the three are enough
+    post_decryption_shares(&mut world, id, TRUSTEES[2], &secrets[2], &c);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .publish_tally(id, tally(&[(c[0], 2), (c[1], 1), (c[2], 0)]))
+        .returns(ExpectError(4, "Tally does not match the decryption shares"))
+        .run();
+    let counts = decrypt_tally(&mut world, id, &c, &[0, 2]);
+    assert_eq!(counts, vec![(c[0], 1), (c[1], 2), (c[2], 0)]);
+    // anyone can publish the tally
+    world.tx().from(voter(0)).to(SC).typed(proxy::BackendScProxy).publish_tally(id, tally(&counts)).run();
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[1]), 2);
+    assert_eq!(election_data(&mut world, id).turnout, 4);
+}
+
+#[test]
+fn tally_is_decrypted_by_any_threshold_trustees() {
+    let mut world = setup();
+    let (id, c, secrets) = encrypted_election(&mut world, APPROVAL, 2, 3, 2);
+    vote_encrypted(&mut world, id, 0, &c, &[true, true]);
+    vote_encrypted(&mut world, id, 1, &c, &[false, true]);
+    vote_encrypted(&mut world, id, 2, &c, &[false, false]);
+
+    world.current_block().block_timestamp(END + 1);
+    for (trustee, secret) in TRUSTEES.into_iter().zip(secrets.iter()) {
+        post_decryption_shares(&mut world, id, trustee, secret, &c);
+    }
+    let expected = vec![(c[0], 1), (c[1], 2)];
+    for trustees in [[0, 1], [0, 2], [1, 2]] {
+        assert_eq!(decrypt_tally(&mut world, id, &c, &trustees), expected);
+    }
+    // a single share is not enough
+    let tally_b = world.query().to(SC).typed(proxy::BackendScProxy).get_encrypted_tally(id, c[1]).returns(ReturnsResult).run().b;
+    let share = world.query().to(SC).typed(proxy::BackendScProxy).decryption_share(id, TRUSTEES[1].to_address(), c[1]).returns(ReturnsResultUnmanaged).run();
+    assert_eq!(elgamal::decrypt_count(&unmanaged(&tally_b), &[(2, share)], 3), None);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).publish_tally(id, tally(&expected)).run();
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+}
+
+#[test]
+fn approval_ballots_can_select_several_candidates() {
+    let mut world = setup();
+    let (id, c, secrets) = encrypted_election(&mut world, APPROVAL, 2, 2, 3);
+    vote_encrypted(&mut world, id, 0, &c, &[true, true]);
+    vote_encrypted(&mut world, id, 1, &c, &[false, true]);
+
+    world.current_block().block_timestamp(END + 1);
+    // with a threshold of 3, every trustee must post their shares
+    for (trustee, secret) in TRUSTEES.into_iter().zip(secrets.iter()).take(2) {
+        post_decryption_shares(&mut world, id, trustee, secret, &c);
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .publish_tally(id, tally(&[(c[0], 1), (c[1], 2)]))
+        .returns(ExpectError(4, "Decryption shares are missing"))
+        .run();
+    post_decryption_shares(&mut world, id, TRUSTEES[2], &secrets[2], &c);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .publish_tally(id, tally(&[(c[0], 1), (c[1], 2)]))
+        .run();
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+}
+
+#[test]
+fn invalid_encrypted_ballots_are_rejected() {
+    let mut world = setup();
+    let (id, c, _) = encrypted_election(&mut world, PLURALITY, 3, 2, 2);
+
+    vote_encrypted_err(&mut world, id, 0, &c, &[true, true, false], "Plurality ballots require a proof that at most one candidate is selected");
+    vote_encrypted_err(&mut world, id, 0, &c[..2], &[true, false], "Ballot must contain every candidate");
+    vote_encrypted_err(&mut world, id, 0, &[c[0], c[0], c[1]], &[true, false, false], "Candidate selected more than once");
+
+    // the proofs are bound to the voter, so a ballot cannot be cast again by another voter
+    let (choices, sum_proof) = encrypted_ballot(&mut world, id, 0, &c, &[true, false, false]);
+    world
+        .tx()
+        .from(voter(1))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote_encrypted(id, choices, sum_proof)
+        .returns(ExpectError(4, "Invalid ballot proof"))
+        .run();
+
+    vote_encrypted(&mut world, id, 0, &c, &[true, false, false]);
+    vote_encrypted_err(&mut world, id, 0, &c, &[true, false, false], "You are not eligible to vote");
+}
+
+#[test]
+fn invalid_trustee_keys_and_shares_are_rejected() {
+    let mut world = setup();
+    for trustee in TRUSTEES {
+        world.account(trustee).nonce(1);
+    }
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    register_voters(&mut world, id, 1);
+    let trustees = || MultiValueVec::from(TRUSTEES.map(|trustee| trustee.to_address()));
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_trustees(id, 2usize, trustees())
+        .returns(ExpectError(4, "Only admin can set the trustees"))
+        .run();
+    for threshold in [0usize, 4] {
+        world
+            .tx()
+            .from(OWNER)
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .set_trustees(id, threshold, trustees())
+            .returns(ExpectError(4, "Threshold must be between 1 and the number of trustees"))
+            .run();
+    }
+    set_trustees(&mut world, id, 2);
+
+    let keys = TRUSTEES.map(|trustee| elgamal::generate_key(id, &address(trustee), 2));
+    register_key_err(&mut world, id, TRUSTEES[0], &elgamal::generate_key(id, &address(TRUSTEES[0]), 3), "Key must commit to threshold - 1 coefficients");
+    // the proof of knowledge is bound to the trustee who registers the key
+    register_key_err(&mut world, id, TRUSTEES[1], &keys[0], "Invalid proof of knowledge of the secret key");
+    register_key_err(&mut world, id, OWNER, &keys[0], "Only trustees can register a key");
+    register_key(&mut world, id, TRUSTEES[0], &keys[0]);
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .joint_public_key(id)
+        .returns(ExpectError(4, "Trustee keys are missing"))
+        .run();
+    register_key(&mut world, id, TRUSTEES[1], &keys[1]);
+    register_key(&mut world, id, TRUSTEES[2], &keys[2]);
+    let public_keys: Vec<num_bigint::BigUint> = keys.iter().map(|key| key.public_key.clone()).collect();
+    assert_eq!(joint_public_key(&mut world, id), elgamal::joint_public_key(&public_keys));
+
+    // each trustee checks the key shares they receive against the commitments of their sender
+    let share = elgamal::key_share(&keys[0].coefficients, 2);
+    assert!(elgamal::verify_key_share(&keys[0].public_key, &keys[0].coefficient_commitments, 2, &share));
+    assert!(!elgamal::verify_key_share(&keys[0].public_key, &keys[0].coefficient_commitments, 3, &share));
+    let secrets = secret_key_shares(&keys);
+    let commitments: Vec<(num_bigint::BigUint, Vec<num_bigint::BigUint>)> = keys.iter().map(|key| (key.public_key.clone(), key.coefficient_commitments.clone())).collect();
+    for (i, trustee) in TRUSTEES.into_iter().enumerate() {
+        let verification_key: num_bigint::BigUint = world
+            .query()
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .trustee_verification_key(id, trustee.to_address())
+            .returns(ReturnsResultUnmanaged)
+            .run();
+        assert_eq!(verification_key, elgamal::generator().modpow(&secrets[i], &elgamal::prime()));
+        assert_eq!(verification_key, elgamal::verification_key(&commitments, i as u64 + 1));
+    }
+
+    world.current_block().block_timestamp(START);
+    vote_encrypted(&mut world, id, 0, &c, &[true, false]);
+    let shares = decryption_shares(&mut world, id, TRUSTEES[0], &secrets[0], &c);
+    world
+        .tx()
+        .from(TRUSTEES[0])
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .post_decryption_shares(id, shares)
+        .returns(ExpectError(4, "Election has not ended yet"))
+        .run();
+
+    world.current_block().block_timestamp(END + 1);
+    // shares computed with the secret key alone, or with another trustee's key share, do not match the verification key
+    for secret in [&keys[0].coefficients[0], &secrets[1]] {
+        let shares = decryption_shares(&mut world, id, TRUSTEES[0], secret, &c);
+        world
+            .tx()
+            .from(TRUSTEES[0])
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .post_decryption_shares(id, shares)
+            .returns(ExpectError(4, "Invalid decryption share proof"))
+            .run();
+    }
+    let shares = decryption_shares(&mut world, id, TRUSTEES[0], &secrets[0], &c);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .post_decryption_shares(id, shares.clone())
+        .returns(ExpectError(4, "Only trustees can post decryption shares"))
+        .run();
+    world.tx().from(TRUSTEES[0]).to(SC).typed(proxy::BackendScProxy).post_decryption_shares(id, shares.clone()).run();
+    world
+        .tx()
+        .from(TRUSTEES[0])
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .post_decryption_shares(id, shares)
+        .returns(ExpectError(4, "Decryption shares already posted"))
+        .run();
+}
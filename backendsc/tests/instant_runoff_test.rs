@@ -0,0 +1,80 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const STV: u64 = 2;
+
+fn stv_rounds(world: &mut ScenarioWorld, id: u64) -> Vec<proxy::StvRound<StaticApi>> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .stv_rounds(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn instant_runoff_transfers_eliminated_votes() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, STV, 3);
+    register_voters(&mut world, id, 5);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![c[0]]);
+    vote(&mut world, id, 1, vec![c[0], c[1]]);
+    vote(&mut world, id, 2, vec![c[1], c[0]]);
+    vote(&mut world, id, 3, vec![c[2], c[1]]);
+    vote(&mut world, id, 4, vec![c[2], c[0]]);
+
+    // round 1: A 2, B 1, C 2, B is eliminated and its vote goes to A, which then has a majority
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    let rounds = stv_rounds(&mut world, id);
+    assert_eq!(rounds.len(), 2);
+    assert_eq!(rounds[0].eliminated, c[1]);
+    assert_eq!(rounds[1].elected.clone().into_vec(), vec![c[0]]);
+}
+
+#[test]
+fn instant_runoff_tracks_exhausted_ballots() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, STV, 3);
+    let (a, b, cc) = (c[0], c[1], c[2]);
+    register_voters(&mut world, id, 7);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![a]);
+    vote(&mut world, id, 1, vec![a, b]);
+    vote(&mut world, id, 2, vec![a, cc]);
+    vote(&mut world, id, 3, vec![b]);
+    vote(&mut world, id, 4, vec![b, a]);
+    vote(&mut world, id, 5, vec![cc]);
+    vote(&mut world, id, 6, vec![cc, b]);
+
+    // round 1: A 3, B 2, C 2, tied B and C never differed, so C (registered last) is eliminated
+    // round 2: A 3, B 3 with 1 exhausted ballot, B had fewer votes in round 1 and is eliminated
+    // round 3: A 4 with 3 exhausted ballots, A has a majority of the continuing ballots
+    assert_eq!(results(&mut world, id), vec![a]);
+    let rounds = stv_rounds(&mut world, id);
+    assert_eq!(rounds.len(), 3);
+    assert_eq!(rounds[0].eliminated, cc);
+    assert_eq!(rounds[0].exhausted_votes, 0);
+    assert_eq!(rounds[1].eliminated, b);
+    assert_eq!(rounds[1].exhausted_votes, 1);
+    assert_eq!(rounds[2].exhausted_votes, 3);
+    assert_eq!(rounds[2].elected.clone().into_vec(), vec![a]);
+}
+
+#[test]
+fn instant_runoff_rejects_invalid_ballots() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, STV, 2);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_err(&mut world, id, 0, vec![c[0], c[0]], "Candidate selected more than once");
+    vote_err(&mut world, id, 0, vec![999], "Invalid candidate");
+    results_err(&mut world, id, "Election has not ended yet");
+}
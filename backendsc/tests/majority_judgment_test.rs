@@ -0,0 +1,119 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const MAJORITY_JUDGMENT: u64 = 10;
+
+fn grade_results(world: &mut ScenarioWorld, id: u64) -> Vec<proxy::GradeResult<StaticApi>> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .grade_results(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn majority_judgment_picks_the_best_median_grade() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, MAJORITY_JUDGMENT, 2);
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    // grades are indexes into the default grades, from Reject (0) to Excellent (5)
+    vote_rated(&mut world, id, 0, vec![(c[0], 5), (c[1], 2)]);
+    vote_rated(&mut world, id, 1, vec![(c[0], 1), (c[1], 3)]);
+    vote_rated(&mut world, id, 2, vec![(c[0], 0), (c[1], 3)]);
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    let grades = grade_results(&mut world, id);
+    assert_eq!(grades[0].median_grade, 1);
+    assert_eq!(grades[1].median_grade, 3);
+    assert_eq!(grades[0].histogram.clone().into_vec(), vec![1, 1, 0, 0, 0, 1]);
+    assert_eq!(result_vector(&mut world, id, c[1]), 3);
+}
+
+#[test]
+fn majority_judgment_breaks_equal_medians_by_removing_them() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, MAJORITY_JUDGMENT, 3);
+    let (a, b, cc) = (c[0], c[1], c[2]);
+    register_voters(&mut world, id, 5);
+    world.current_block().block_timestamp(START);
+
+    // every candidate has the majority grade 3, ungraded candidates get the worst grade
+    let grades_a = [5, 4, 3, 2, 1];
+    let grades_b = [5, 5, 3, 0, 0];
+    for v in 0..5 {
+        let mut ballot = vec![(a, grades_a[v]), (cc, 3)];
+        if grades_b[v] > 0 {
+            ballot.push((b, grades_b[v]));
+        }
+        vote_rated(&mut world, id, v, ballot);
+    }
+
+    assert_eq!(results(&mut world, id), vec![cc]);
+    let grades = grade_results(&mut world, id);
+    assert_eq!(grades.len(), 3);
+    assert!(grades.iter().all(|grade| grade.median_grade == 3));
+    assert_eq!(grades[1].histogram.clone().into_vec(), vec![2, 0, 0, 1, 0, 2]);
+}
+
+#[test]
+fn majority_judgment_uses_custom_grades() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, MAJORITY_JUDGMENT, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_grades(id, MultiValueVec::from(vec!["No", "Maybe", "Yes"]))
+        .run();
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_rated_err(&mut world, id, 0, vec![(c[0], 3)], "Invalid grade");
+    vote_rated(&mut world, id, 0, vec![(c[0], 2), (c[1], 1)]);
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    assert_eq!(grade_results(&mut world, id)[0].histogram.len(), 3);
+}
+
+#[test]
+fn majority_judgment_rejects_invalid_grades() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, MAJORITY_JUDGMENT, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_grades(id, MultiValueVec::from(vec!["Only"]))
+        .returns(ExpectError(4, "At least two grades are required"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_grades(id, MultiValueVec::from(vec!["Bad", ""]))
+        .returns(ExpectError(4, "Grade name cannot be empty"))
+        .run();
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_grades(id, MultiValueVec::from(vec!["Bad", "Good"]))
+        .returns(ExpectError(4, "Only admin can set the grades"))
+        .run();
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_rated_err(&mut world, id, 0, vec![(c[0], 6)], "Invalid grade");
+    vote_rated_err(&mut world, id, 0, vec![(c[0], -1)], "Rating cannot be negative");
+    vote_err(&mut world, id, 0, vec![c[0]], "Election requires rated votes");
+}
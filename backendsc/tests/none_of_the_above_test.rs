@@ -0,0 +1,182 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PLURALITY: u64 = 0;
+const APPROVAL: u64 = 1;
+const ADMIN_CASTING_VOTE: u64 = 2;
+const CASTING_VOTE_PERIOD: u64 = 7 * 24 * 60 * 60;
+const NONE_OF_THE_ABOVE: u16 = u16::MAX;
+
+fn set_none_of_the_above(world: &mut ScenarioWorld, id: u64) {
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_none_of_the_above(id, true).run();
+}
+
+#[test]
+fn blank_ballots_count_toward_turnout_only() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_blank_ballots_allowed(id, true).run();
+    register_voters(&mut world, id, 4);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![c[1]]);
+    vote(&mut world, id, 1, vec![]);
+    vote(&mut world, id, 2, vec![]);
+    vote_err(&mut world, id, 1, vec![], "You are not eligible to vote");
+    world
+        .tx()
+        .from(voter(3))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::<u16>::new())
+        .single_esdt(&TOKEN.into(), 0, &BigUint::from(1u64))
+        .returns(ExpectError(4, "Blank ballots do not accept payments"))
+        .run();
+
+    let blank_ballots: u64 = world.query().to(SC).typed(proxy::BackendScProxy).blank_ballots(id).returns(ReturnsResult).run();
+    assert_eq!(blank_ballots, 2);
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    let data = election_data(&mut world, id);
+    assert_eq!((data.turnout, data.void), (3, false));
+}
+
+#[test]
+fn winning_none_of_the_above_voids_the_election() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, APPROVAL, 2);
+    set_none_of_the_above(&mut world, id);
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+    vote(&mut world, id, 0, vec![NONE_OF_THE_ABOVE]);
+    vote(&mut world, id, 1, vec![NONE_OF_THE_ABOVE, c[0]]);
+
+    assert_eq!(results(&mut world, id), vec![NONE_OF_THE_ABOVE]);
+    assert!(election_data(&mut world, id).void);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .rerun_election(id, END + 500, END + 400)
+        .returns(ExpectError(4, "Rerun must end after it starts"))
+        .run();
+    let rerun: u64 = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .rerun_election(id, END + 1000, END + 2000)
+        .returns(ReturnsResult)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .rerun_election(id, END + 1000, END + 2000)
+        .returns(ExpectError(4, "Election has already been rerun"))
+        .run();
+
+    // the rerun keeps the voters and "None of the above", candidates register again
+    assert_eq!(election_data(&mut world, rerun).previous_round, Some(id));
+    let candidates: Vec<u16> = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .candidate_id_list(rerun)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(candidates, vec![NONE_OF_THE_ABOVE]);
+}
+
+#[test]
+fn rerun_requires_a_void_election() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 1);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+    vote(&mut world, id, 0, vec![c[0]]);
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .rerun_election(id, END + 1000, END + 2000)
+        .returns(ExpectError(4, "Election is not void"))
+        .run();
+}
+
+/// A Plurality election where "None of the above", added before the only candidate, is tied with it.
+fn tied_with_none_of_the_above(world: &mut ScenarioWorld) -> (u64, u16) {
+    let (id, _) = election_with_tie_break(world, PLURALITY, 0, OptionalValue::Some(ADMIN_CASTING_VOTE));
+    set_none_of_the_above(world, id);
+    let c = candidate(world, id);
+    register_voters(world, id, 2);
+    world.current_block().block_timestamp(START);
+    vote(world, id, 0, vec![NONE_OF_THE_ABOVE]);
+    vote(world, id, 1, vec![c]);
+    assert_eq!(results(world, id), Vec::<u16>::new());
+    (id, c)
+}
+
+#[test]
+fn casting_vote_for_none_of_the_above_voids_the_election() {
+    let mut world = setup();
+    let (id, _) = tied_with_none_of_the_above(&mut world);
+
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).cast_tie_breaking_vote(id, NONE_OF_THE_ABOVE).run();
+    assert_eq!(results(&mut world, id), vec![NONE_OF_THE_ABOVE]);
+    assert!(election_data(&mut world, id).void);
+}
+
+#[test]
+fn expired_casting_vote_for_none_of_the_above_voids_the_election() {
+    let mut world = setup();
+    let (id, _) = tied_with_none_of_the_above(&mut world);
+
+    // "None of the above" was registered first, so it wins the tie once the deadline has passed
+    world.current_block().block_timestamp(END + CASTING_VOTE_PERIOD + 1);
+    let winner: Vec<u16> = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .results(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(winner, vec![NONE_OF_THE_ABOVE]);
+    assert!(election_data(&mut world, id).void);
+}
+
+#[test]
+fn casting_vote_for_a_candidate_keeps_the_election_valid() {
+    let mut world = setup();
+    let (id, c) = tied_with_none_of_the_above(&mut world);
+
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).cast_tie_breaking_vote(id, c).run();
+    assert_eq!(results(&mut world, id), vec![c]);
+    assert!(!election_data(&mut world, id).void);
+}
+
+#[test]
+fn disabling_none_of_the_above_keeps_the_registration_order() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, PLURALITY, 0);
+    set_none_of_the_above(&mut world, id);
+    let c = [candidate(&mut world, id), candidate(&mut world, id)];
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_none_of_the_above(id, false).run();
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+    vote(&mut world, id, 0, vec![c[1]]);
+    vote(&mut world, id, 1, vec![c[0]]);
+
+    // the tie goes to the candidate registered first
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PARTY_LIST: u64 = 12;
+const D_HONDT: u64 = 0;
+const SAINTE_LAGUE: u64 = 1;
+
+/// Registers a four-seat election with three parties, the first one listing three candidates,
+/// and casts token-weighted votes of 600, 300 and 40 for them.
+fn party_election(world: &mut ScenarioWorld, method: u64) -> (u64, Vec<u16>, Vec<u16>) {
+    let (id, c) = election(world, PARTY_LIST, 5);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_seats(id, 4u32).run();
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_party_list_rules(id, method, 5u64).run();
+    let mut parties = vec![];
+    for _ in 0..3 {
+        let party: u16 = world
+            .tx()
+            .from(OWNER)
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .register_party(id, "party")
+            .returns(ReturnsResult)
+            .run();
+        parties.push(party);
+    }
+    for (i, p) in [0usize, 0, 0, 1, 2].iter().enumerate() {
+        world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).add_candidate_to_party(id, parties[*p], c[i]).run();
+    }
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_voting_token(id, TOKEN).run();
+    register_voters(world, id, 3);
+    world.current_block().block_timestamp(START);
+    for (v, (p, amount)) in [(0usize, 600u64), (1, 300), (2, 40)].iter().enumerate() {
+        world
+            .tx()
+            .from(voter(v))
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .vote(id, MultiValueVec::from(vec![parties[*p]]))
+            .single_esdt(&TOKEN.into(), 0, &BigUint::from(*amount))
+            .run();
+    }
+    (id, c, parties)
+}
+
+fn seats(world: &mut ScenarioWorld, id: u64) -> Vec<(u16, u32)> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .party_results(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+        .iter()
+        .map(|result| (result.party, result.seats))
+        .collect()
+}
+
+#[test]
+fn d_hondt_allocates_seats_and_applies_the_threshold() {
+    let mut world = setup();
+    let (id, c, parties) = party_election(&mut world, D_HONDT);
+
+    // quotients 600, 300, 300, 200: the first party wins three seats, the third is below 5%
+    assert_eq!(results(&mut world, id), vec![c[0], c[1], c[3], c[2]]);
+    let seats = seats(&mut world, id);
+    assert!(seats.contains(&(parties[0], 3)));
+    assert!(seats.contains(&(parties[1], 1)));
+    assert!(seats.contains(&(parties[2], 0)));
+}
+
+#[test]
+fn sainte_lague_favours_smaller_parties() {
+    let mut world = setup();
+    let (id, c, parties) = party_election(&mut world, SAINTE_LAGUE);
+
+    // quotients 600, 300, 200, 120: the second party takes the second seat
+    assert_eq!(results(&mut world, id), vec![c[0], c[3], c[1], c[2]]);
+    let seats = seats(&mut world, id);
+    assert!(seats.contains(&(parties[0], 3)));
+    assert!(seats.contains(&(parties[1], 1)));
+}
+
+#[test]
+fn party_list_rejects_invalid_parties() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PARTY_LIST, 1);
+    let party: u16 = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .register_party(id, "party")
+        .returns(ReturnsResult)
+        .run();
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).add_candidate_to_party(id, party, c[0]).run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .add_candidate_to_party(id, party, c[0])
+        .returns(ExpectError(4, "Candidate already on a party list"))
+        .run();
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_err(&mut world, id, 0, vec![party.wrapping_add(1)], "Invalid party");
+    vote(&mut world, id, 0, vec![party]);
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const QUADRATIC: u64 = 9;
+
+fn set_budget(world: &mut ScenarioWorld, id: u64, budget: u64) {
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_voice_credit_budget(id, budget).run();
+}
+
+fn voice_credits_spent(world: &mut ScenarioWorld, id: u64, v: usize) -> u64 {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .voice_credits_spent(id, voter(v).to_managed_address())
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn net_votes(world: &mut ScenarioWorld, id: u64, candidate: u16) -> num_bigint::BigInt {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .net_votes(id, candidate)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+#[test]
+fn quadratic_votes_cost_their_square() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, QUADRATIC, 3);
+    set_budget(&mut world, id, 25);
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    vote_rated(&mut world, id, 0, vec![(c[0], 5)]);
+    vote_rated(&mut world, id, 1, vec![(c[1], 3), (c[2], 4)]);
+    vote_rated(&mut world, id, 2, vec![(c[1], 4), (c[2], 2)]);
+    assert_eq!(voice_credits_spent(&mut world, id, 0), 25);
+    assert_eq!(voice_credits_spent(&mut world, id, 2), 20);
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 5);
+    assert_eq!(result_vector(&mut world, id, c[1]), 7);
+    assert_eq!(result_vector(&mut world, id, c[2]), 6);
+    assert_eq!(net_votes(&mut world, id, c[1]), 7.into());
+}
+
+#[test]
+fn quadratic_tallies_net_votes() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, QUADRATIC, 3);
+    set_budget(&mut world, id, 25);
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    // votes against a candidate cost as much as votes for them
+    vote_rated(&mut world, id, 0, vec![(c[0], 4), (c[1], -3)]);
+    vote_rated(&mut world, id, 1, vec![(c[0], -5)]);
+    vote_rated(&mut world, id, 2, vec![(c[1], 2), (c[2], -1)]);
+    assert_eq!(voice_credits_spent(&mut world, id, 0), 25);
+    assert_eq!(voice_credits_spent(&mut world, id, 1), 25);
+
+    // A: 4 - 5 = -1, B: -3 + 2 = -1, C: -1, the tie goes to the candidate registered first
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    assert_eq!(net_votes(&mut world, id, c[0]), (-1).into());
+    assert_eq!(net_votes(&mut world, id, c[1]), (-1).into());
+    assert_eq!(net_votes(&mut world, id, c[2]), (-1).into());
+    assert_eq!(result_vector(&mut world, id, c[0]), 0);
+}
+
+#[test]
+fn quadratic_votes_against_can_decide_the_winner() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, QUADRATIC, 2);
+    set_budget(&mut world, id, 25);
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    vote_rated(&mut world, id, 0, vec![(c[0], 3)]);
+    vote_rated(&mut world, id, 1, vec![(c[1], 2)]);
+    vote_rated(&mut world, id, 2, vec![(c[0], -2)]);
+
+    // A has more votes for them (3 to 2), but B has more net votes (2 to 1)
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(net_votes(&mut world, id, c[0]), 1.into());
+    assert_eq!(net_votes(&mut world, id, c[1]), 2.into());
+}
+
+#[test]
+fn quadratic_rejects_overspending() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, QUADRATIC, 3);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_voice_credit_budget(id, 0u64)
+        .returns(ExpectError(4, "Voice credit budget must be positive"))
+        .run();
+    let (cumulative, _) = election(&mut world, 8, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_voice_credit_budget(cumulative, 25u64)
+        .returns(ExpectError(4, "Election type does not use voice credits"))
+        .run();
+    set_budget(&mut world, id, 25);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_rated_err(&mut world, id, 0, vec![(c[1], 4), (c[2], 4)], "Voice credits exceed the budget");
+    vote_rated_err(&mut world, id, 0, vec![(c[1], -4), (c[2], -4)], "Voice credits exceed the budget");
+    vote_rated_err(&mut world, id, 0, vec![(c[1], i64::MAX)], "Voice credits exceed the budget");
+    vote_rated_err(&mut world, id, 0, vec![(c[1], i64::MIN)], "Voice credits exceed the budget");
+    vote_rated_err(&mut world, id, 0, vec![(c[1], 1), (c[1], -1)], "Candidate rated more than once");
+    assert_eq!(voice_credits_spent(&mut world, id, 0), 0);
+}
+
+#[test]
+fn negative_ratings_are_only_allowed_in_quadratic_elections() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, 8, 2);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_rated_err(&mut world, id, 0, vec![(c[0], -1)], "Rating cannot be negative");
+}
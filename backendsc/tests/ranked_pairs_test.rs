@@ -0,0 +1,63 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const RANKED_PAIRS: u64 = 5;
+
+fn locked_pairs(world: &mut ScenarioWorld, id: u64) -> Vec<(u16, u16, u64)> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .locked_pairs(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+        .into_iter()
+        .map(|pair| (pair.winner, pair.loser, pair.margin))
+        .collect()
+}
+
+#[test]
+fn ranked_pairs_skips_the_pair_that_closes_a_cycle() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, RANKED_PAIRS, 3);
+    let (a, b, cc) = (c[0], c[1], c[2]);
+    register_voters(&mut world, id, 7);
+    world.current_block().block_timestamp(START);
+
+    // A beats B 5:2, B beats C 4:2 and C beats A 4:3, which would close the cycle
+    vote(&mut world, id, 0, vec![a, b, cc]);
+    vote(&mut world, id, 1, vec![a, b]);
+    vote(&mut world, id, 2, vec![b, cc, a]);
+    vote(&mut world, id, 3, vec![b, cc]);
+    vote(&mut world, id, 4, vec![cc, a, b]);
+    vote(&mut world, id, 5, vec![cc, a]);
+    vote(&mut world, id, 6, vec![a]);
+
+    assert_eq!(results(&mut world, id), vec![a, b, cc]);
+    let locked = locked_pairs(&mut world, id);
+    assert_eq!(locked.len(), 2);
+    assert!(locked.contains(&(a, b, 3)));
+    assert!(locked.contains(&(b, cc, 2)));
+    assert!(!locked.iter().any(|&(winner, loser, _)| winner == cc && loser == a));
+    // the tally of a candidate is the number of candidates ranked below them
+    assert_eq!(result_vector(&mut world, id, a), 2);
+    assert_eq!(result_vector(&mut world, id, b), 1);
+    assert_eq!(result_vector(&mut world, id, cc), 0);
+}
+
+#[test]
+fn ranked_pairs_rejects_invalid_ballots() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, RANKED_PAIRS, 3);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_err(&mut world, id, 0, vec![c[2], c[2]], "Candidate selected more than once");
+    vote_err(&mut world, id, 0, vec![c[0], 999], "Invalid candidate");
+    vote_rated_err(&mut world, id, 0, vec![(c[0], 1)], "Election does not use rated votes");
+    results_err(&mut world, id, "Election has not ended yet");
+    assert!(locked_pairs(&mut world, id).is_empty());
+}
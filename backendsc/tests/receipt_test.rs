@@ -0,0 +1,116 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+use sha2::{Digest, Sha256};
+
+const PLURALITY: u64 = 0;
+
+/// A distinct transaction hash for each vote: it seeds the random salt of the receipt, and is all zeros by default.
+fn tx_hash(id: u64, v: usize) -> [u8; 32] {
+    let mut tx_hash = [0u8; 32];
+    tx_hash[..8].copy_from_slice(&id.to_be_bytes());
+    tx_hash[8..16].copy_from_slice(&(v as u64).to_be_bytes());
+    tx_hash
+}
+
+fn vote_receipt(world: &mut ScenarioWorld, id: u64, v: usize, candidates: Vec<u16>) -> Option<proxy::Receipt<StaticApi>> {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .tx_hash(tx_hash(id, v))
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(candidates))
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+}
+
+fn verify_receipt(world: &mut ScenarioWorld, id: u64, ballot_id: u64, salt: &ManagedByteArray<StaticApi, 32>) -> proxy::ReceiptStatus {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .verify_receipt(id, ballot_id, salt.clone())
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+/// The receipt hash of a ballot of the ledger, computed off-chain: sha256(election ID || ballot || salt).
+fn receipt_hash(world: &mut ScenarioWorld, id: u64, ballot_id: u64, salt: &ManagedByteArray<StaticApi, 32>) -> [u8; 32] {
+    let ballot: proxy::Ballot<StaticApi> = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_ballot(id, ballot_id)
+        .returns(ReturnsResult)
+        .run();
+    let mut encoded_ballot = ManagedBuffer::<StaticApi>::new();
+    ballot.top_encode(&mut encoded_ballot).unwrap();
+    let mut hasher = Sha256::new();
+    hasher.update(id.to_be_bytes());
+    hasher.update(encoded_ballot.to_boxed_bytes().as_slice());
+    hasher.update(salt.to_byte_array());
+    hasher.finalize().into()
+}
+
+#[test]
+fn receipts_commit_to_the_ballot_and_its_position() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+
+    let first = vote_receipt(&mut world, id, 0, vec![c[0]]).unwrap();
+    let second = vote_receipt(&mut world, id, 1, vec![c[0]]).unwrap();
+    assert_eq!((first.ballot_id, second.ballot_id), (1, 2));
+    // identical ballots get different receipts
+    assert_ne!(first.hash.to_byte_array(), second.hash.to_byte_array());
+    assert_ne!(first.salt.to_byte_array(), second.salt.to_byte_array());
+
+    for receipt in [&first, &second] {
+        assert_eq!(receipt.hash.to_byte_array(), receipt_hash(&mut world, id, receipt.ballot_id, &receipt.salt));
+        assert_eq!(verify_receipt(&mut world, id, receipt.ballot_id, &receipt.salt), proxy::ReceiptStatus::Counted);
+    }
+}
+
+#[test]
+fn forged_receipts_are_not_verified() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    let (other_id, other_c) = election(&mut world, PLURALITY, 2);
+    register_voters(&mut world, id, 2);
+    register_voters(&mut world, other_id, 1);
+    world.current_block().block_timestamp(START);
+    let first = vote_receipt(&mut world, id, 0, vec![c[0]]).unwrap();
+    let second = vote_receipt(&mut world, id, 1, vec![c[1]]).unwrap();
+    vote_receipt(&mut world, other_id, 0, vec![other_c[0]]).unwrap();
+
+    assert_eq!(verify_receipt(&mut world, id, second.ballot_id, &first.salt), proxy::ReceiptStatus::Invalid);
+    assert_eq!(verify_receipt(&mut world, id, 0, &first.salt), proxy::ReceiptStatus::Invalid);
+    assert_eq!(verify_receipt(&mut world, id, 3, &first.salt), proxy::ReceiptStatus::Invalid);
+    assert_eq!(verify_receipt(&mut world, other_id, first.ballot_id, &first.salt), proxy::ReceiptStatus::Invalid);
+    let mut tampered = first.salt.to_byte_array();
+    tampered[0] ^= 1;
+    assert_eq!(verify_receipt(&mut world, id, first.ballot_id, &ManagedByteArray::new_from_bytes(&tampered)), proxy::ReceiptStatus::Invalid);
+    // the public ledger is not enough to produce a receipt: without the salt of the voter, the hash does not match
+    assert_eq!(verify_receipt(&mut world, id, first.ballot_id, &ManagedByteArray::new_from_bytes(&[0u8; 32])), proxy::ReceiptStatus::Invalid);
+}
+
+#[test]
+fn blank_ballots_have_no_receipt() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, PLURALITY, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_blank_ballots_allowed(id, true)
+        .run();
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    assert!(vote_receipt(&mut world, id, 0, vec![]).is_none());
+}
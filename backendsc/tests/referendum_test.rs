@@ -0,0 +1,117 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const REFERENDUM: u64 = 13;
+const YES: u16 = 1;
+const NO: u16 = 2;
+const ABSTAIN: u16 = 3;
+
+fn referendum(world: &mut ScenarioWorld, quorum_percent: u64, numerator: u64, denominator: u64, n_voters: usize) -> u64 {
+    let (id, _) = election(world, REFERENDUM, 0);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_referendum_rules(id, quorum_percent, numerator, denominator)
+        .run();
+    register_voters(world, id, n_voters);
+    world.current_block().block_timestamp(START);
+    id
+}
+
+fn referendum_result(world: &mut ScenarioWorld, id: u64) -> proxy::ReferendumResult {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .referendum_result(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+#[test]
+fn referendum_passes_with_the_threshold() {
+    let mut world = setup();
+    let id = referendum(&mut world, 50, 2, 3, 5);
+    vote(&mut world, id, 0, vec![YES]);
+    vote(&mut world, id, 1, vec![YES]);
+    vote(&mut world, id, 2, vec![NO]);
+    vote(&mut world, id, 3, vec![ABSTAIN]);
+
+    // 2 of 3 decisive votes reach the two-thirds threshold, abstentions count towards the quorum only
+    assert_eq!(results(&mut world, id), vec![YES]);
+    let result = referendum_result(&mut world, id);
+    assert_eq!((result.yes_votes, result.no_votes, result.abstain_votes, result.registered_voters), (2, 1, 1, 5));
+    assert_eq!(result.outcome, proxy::ReferendumOutcome::Passed);
+}
+
+#[test]
+fn referendum_is_rejected_below_the_threshold() {
+    let mut world = setup();
+    let id = referendum(&mut world, 0, 3, 4, 3);
+    vote(&mut world, id, 0, vec![YES]);
+    vote(&mut world, id, 1, vec![YES]);
+    vote(&mut world, id, 2, vec![NO]);
+
+    assert_eq!(results(&mut world, id), vec![NO]);
+    assert_eq!(referendum_result(&mut world, id).outcome, proxy::ReferendumOutcome::Rejected);
+}
+
+#[test]
+fn referendum_without_quorum_has_no_result() {
+    let mut world = setup();
+    let id = referendum(&mut world, 70, 1, 2, 5);
+    vote(&mut world, id, 0, vec![YES]);
+    vote(&mut world, id, 1, vec![NO]);
+    vote(&mut world, id, 2, vec![ABSTAIN]);
+
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+    assert_eq!(referendum_result(&mut world, id).outcome, proxy::ReferendumOutcome::QuorumNotMet);
+}
+
+#[test]
+fn referendum_rejects_invalid_input() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, REFERENDUM, 0);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .submit_candidancy(id, "name", "description")
+        .returns(ExpectError(4, "Referendum elections have no candidates"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_referendum_rules(id, 0u64, 3u64, 2u64)
+        .returns(ExpectError(4, "Threshold cannot exceed 1"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_referendum_rules(id, 101u64, 1u64, 2u64)
+        .returns(ExpectError(4, "Quorum cannot exceed 100 percent"))
+        .run();
+
+    let (other, _) = election(&mut world, 0, 1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_referendum_rules(other, 0u64, 1u64, 2u64)
+        .returns(ExpectError(4, "Election type is not a referendum"))
+        .run();
+
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+    vote_err(&mut world, id, 0, vec![4], "Invalid referendum choice");
+}
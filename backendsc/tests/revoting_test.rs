@@ -0,0 +1,191 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PLURALITY: u64 = 0;
+const QUADRATIC: u64 = 9;
+
+fn set_revoting_allowed(world: &mut ScenarioWorld, id: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_revoting_allowed(id, true)
+        .run();
+}
+
+fn set_revoting_allowed_err(world: &mut ScenarioWorld, from: TestAddress, id: u64, message: &str) {
+    world
+        .tx()
+        .from(from)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_revoting_allowed(id, true)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+/// A plurality election with revoting and `n` registered voters, at the start of voting.
+fn revoting_election(world: &mut ScenarioWorld, n: usize) -> (u64, Vec<u16>) {
+    let (id, c) = election(world, PLURALITY, 2);
+    set_revoting_allowed(world, id);
+    register_voters(world, id, n);
+    world.current_block().block_timestamp(START);
+    (id, c)
+}
+
+fn vote_receipt(world: &mut ScenarioWorld, id: u64, v: usize, candidates: Vec<u16>) -> proxy::Receipt<StaticApi> {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(candidates))
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap()
+}
+
+fn verify_receipt(world: &mut ScenarioWorld, id: u64, receipt: &proxy::Receipt<StaticApi>) -> proxy::ReceiptStatus {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .verify_receipt(id, receipt.ballot_id, receipt.salt.clone())
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+fn superseded_ballots(world: &mut ScenarioWorld, id: u64) -> Vec<u64> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .superseded_ballots(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn only_the_latest_ballot_is_counted() {
+    let mut world = setup();
+    let (id, c) = revoting_election(&mut world, 3);
+    let first = vote_receipt(&mut world, id, 0, vec![c[0]]);
+    vote(&mut world, id, 1, vec![c[1]]);
+    vote(&mut world, id, 2, vec![c[0]]);
+    let second = vote_receipt(&mut world, id, 0, vec![c[1]]);
+    let third = vote_receipt(&mut world, id, 0, vec![c[1]]);
+
+    assert_eq!(superseded_ballots(&mut world, id), vec![first.ballot_id, second.ballot_id]);
+    assert_eq!(verify_receipt(&mut world, id, &first), proxy::ReceiptStatus::Superseded);
+    assert_eq!(verify_receipt(&mut world, id, &second), proxy::ReceiptStatus::Superseded);
+    assert_eq!(verify_receipt(&mut world, id, &third), proxy::ReceiptStatus::Counted);
+    assert_eq!(election_data(&mut world, id).turnout, 3);
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 1);
+    assert_eq!(result_vector(&mut world, id, c[1]), 2);
+}
+
+#[test]
+fn only_the_latest_quadratic_ballot_is_counted() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, QUADRATIC, 2);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_voice_credit_budget(id, 25u64).run();
+    set_revoting_allowed(&mut world, id);
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+
+    vote_rated(&mut world, id, 0, vec![(c[0], 5)]);
+    vote_rated(&mut world, id, 1, vec![(c[1], 3)]);
+    vote_rated(&mut world, id, 0, vec![(c[0], -2), (c[1], 4)]);
+
+    // A: -2, B: 3 + 4 = 7, the first ballot of voter 0 is not counted
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 0);
+    assert_eq!(result_vector(&mut world, id, c[1]), 7);
+}
+
+#[test]
+fn a_vote_replaces_a_blank_ballot() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    set_revoting_allowed(&mut world, id);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_blank_ballots_allowed(id, true)
+        .run();
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![]);
+    vote(&mut world, id, 0, vec![c[0]]);
+
+    let blank_ballots: u64 = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .blank_ballots(id)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(blank_ballots, 0);
+    assert_eq!(election_data(&mut world, id).turnout, 1);
+    assert_eq!(results(&mut world, id)[0], c[0]);
+}
+
+#[test]
+fn revoting_ends_with_the_voting_period() {
+    let mut world = setup();
+    let (id, c) = revoting_election(&mut world, 1);
+    vote(&mut world, id, 0, vec![c[0]]);
+
+    world.current_block().block_timestamp(END);
+    vote_err(&mut world, id, 0, vec![c[1]], "Voting has ended");
+    assert_eq!(results(&mut world, id)[0], c[0]);
+}
+
+#[test]
+fn voters_cannot_revote_without_revoting() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![c[0]]);
+    vote_err(&mut world, id, 0, vec![c[1]], "You are not eligible to vote");
+}
+
+#[test]
+fn revoting_is_rejected_with_incompatible_settings() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, PLURALITY, 2);
+    set_revoting_allowed_err(&mut world, voter(0), id, "Only admin can allow revoting");
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_reveal_period(id, 100u64)
+        .run();
+    set_revoting_allowed_err(&mut world, OWNER, id, "Revoting is not available with committed votes");
+
+    let (id, _) = election(&mut world, PLURALITY, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_voting_token(id, TOKEN)
+        .run();
+    set_revoting_allowed_err(&mut world, OWNER, id, "Revoting is not available with token-weighted votes");
+
+    world.current_block().block_timestamp(START);
+    set_revoting_allowed_err(&mut world, OWNER, id, "Election has started");
+}
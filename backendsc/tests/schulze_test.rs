@@ -0,0 +1,68 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const SCHULZE: u64 = 4;
+
+fn pairwise_matrix(world: &mut ScenarioWorld, id: u64) -> Vec<(u16, u16, u64)> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_pairwise_matrix(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+        .into_iter()
+        .map(|preference| (preference.candidate, preference.opponent, preference.count))
+        .collect()
+}
+
+#[test]
+fn schulze_finds_the_condorcet_winner() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, SCHULZE, 3);
+    let (a, b, cc) = (c[0], c[1], c[2]);
+    register_voters(&mut world, id, 5);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![a, cc, b]);
+    vote(&mut world, id, 1, vec![a, cc]);
+    vote(&mut world, id, 2, vec![b, cc, a]);
+    vote(&mut world, id, 3, vec![b, cc]);
+    vote(&mut world, id, 4, vec![cc, a, b]);
+
+    let matrix = pairwise_matrix(&mut world, id);
+    assert_eq!(matrix.len(), 6);
+    assert!(matrix.contains(&(cc, a, 3)));
+    assert!(matrix.contains(&(a, cc, 2)));
+    assert!(matrix.contains(&(cc, b, 3)));
+    assert!(matrix.contains(&(a, b, 3)));
+    assert!(matrix.contains(&(b, a, 2)));
+
+    assert_eq!(results(&mut world, id), vec![cc]);
+    // the tally of a candidate is the number of head-to-head wins along the strongest paths
+    assert_eq!(result_vector(&mut world, id, cc), 2);
+    assert_eq!(result_vector(&mut world, id, a), 1);
+    assert_eq!(result_vector(&mut world, id, b), 0);
+}
+
+#[test]
+fn schulze_rejects_invalid_ballots_and_queries() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, SCHULZE, 3);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    vote_err(&mut world, id, 0, vec![c[0], c[1], c[0]], "Candidate selected more than once");
+    vote_err(&mut world, id, 0, vec![999], "Invalid candidate");
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_pairwise_matrix(42u64)
+        .returns(ExpectError(4, "Election does not exist"))
+        .run();
+    results_err(&mut world, id, "Election has not ended yet");
+}
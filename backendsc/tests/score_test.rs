@@ -0,0 +1,96 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const SCORE: u64 = 6;
+
+#[test]
+fn score_sums_the_ratings() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, SCORE, 3);
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    // unrated candidates count as 0
+    vote_rated(&mut world, id, 0, vec![(c[0], 10), (c[1], 5)]);
+    vote_rated(&mut world, id, 1, vec![(c[0], 0), (c[1], 8), (c[2], 9)]);
+    vote_rated(&mut world, id, 2, vec![(c[1], 7), (c[2], 2)]);
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 10);
+    assert_eq!(result_vector(&mut world, id, c[1]), 20);
+    assert_eq!(result_vector(&mut world, id, c[2]), 11);
+    let average: u64 = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .result_average(id, c[1])
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(average, 666);
+}
+
+#[test]
+fn score_uses_a_custom_rating_scale() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, SCORE, 2);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_rating_scale(id, 1u64, 3u64).run();
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+
+    vote_rated_err(&mut world, id, 0, vec![(c[0], 0)], "Rating out of bounds");
+    vote_rated_err(&mut world, id, 0, vec![(c[0], 4)], "Rating out of bounds");
+    vote_rated(&mut world, id, 0, vec![(c[0], 3), (c[1], 1)]);
+    vote_rated(&mut world, id, 1, vec![(c[0], 1), (c[1], 2)]);
+
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 4);
+    assert_eq!(result_vector(&mut world, id, c[1]), 3);
+}
+
+#[test]
+fn score_rejects_invalid_ballots_and_settings() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, SCORE, 2);
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_rating_scale(id, 0u64, 5u64)
+        .returns(ExpectError(4, "Only admin can set the rating scale"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_rating_scale(id, 5u64, 5u64)
+        .returns(ExpectError(4, "Invalid rating scale"))
+        .run();
+    let (plurality, _) = election(&mut world, 0, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_rating_scale(plurality, 0u64, 5u64)
+        .returns(ExpectError(4, "Election type does not use a rating scale"))
+        .run();
+
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_rating_scale(id, 0u64, 5u64)
+        .returns(ExpectError(4, "Election has started"))
+        .run();
+    vote_rated_err(&mut world, id, 0, vec![(c[1], 11)], "Rating out of bounds");
+    vote_rated_err(&mut world, id, 0, vec![(c[1], 1), (c[1], 2)], "Candidate rated more than once");
+    vote_rated_err(&mut world, id, 0, vec![(999, 1)], "Invalid candidate");
+    vote_err(&mut world, id, 0, vec![c[0]], "Election requires rated votes");
+}
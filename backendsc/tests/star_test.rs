@@ -0,0 +1,81 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const STAR: u64 = 7;
+
+#[test]
+fn star_runoff_can_overturn_the_score_leader() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, STAR, 3);
+    let (a, b, cc) = (c[0], c[1], c[2]);
+    register_voters(&mut world, id, 5);
+    world.current_block().block_timestamp(START);
+
+    vote_rated(&mut world, id, 0, vec![(a, 5), (b, 0)]);
+    vote_rated(&mut world, id, 1, vec![(a, 5), (b, 1)]);
+    vote_rated(&mut world, id, 2, vec![(a, 2), (b, 3)]);
+    vote_rated(&mut world, id, 3, vec![(a, 2), (b, 3), (cc, 0)]);
+    vote_rated(&mut world, id, 4, vec![(a, 3), (b, 4)]);
+
+    // A has the highest score (17 to 11), but B is preferred to A by 3 voters out of 5
+    assert_eq!(results(&mut world, id), vec![b]);
+    assert_eq!(result_vector(&mut world, id, a), 17);
+    assert_eq!(result_vector(&mut world, id, b), 11);
+    assert_eq!(result_vector(&mut world, id, cc), 0);
+    let star_result = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .star_result(id)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(star_result.first_finalist, a);
+    assert_eq!(star_result.second_finalist, b);
+    assert_eq!(star_result.first_finalist_votes, 2);
+    assert_eq!(star_result.second_finalist_votes, 3);
+    assert_eq!(star_result.no_preference, 0);
+    assert_eq!(star_result.winner, b);
+    assert_eq!(star_result.scores.len(), 3);
+}
+
+#[test]
+fn star_counts_equal_ratings_as_no_preference() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, STAR, 2);
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    vote_rated(&mut world, id, 0, vec![(c[0], 4), (c[1], 4)]);
+    vote_rated(&mut world, id, 1, vec![(c[0], 5), (c[1], 1)]);
+    vote_rated(&mut world, id, 2, vec![(c[0], 2)]);
+
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    let star_result = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .star_result(id)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(star_result.first_finalist_votes, 2);
+    assert_eq!(star_result.second_finalist_votes, 0);
+    assert_eq!(star_result.no_preference, 1);
+}
+
+#[test]
+fn star_rejects_invalid_ballots() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, STAR, 2);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    // the default STAR scale is 0 to 5
+    vote_rated_err(&mut world, id, 0, vec![(c[0], 6)], "Rating out of bounds");
+    vote_rated_err(&mut world, id, 0, vec![(c[0], 1), (c[0], 1)], "Candidate rated more than once");
+    vote_err(&mut world, id, 0, vec![c[0]], "Election requires rated votes");
+    vote_rated(&mut world, id, 0, vec![(c[0], 5)]);
+    vote_rated_err(&mut world, id, 0, vec![(c[1], 5)], "You are not eligible to vote");
+    results_err(&mut world, id, "Election has not ended yet");
+}
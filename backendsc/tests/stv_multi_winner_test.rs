@@ -0,0 +1,104 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const STV: u64 = 2;
+
+fn set_seats(world: &mut ScenarioWorld, id: u64, seats: u32) {
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_seats(id, seats).run();
+}
+
+#[test]
+fn stv_transfers_the_surplus_of_elected_candidates() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, STV, 4);
+    let (a, b, cc, d) = (c[0], c[1], c[2], c[3]);
+    set_seats(&mut world, id, 2);
+    register_voters(&mut world, id, 9);
+    world.current_block().block_timestamp(START);
+
+    // 9 votes for 2 seats: the Droop quota is 4, A has a surplus of 2 votes which goes to B
+    for v in 0..6 {
+        vote(&mut world, id, v, vec![a, b]);
+    }
+    vote(&mut world, id, 6, vec![b]);
+    vote(&mut world, id, 7, vec![cc]);
+    vote(&mut world, id, 8, vec![cc, d]);
+
+    assert_eq!(results(&mut world, id), vec![a, b]);
+    assert_eq!(result_vector(&mut world, id, a), 6);
+    assert_eq!(result_vector(&mut world, id, b), 2);
+
+    let rounds = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .stv_rounds(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(rounds.len(), 4);
+    assert_eq!(rounds[0].elected.clone().into_vec(), vec![a]);
+    assert_eq!(rounds[1].eliminated, d);
+    assert_eq!(rounds[2].eliminated, cc);
+    assert_eq!(rounds[3].elected.clone().into_vec(), vec![b]);
+    assert_eq!(rounds[1].quota, rounds[0].quota);
+}
+
+#[test]
+fn stv_fills_every_seat_with_few_candidates() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, STV, 3);
+    set_seats(&mut world, id, 3);
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![c[1]]);
+    vote(&mut world, id, 1, vec![c[1], c[2]]);
+
+    // all continuing candidates are elected, ordered by their votes
+    let ranking = results(&mut world, id);
+    assert_eq!(ranking.len(), 3);
+    assert_eq!(ranking[0], c[1]);
+}
+
+#[test]
+fn stv_seats_are_validated() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, STV, 3);
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_seats(id, 2u32)
+        .returns(ExpectError(4, "Only admin can set the number of seats"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_seats(id, 0u32)
+        .returns(ExpectError(4, "Number of seats must be positive"))
+        .run();
+    let (borda, _) = election(&mut world, 3, 3);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_seats(borda, 2u32)
+        .returns(ExpectError(4, "Election type does not support multiple seats"))
+        .run();
+    world.current_block().block_timestamp(START);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_seats(id, 2u32)
+        .returns(ExpectError(4, "Election has started"))
+        .run();
+}
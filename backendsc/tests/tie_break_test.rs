@@ -0,0 +1,270 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PLURALITY: u64 = 0;
+const STV: u64 = 2;
+const RANKED_PAIRS: u64 = 5;
+const STAR: u64 = 7;
+const MAJORITY_JUDGMENT: u64 = 10;
+const TWO_ROUND: u64 = 11;
+const PARTY_LIST: u64 = 12;
+
+const RANDOM: u64 = 1;
+const ADMIN_CASTING_VOTE: u64 = 2;
+const CASTING_VOTE_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+fn tie_breaks(world: &mut ScenarioWorld, id: u64) -> Vec<proxy::TieBreak<StaticApi>> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .get_tie_breaks(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+fn tied_candidates(tie_break: &proxy::TieBreak<StaticApi>) -> Vec<u16> {
+    tie_break.candidates.iter().collect()
+}
+
+fn cast_tie_breaking_vote(world: &mut ScenarioWorld, id: u64, candidate: u16) {
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).cast_tie_breaking_vote(id, candidate).run();
+}
+
+fn cast_tie_breaking_vote_err(world: &mut ScenarioWorld, id: u64, candidate: u16, message: &str) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .cast_tie_breaking_vote(id, candidate)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+/// A Plurality election where the first two of three candidates are tied with one vote each.
+fn tied_plurality(world: &mut ScenarioWorld, policy: OptionalValue<u64>) -> (u64, Vec<u16>) {
+    let (id, c) = election_with_tie_break(world, PLURALITY, 3, policy);
+    register_voters(world, id, 2);
+    world.current_block().block_timestamp(START);
+    vote(world, id, 0, vec![c[0]]);
+    vote(world, id, 1, vec![c[1]]);
+    (id, c)
+}
+
+#[test]
+fn earliest_registration_is_the_default() {
+    let mut world = setup();
+    let (id, c) = tied_plurality(&mut world, OptionalValue::None);
+
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    let tie_breaks = tie_breaks(&mut world, id);
+    assert_eq!(tie_breaks.len(), 1);
+    assert_eq!(tied_candidates(&tie_breaks[0]), vec![c[0], c[1]]);
+    assert_eq!(tie_breaks[0].chosen, c[0]);
+    assert!(!tie_breaks[0].elimination);
+    assert!(!c.contains(&0));
+}
+
+#[test]
+fn random_draw_records_the_seed() {
+    let mut world = setup();
+    let (id, c) = tied_plurality(&mut world, OptionalValue::Some(RANDOM));
+
+    let winner = results(&mut world, id);
+    let tie_breaks = tie_breaks(&mut world, id);
+    assert_eq!(tie_breaks[0].policy, proxy::TieBreakPolicy::Random);
+    assert!(!tie_breaks[0].seed.is_empty());
+    assert!(tie_breaks[0].chosen == c[0] || tie_breaks[0].chosen == c[1]);
+    assert_eq!(winner, vec![tie_breaks[0].chosen]);
+}
+
+#[test]
+fn admin_casting_vote_decides_the_tie() {
+    let mut world = setup();
+    let (id, c) = tied_plurality(&mut world, OptionalValue::Some(ADMIN_CASTING_VOTE));
+
+    cast_tie_breaking_vote_err(&mut world, id, c[1], "No tie to break");
+    // no winner while the casting vote is pending
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+    assert_eq!(tie_breaks(&mut world, id)[0].deadline, END + CASTING_VOTE_PERIOD);
+
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .cast_tie_breaking_vote(id, c[1])
+        .returns(ExpectError(4, "Only admin can cast the tie-breaking vote"))
+        .run();
+    cast_tie_breaking_vote_err(&mut world, id, c[2], "Candidate is not tied");
+    cast_tie_breaking_vote(&mut world, id, c[1]);
+    cast_tie_breaking_vote_err(&mut world, id, c[0], "No tie to break");
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(tie_breaks(&mut world, id)[0].chosen, c[1]);
+}
+
+#[test]
+fn expired_casting_vote_falls_back_to_earliest_registration() {
+    let mut world = setup();
+    let (id, c) = tied_plurality(&mut world, OptionalValue::Some(ADMIN_CASTING_VOTE));
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+
+    world.current_block().block_timestamp(END + CASTING_VOTE_PERIOD + 1);
+    cast_tie_breaking_vote_err(&mut world, id, c[1], "Casting vote deadline has passed");
+    let winner: Vec<u16> = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .results(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(winner, vec![c[0]]);
+}
+
+/// An instant-runoff election where the second and third candidates are tied for elimination,
+/// and the votes of the eliminated one elect the other.
+fn tied_elimination(world: &mut ScenarioWorld, policy: OptionalValue<u64>) -> (u64, Vec<u16>) {
+    let (id, c) = election_with_tie_break(world, STV, 3, policy);
+    register_voters(world, id, 7);
+    world.current_block().block_timestamp(START);
+    for v in 0..3 {
+        vote(world, id, v, vec![c[0]]);
+    }
+    vote(world, id, 3, vec![c[1], c[2]]);
+    vote(world, id, 4, vec![c[1], c[2]]);
+    vote(world, id, 5, vec![c[2], c[1]]);
+    vote(world, id, 6, vec![c[2], c[1]]);
+    (id, c)
+}
+
+#[test]
+fn stv_elimination_tie_eliminates_the_last_registered_by_default() {
+    let mut world = setup();
+    let (id, c) = tied_elimination(&mut world, OptionalValue::None);
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    let tie_breaks = tie_breaks(&mut world, id);
+    assert_eq!(tie_breaks.len(), 1);
+    assert!(tie_breaks[0].elimination);
+    assert_eq!(tied_candidates(&tie_breaks[0]), vec![c[1], c[2]]);
+    assert_eq!(tie_breaks[0].chosen, c[2]);
+}
+
+#[test]
+fn stv_count_resumes_after_the_casting_vote() {
+    let mut world = setup();
+    let (id, c) = tied_elimination(&mut world, OptionalValue::Some(ADMIN_CASTING_VOTE));
+
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+    // the admin eliminates the candidate registered first
+    cast_tie_breaking_vote(&mut world, id, c[1]);
+    assert_eq!(results(&mut world, id), vec![c[2]]);
+}
+
+#[test]
+fn two_round_tie_for_the_second_place() {
+    let mut world = setup();
+    let (id, c) = election_with_tie_break(&mut world, TWO_ROUND, 3, OptionalValue::Some(ADMIN_CASTING_VOTE));
+    register_voters(&mut world, id, 7);
+    world.current_block().block_timestamp(START);
+    for (v, candidate) in [0usize, 0, 0, 1, 1, 2, 2].iter().enumerate() {
+        vote(&mut world, id, v, vec![c[*candidate]]);
+    }
+
+    // no second round is created while the casting vote is pending
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+    assert!(election_data(&mut world, id).next_round.is_none());
+    assert_eq!(tied_candidates(&tie_breaks(&mut world, id)[0]), vec![c[1], c[2]]);
+
+    cast_tie_breaking_vote(&mut world, id, c[2]);
+    assert_eq!(results(&mut world, id), vec![c[0], c[2]]);
+    assert!(election_data(&mut world, id).next_round.is_some());
+}
+
+#[test]
+fn star_runoff_tie_with_equal_totals() {
+    let mut world = setup();
+    let (id, c) = election_with_tie_break(&mut world, STAR, 2, OptionalValue::None);
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+    vote_rated(&mut world, id, 0, vec![(c[0], 5), (c[1], 0)]);
+    vote_rated(&mut world, id, 1, vec![(c[0], 0), (c[1], 5)]);
+
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    let tie_breaks = tie_breaks(&mut world, id);
+    assert_eq!(tie_breaks.len(), 1);
+    assert_eq!(tied_candidates(&tie_breaks[0]), vec![c[0], c[1]]);
+}
+
+#[test]
+fn majority_judgment_perfect_tie() {
+    let mut world = setup();
+    let (id, c) = election_with_tie_break(&mut world, MAJORITY_JUDGMENT, 3, OptionalValue::Some(ADMIN_CASTING_VOTE));
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+    vote_rated(&mut world, id, 0, vec![(c[0], 1), (c[1], 3), (c[2], 3)]);
+    vote_rated(&mut world, id, 1, vec![(c[0], 1), (c[1], 4), (c[2], 4)]);
+
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+    assert_eq!(tied_candidates(&tie_breaks(&mut world, id)[0]), vec![c[1], c[2]]);
+    cast_tie_breaking_vote(&mut world, id, c[2]);
+    assert_eq!(results(&mut world, id), vec![c[2]]);
+}
+
+#[test]
+fn party_list_tie_for_the_last_seat() {
+    let mut world = setup();
+    let (id, c) = election_with_tie_break(&mut world, PARTY_LIST, 2, OptionalValue::Some(ADMIN_CASTING_VOTE));
+    let mut parties = vec![];
+    for candidate in c.iter() {
+        let party: u16 = world
+            .tx()
+            .from(OWNER)
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .register_party(id, "party")
+            .returns(ReturnsResult)
+            .run();
+        world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).add_candidate_to_party(id, party, *candidate).run();
+        parties.push(party);
+    }
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+    vote(&mut world, id, 0, vec![parties[0]]);
+    vote(&mut world, id, 1, vec![parties[1]]);
+
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+    assert_eq!(tied_candidates(&tie_breaks(&mut world, id)[0]), parties);
+    cast_tie_breaking_vote(&mut world, id, parties[1]);
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+}
+
+#[test]
+fn ranked_pairs_cycle_of_equal_victories() {
+    let mut world = setup();
+    let (id, c) = election_with_tie_break(&mut world, RANKED_PAIRS, 3, OptionalValue::Some(ADMIN_CASTING_VOTE));
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+    // every victory has a margin of 1 with 2 votes: A > B, B > C and C > A
+    vote(&mut world, id, 0, vec![c[0], c[1], c[2]]);
+    vote(&mut world, id, 1, vec![c[1], c[2], c[0]]);
+    vote(&mut world, id, 2, vec![c[2], c[0], c[1]]);
+
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+    assert_eq!(tied_candidates(&tie_breaks(&mut world, id)[0]), c);
+    // C > A is locked first, A > B and B > C still form a cycle with it
+    cast_tie_breaking_vote(&mut world, id, c[2]);
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+    assert_eq!(tied_candidates(&tie_breaks(&mut world, id)[1]), vec![c[0], c[1]]);
+    cast_tie_breaking_vote(&mut world, id, c[0]);
+
+    assert_eq!(results(&mut world, id), vec![c[2], c[0], c[1]]);
+    assert_eq!(tie_breaks(&mut world, id).len(), 2);
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PLURALITY: u64 = 0;
+const APPROVAL: u64 = 1;
+const STV: u64 = 2;
+
+fn staked_vote(world: &mut ScenarioWorld, id: u64, v: usize, candidates: Vec<u16>, token: TestTokenIdentifier, amount: u64) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(candidates))
+        .single_esdt(&token.into(), 0, &BigUint::from(amount))
+        .run();
+}
+
+fn staked_vote_err(world: &mut ScenarioWorld, id: u64, v: usize, candidates: Vec<u16>, token: TestTokenIdentifier, amount: u64, message: &str) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(candidates))
+        .single_esdt(&token.into(), 0, &BigUint::from(amount))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn withdraw_stake_err(world: &mut ScenarioWorld, id: u64, v: usize, message: &str) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .withdraw_stake(id)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+#[test]
+fn token_weighted_votes_count_the_stake() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_voting_token(id, TOKEN).run();
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    // one large stake loses to two smaller ones
+    staked_vote(&mut world, id, 0, vec![c[0]], TOKEN, 300);
+    staked_vote(&mut world, id, 1, vec![c[1]], TOKEN, 200);
+    staked_vote(&mut world, id, 2, vec![c[1]], TOKEN, 150);
+    withdraw_stake_err(&mut world, id, 0, "Election has not ended yet");
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 300);
+    assert_eq!(result_vector(&mut world, id, c[1]), 350);
+
+    world.tx().from(voter(0)).to(SC).typed(proxy::BackendScProxy).withdraw_stake(id).run();
+    withdraw_stake_err(&mut world, id, 0, "No stake to withdraw");
+    world.check_account(voter(0)).esdt_balance(TOKEN, 1_000u64);
+    world.check_account(voter(1)).esdt_balance(TOKEN, 800u64);
+}
+
+#[test]
+fn token_weighted_votes_reject_invalid_stakes() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_voting_token(id, TOKEN).run();
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    staked_vote_err(&mut world, id, 0, vec![c[0]], OTHER, 100, "Invalid stake token");
+    vote_err(&mut world, id, 0, vec![c[0]], "The stake must be paid in a single transfer of the voting token");
+}
+
+#[test]
+fn unweighted_elections_reject_payments() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, APPROVAL, 2);
+    register_voters(&mut world, id, 1);
+    world.current_block().block_timestamp(START);
+
+    staked_vote_err(&mut world, id, 0, vec![c[0]], TOKEN, 1, "Election does not accept payments");
+}
+
+#[test]
+fn set_voting_token_validates_the_election() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, STV, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_voting_token(id, TOKEN)
+        .returns(ExpectError(4, "Election type does not support token-weighted votes"))
+        .run();
+
+    let (id, _) = election(&mut world, PLURALITY, 2);
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_voting_token(id, TOKEN)
+        .returns(ExpectError(4, "Only admin can set the voting token"))
+        .run();
+
+    world.current_block().block_timestamp(START);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_voting_token(id, TOKEN)
+        .returns(ExpectError(4, "Election has started"))
+        .run();
+}
@@ -0,0 +1,199 @@
+mod common;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const TWO_ROUND: u64 = 11;
+const NONE_OF_THE_ABOVE: u16 = u16::MAX;
+
+fn candidate_ids(world: &mut ScenarioWorld, id: u64) -> Vec<u16> {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .candidate_id_list(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn two_round_creates_a_runoff_without_a_majority() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, TWO_ROUND, 3);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_runoff_schedule(id, 2000u64, 3000u64).run();
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![c[0]]);
+    vote(&mut world, id, 1, vec![c[2]]);
+    vote_err(&mut world, id, 2, vec![c[0], c[1]], "Two-Round election can only have one candidate");
+
+    // no candidate has more than half of the votes, the two leading candidates go to the second round
+    assert_eq!(results(&mut world, id), vec![c[0], c[2]]);
+    let first_round = election_data(&mut world, id);
+    let runoff_id = first_round.next_round.unwrap();
+    let runoff = election_data(&mut world, runoff_id);
+    assert_eq!(runoff.previous_round, Some(id));
+    assert_eq!(runoff.election_type, proxy::ElectionType::Plurality);
+    assert_eq!((runoff.start_time, runoff.end_time), (2000, 3000));
+    assert_eq!(candidate_ids(&mut world, runoff_id), vec![c[0], c[2]]);
+
+    world.current_block().block_timestamp(2500);
+    vote_err(&mut world, runoff_id, 0, vec![c[1]], "Invalid candidate");
+    vote(&mut world, runoff_id, 2, vec![c[2]]);
+    world.current_block().block_timestamp(3000);
+    let runoff_ranking: Vec<u16> = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .results(runoff_id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(runoff_ranking, vec![c[2]]);
+}
+
+#[test]
+fn two_round_absolute_majority_wins_in_the_first_round() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, TWO_ROUND, 2);
+    register_voters(&mut world, id, 3);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![c[1]]);
+    vote(&mut world, id, 1, vec![c[1]]);
+    vote(&mut world, id, 2, vec![c[0]]);
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(election_data(&mut world, id).next_round, None);
+}
+
+#[test]
+fn two_round_runoff_keeps_the_settings_of_the_first_round() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, TWO_ROUND, 3);
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_none_of_the_above(id, true).run();
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_blank_ballots_allowed(id, true).run();
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).set_revoting_allowed(id, true).run();
+    register_voters(&mut world, id, 4);
+    world.current_block().block_timestamp(START);
+
+    vote(&mut world, id, 0, vec![c[0]]);
+    vote(&mut world, id, 1, vec![c[1]]);
+    vote(&mut world, id, 2, vec![c[0]]);
+    vote(&mut world, id, 3, vec![c[1]]);
+    vote(&mut world, id, 3, vec![c[2]]);
+
+    results(&mut world, id);
+    let runoff_id = election_data(&mut world, id).next_round.unwrap();
+    assert_eq!(candidate_ids(&mut world, runoff_id), vec![c[0], c[1], NONE_OF_THE_ABOVE]);
+    let blank_ballots_allowed: bool = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .blank_ballots_allowed(runoff_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(blank_ballots_allowed);
+    let revoting_allowed: bool = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .revoting_allowed(runoff_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(revoting_allowed);
+
+    // the runoff starts right away and lasts as long as the first round
+    let runoff = election_data(&mut world, runoff_id);
+    assert_eq!((runoff.start_time, runoff.end_time), (END, END + END - START));
+    vote(&mut world, runoff_id, 0, vec![]);
+    vote(&mut world, runoff_id, 1, vec![c[1]]);
+    vote(&mut world, runoff_id, 1, vec![c[0]]);
+}
+
+#[test]
+fn two_round_without_candidates_has_no_winner() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, TWO_ROUND, 0);
+    assert!(results(&mut world, id).is_empty());
+    assert_eq!(election_data(&mut world, id).next_round, None);
+}
+
+#[test]
+fn two_round_runoff_schedule_is_validated() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, TWO_ROUND, 2);
+    let (plurality, _) = election(&mut world, 0, 2);
+    let set_schedule_err = |world: &mut ScenarioWorld, from: TestAddress, id: u64, start: u64, end: u64, message: &str| {
+        world
+            .tx()
+            .from(from)
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .set_runoff_schedule(id, start, end)
+            .returns(ExpectError(4, message))
+            .run();
+    };
+    set_schedule_err(&mut world, voter(0), id, 2000, 3000, "Only admin can set the runoff schedule");
+    set_schedule_err(&mut world, OWNER, plurality, 2000, 3000, "Election type does not have a runoff");
+    set_schedule_err(&mut world, OWNER, id, END - 1, 3000, "Runoff cannot start before the first round ends");
+    set_schedule_err(&mut world, OWNER, id, 3000, 3000, "Runoff must start before it ends");
+    results(&mut world, id);
+    set_schedule_err(&mut world, OWNER, id, 2000, 3000, "Election has been finalised");
+}
+
+#[test]
+fn only_the_admin_can_end_an_election_early() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, TWO_ROUND, 2);
+    register_voters(&mut world, id, 2);
+    world.current_block().block_timestamp(START);
+    vote(&mut world, id, 0, vec![c[0]]);
+
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .end_election(id)
+        .returns(ExpectError(4, "Only admin can end election"))
+        .run();
+    results_err(&mut world, id, "Election has not ended yet");
+
+    world.tx().from(OWNER).to(SC).typed(proxy::BackendScProxy).end_election(id).run();
+    vote_err(&mut world, id, 1, vec![c[1]], "Election has already ended");
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .end_election(id)
+        .returns(ExpectError(4, "Election has already ended"))
+        .run();
+    let ranking: Vec<u16> = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .results(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(ranking, vec![c[0]]);
+}
+
+#[test]
+fn two_round_first_round_must_end_after_it_starts() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .register_election("name", "description", TWO_ROUND, END, START, OptionalValue::<u64>::None)
+        .returns(ExpectError(4, "Election must end after it starts"))
+        .run();
+}
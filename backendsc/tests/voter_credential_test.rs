@@ -0,0 +1,213 @@
+mod common;
+
+use backendsc::BackendSc;
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PLURALITY: u64 = 0;
+const CREDENTIAL: TestTokenIdentifier = TestTokenIdentifier::new("VCRED-123456");
+
+/// Sets the voter credential token, as the issue callback does, and gives the contract its roles.
+fn set_voter_credential(world: &mut ScenarioWorld) {
+    world.tx().from(OWNER).to(SC).whitebox(backendsc::contract_obj, |sc| {
+        sc.voter_credential().set_token_id(TokenIdentifier::from(CREDENTIAL.as_str()));
+    });
+    world.set_esdt_local_roles(SC, CREDENTIAL.as_bytes(), &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn, EsdtLocalRole::Transfer]);
+}
+
+fn set_credential_required(world: &mut ScenarioWorld, id: u64, required: bool) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_credential_required(id, required)
+        .run();
+}
+
+fn set_credential_required_err(world: &mut ScenarioWorld, from: TestAddress, id: u64, message: &str) {
+    world
+        .tx()
+        .from(from)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_credential_required(id, true)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+/// A plurality election requiring voter credentials, with `n` registered voters, at the start of voting.
+/// The credential of voter `i` has nonce `i + 1`.
+fn credential_election(world: &mut ScenarioWorld, n: usize, revoting: bool) -> (u64, Vec<u16>) {
+    let (id, c) = election(world, PLURALITY, 2);
+    if revoting {
+        world
+            .tx()
+            .from(OWNER)
+            .to(SC)
+            .typed(proxy::BackendScProxy)
+            .set_revoting_allowed(id, true)
+            .run();
+    }
+    set_credential_required(world, id, true);
+    register_voters(world, id, n);
+    world.current_block().block_timestamp(START);
+    (id, c)
+}
+
+fn vote_with_credential(world: &mut ScenarioWorld, id: u64, v: usize, nonce: u64, candidates: Vec<u16>) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(candidates))
+        .single_esdt(&CREDENTIAL.into(), nonce, &BigUint::from(1u64))
+        .run();
+}
+
+fn revoke_voter_credential(world: &mut ScenarioWorld, id: u64, v: usize) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .revoke_voter_credential(id, voter(v).to_managed_address())
+        .run();
+}
+
+fn revoke_voter_credential_err(world: &mut ScenarioWorld, from: TestAddress, id: u64, v: usize, message: &str) {
+    world
+        .tx()
+        .from(from)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .revoke_voter_credential(id, voter(v).to_managed_address())
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+#[test]
+fn credentials_are_minted_on_registration_and_burned_by_votes() {
+    let mut world = setup();
+    set_voter_credential(&mut world);
+    let (id, c) = credential_election(&mut world, 2, false);
+
+    world
+        .check_account(voter(0))
+        .esdt_nft_balance_and_attributes(CREDENTIAL, 1, 1, (id, voter(0).to_address()));
+    world
+        .check_account(voter(1))
+        .esdt_nft_balance_and_attributes(CREDENTIAL, 2, 1, (id, voter(1).to_address()));
+
+    vote_with_credential(&mut world, id, 0, 1, vec![c[0]]);
+    world
+        .check_account(voter(0))
+        .esdt_nft_balance_and_attributes(CREDENTIAL, 1, 0, (id, voter(0).to_address()));
+
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 1);
+}
+
+#[test]
+fn credentials_are_returned_with_revoting() {
+    let mut world = setup();
+    set_voter_credential(&mut world);
+    let (id, c) = credential_election(&mut world, 1, true);
+
+    vote_with_credential(&mut world, id, 0, 1, vec![c[0]]);
+    vote_with_credential(&mut world, id, 0, 1, vec![c[1]]);
+    world
+        .check_account(voter(0))
+        .esdt_nft_balance_and_attributes(CREDENTIAL, 1, 1, (id, voter(0).to_address()));
+
+    assert_eq!(results(&mut world, id), vec![c[1]]);
+    assert_eq!(result_vector(&mut world, id, c[1]), 1);
+}
+
+#[test]
+fn revoked_voters_cannot_vote() {
+    let mut world = setup();
+    set_voter_credential(&mut world);
+    let (id, c) = credential_election(&mut world, 3, false);
+    vote_with_credential(&mut world, id, 0, 1, vec![c[0]]);
+    revoke_voter_credential(&mut world, id, 0);
+    revoke_voter_credential(&mut world, id, 1);
+    revoke_voter_credential_err(&mut world, OWNER, id, 1, "Voter credential already revoked");
+    revoke_voter_credential_err(&mut world, voter(2), id, 2, "Only admin can revoke voter credentials");
+    revoke_voter_credential_err(&mut world, OWNER, id, 3, "Voter is not registered");
+
+    world
+        .tx()
+        .from(voter(1))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(vec![c[1]]))
+        .single_esdt(&CREDENTIAL.into(), 2, &BigUint::from(1u64))
+        .returns(ExpectError(4, "You are not eligible to vote"))
+        .run();
+    let revoked_voters = world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .revoked_voters(id)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(revoked_voters.into_vec(), vec![voter(0).to_address(), voter(1).to_address()]);
+
+    // a ballot cast before the revocation stays counted
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 1);
+}
+
+#[test]
+fn votes_without_their_credential_are_rejected() {
+    let mut world = setup();
+    set_voter_credential(&mut world);
+    let (id, c) = credential_election(&mut world, 2, false);
+
+    vote_err(&mut world, id, 0, vec![c[0]], "Voter credential required");
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(vec![c[0]]))
+        .single_esdt(&TOKEN.into(), 0, &BigUint::from(1u64))
+        .returns(ExpectError(4, "Invalid voter credential"))
+        .run();
+    // only the contract can transfer credentials on chain, but the VM does not enforce the transfer role,
+    // so check that a credential obtained from another voter is rejected anyway
+    world.transfer_step(
+        TransferStep::new()
+            .from(voter(1))
+            .to(voter(0))
+            .esdt_transfer(CREDENTIAL.as_bytes(), 2, 1u64),
+    );
+    world
+        .tx()
+        .from(voter(0))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote(id, MultiValueVec::from(vec![c[0]]))
+        .single_esdt(&CREDENTIAL.into(), 2, &BigUint::from(1u64))
+        .returns(ExpectError(4, "Invalid voter credential"))
+        .run();
+}
+
+#[test]
+fn credentials_are_rejected_with_invalid_settings() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, PLURALITY, 2);
+    set_credential_required_err(&mut world, OWNER, id, "Voter credential token not issued");
+    set_voter_credential(&mut world);
+    set_credential_required_err(&mut world, voter(0), id, "Only admin can require voter credentials");
+    revoke_voter_credential_err(&mut world, OWNER, id, 0, "Election does not use voter credentials");
+
+    register_voters(&mut world, id, 1);
+    set_credential_required_err(&mut world, OWNER, id, "Voters are already registered");
+
+    let (id, _) = election(&mut world, PLURALITY, 2);
+    world.current_block().block_timestamp(START);
+    set_credential_required_err(&mut world, OWNER, id, "Election has started");
+}
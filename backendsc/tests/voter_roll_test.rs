@@ -0,0 +1,187 @@
+mod common;
+
+#[allow(dead_code)]
+#[path = "../interactor/src/voter_roll.rs"]
+mod voter_roll;
+
+use common::*;
+use multiversx_sc_scenario::imports::*;
+
+const PLURALITY: u64 = 0;
+const REFERENDUM: u64 = 13;
+const YES: u16 = 1;
+
+/// A voter roll of the first `n` voters, voter `i` having weight `i + 1`.
+fn roll(n: usize) -> voter_roll::VoterRollTree {
+    let csv: String = (0..n).map(|i| format!("{},{}\n", bech32::encode(&voter(i).to_address()), i + 1)).collect();
+    voter_roll::VoterRollTree::new(voter_roll::parse_csv(&csv).unwrap())
+}
+
+fn proof(tree: &voter_roll::VoterRollTree, v: usize) -> ManagedBuffer<StaticApi> {
+    ManagedBuffer::new_from_bytes(&tree.proof(&voter(v).to_address()).unwrap())
+}
+
+fn set_voter_roll(world: &mut ScenarioWorld, id: u64, tree: &voter_roll::VoterRollTree, weighted: bool) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_voter_roll(id, ManagedByteArray::<StaticApi, 32>::new_from_bytes(&tree.root()), tree.voters.len() as u64, weighted)
+        .run();
+}
+
+fn set_voter_roll_err(world: &mut ScenarioWorld, from: TestAddress, id: u64, voters: u64, weighted: bool, message: &str) {
+    world
+        .tx()
+        .from(from)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_voter_roll(id, ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[0u8; 32]), voters, weighted)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn register_self(world: &mut ScenarioWorld, id: u64, v: usize, verification_data: ManagedBuffer<StaticApi>) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .register_self(id, verification_data)
+        .run();
+}
+
+fn register_self_err(world: &mut ScenarioWorld, id: u64, v: usize, verification_data: ManagedBuffer<StaticApi>, message: &str) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .register_self(id, verification_data)
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn vote_with_proof(world: &mut ScenarioWorld, id: u64, v: usize, verification_data: ManagedBuffer<StaticApi>, candidates: Vec<u16>) {
+    world
+        .tx()
+        .from(voter(v))
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .vote_with_proof(id, verification_data, MultiValueVec::from(candidates))
+        .run();
+}
+
+fn referendum_result(world: &mut ScenarioWorld, id: u64) -> proxy::ReferendumResult {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .referendum_result(id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+#[test]
+fn voters_register_from_a_weighted_roll() {
+    let mut world = setup();
+    let (id, c) = election(&mut world, PLURALITY, 2);
+    let tree = roll(5);
+    set_voter_roll(&mut world, id, &tree, true);
+    register_self(&mut world, id, 0, proof(&tree, 0));
+
+    // voters of a roll can still register once the election has started
+    world.current_block().block_timestamp(START);
+    register_self(&mut world, id, 3, proof(&tree, 3));
+    vote(&mut world, id, 0, vec![c[0]]);
+    vote(&mut world, id, 3, vec![c[0]]);
+    vote_with_proof(&mut world, id, 4, proof(&tree, 4), vec![c[1]]);
+    vote_with_proof(&mut world, id, 1, proof(&tree, 1), vec![c[0]]);
+
+    world.current_block().block_timestamp(END + 1);
+    // 1 + 4 + 2 = 7 against 5
+    assert_eq!(results(&mut world, id), vec![c[0]]);
+    assert_eq!(result_vector(&mut world, id, c[0]), 7);
+    assert_eq!(result_vector(&mut world, id, c[1]), 5);
+}
+
+#[test]
+fn invalid_voter_rolls_and_proofs_are_rejected() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, PLURALITY, 2);
+    set_voter_roll_err(&mut world, voter(0), id, 5, false, "Only admin can set the voter roll");
+    set_voter_roll_err(&mut world, OWNER, id, 0, false, "Voter roll cannot be empty");
+    let (referendum_id, _) = election(&mut world, REFERENDUM, 0);
+    set_voter_roll_err(&mut world, OWNER, referendum_id, 5, true, "Election type does not support weighted votes");
+
+    let tree = roll(5);
+    set_voter_roll(&mut world, id, &tree, false);
+    register_self(&mut world, id, 0, proof(&tree, 0));
+    register_self_err(&mut world, id, 0, proof(&tree, 0), "Already registered");
+    register_self_err(&mut world, id, 1, proof(&tree, 0), "Invalid voter roll proof");
+
+    let mut tampered = tree.proof(&voter(2).to_address()).unwrap();
+    tampered[7] = 9;
+    register_self_err(&mut world, id, 2, ManagedBuffer::new_from_bytes(&tampered), "Invalid voter roll proof");
+    register_self_err(&mut world, id, 2, ManagedBuffer::new_from_bytes(&tampered[..9]), "Invalid verification data");
+    register_self_err(&mut world, id, 5, proof(&tree, 4), "Invalid voter roll proof");
+
+    world.current_block().block_timestamp(START);
+    set_voter_roll_err(&mut world, OWNER, id, 5, false, "Election has started");
+}
+
+#[test]
+fn referendum_quorum_counts_the_whole_roll() {
+    let mut world = setup();
+    let (id, _) = election(&mut world, REFERENDUM, 0);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .set_referendum_rules(id, 50u64, 1u64, 2u64)
+        .run();
+    let tree = roll(6);
+    set_voter_roll(&mut world, id, &tree, false);
+    // a voter registered outside the roll counts as well
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(proxy::BackendScProxy)
+        .register_voter(id, voter(10).to_managed_address())
+        .run();
+
+    world.current_block().block_timestamp(START);
+    vote_with_proof(&mut world, id, 0, proof(&tree, 0), vec![YES]);
+    vote_with_proof(&mut world, id, 1, proof(&tree, 1), vec![YES]);
+    vote(&mut world, id, 10, vec![YES]);
+
+    // 3 votes out of an electorate of 7: only 3 voters registered, but the quorum is not met
+    world.current_block().block_timestamp(END + 1);
+    assert_eq!(results(&mut world, id), Vec::<u16>::new());
+    let result = referendum_result(&mut world, id);
+    assert_eq!((result.yes_votes, result.registered_voters), (3, 7));
+    assert_eq!(result.outcome, proxy::ReferendumOutcome::QuorumNotMet);
+}
+
+#[test]
+fn voter_roll_csv_is_validated() {
+    let address = bech32::encode(&voter(0).to_address());
+    let tree = roll(5);
+    assert_eq!(tree.voters.len(), 5);
+    for v in 0..5 {
+        let proof = tree.proof(&voter(v).to_address()).unwrap();
+        assert!(voter_roll::verify(&tree.root(), &voter(v).to_address(), &proof));
+    }
+    assert!(tree.proof(&voter(5).to_address()).is_none());
+
+    // invalid addresses are errors, not panics
+    assert_eq!(voter_roll::parse_csv("# voters\n\nabc,1").unwrap_err(), "line 3: invalid address abc: parsing failed");
+    assert!(voter_roll::parse_csv("erd1qqqqqqqq").unwrap_err().starts_with("line 1: invalid address erd1qqqqqqqq"));
+    assert!(voter_roll::parse_csv(&format!("{}x", &address[..address.len() - 1])).unwrap_err().starts_with("line 1: invalid address"));
+    assert!(voter_roll::parse_csv(&format!("{address},x")).unwrap_err().starts_with("line 1: invalid weight"));
+    assert_eq!(voter_roll::parse_csv(&format!("{address}\n{address},2")).unwrap_err(), "line 2: duplicate address");
+    assert_eq!(voter_roll::parse_csv("# no voters\n").unwrap_err(), "the voter roll is empty");
+}
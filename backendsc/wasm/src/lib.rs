@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           24
-// Async Callback (empty):               1
-// Total number of exported functions:  27
+// Endpoints:                          121
+// Async Callback:                       1
+// Total number of exported functions: 124
 
 #![no_std]
 
@@ -25,26 +25,123 @@ multiversx_sc_wasm_adapter::endpoints! {
         getElectionIDList => election_id_list
         getElectionData => election_data
         getRegisteredVoters => registered_voters
+        getVoterCredentialToken => voter_credential
+        getCredentialRequired => credential_required
+        getRevokedVoters => revoked_voters
+        getVoter => get_voter
+        isRollLeafUsed => roll_leaf_used
+        getRollRegistrations => roll_registrations
+        getVoterWeight => voter_weight
+        getVoterRoll => get_voter_roll
         getPotentialCandidateIDs => potential_candidate_id_list
         getCandidateIDs => candidate_id_list
         getCandidate => candidate
-        getVotes => votes
+        getVotes => get_votes
+        getBallotCount => get_ballot_count
+        getBallots => get_ballots
+        getBallot => get_ballot
+        verifyReceipt => verify_receipt
+        getBlankBallotsAllowed => blank_ballots_allowed
+        getBlankBallots => blank_ballots
+        getRevotingAllowed => revoting_allowed
+        getDelegationAllowed => delegation_allowed
+        getDelegators => delegators
+        getDelegationChain => get_delegation_chain
+        getEffectiveWeight => get_effective_weight
+        getSupersededBallots => superseded_ballots
+        getRevealPeriod => reveal_period
+        getCommitment => commitment
+        getUnrevealedVoters => unrevealed_voters
+        getTrustees => trustees
+        getTrusteeThreshold => trustee_threshold
+        getTrusteeKey => trustee_key
+        getCoefficientCommitments => coefficient_commitments
+        getEncryptedBallots => encrypted_ballots
+        getDecryptionShare => decryption_share
+        getDecryptingTrustees => decrypting_trustees
+        getTallyPublished => tally_published
+        getEncryptedTally => get_encrypted_tally
+        getJointPublicKey => joint_public_key
+        getTrusteeVerificationKey => trustee_verification_key
+        getBallotRules => ballot_rules
+        getVotingToken => voting_token
+        getStake => stake
+        getRatingScale => rating_scale
+        getPointBudget => point_budget
+        getVoiceCreditBudget => voice_credit_budget
+        getVoiceCreditsSpent => voice_credits_spent
+        getNetVotes => net_votes
+        getGrades => grades
+        getRunoffSchedule => runoff_schedule
+        getPartyIDs => party_id_list
+        getParty => party
+        getPartyList => party_list
+        getCandidateParty => candidate_party
+        getPartyListRules => party_list_rules
+        getReferendumRules => referendum_rules
         getDisputeIDList => dispute_id_list
         getDispute => dispute
+        getPairwiseMatrix => get_pairwise_matrix
         result_vector => result_vector
+        result_average => result_average
+        getStarResult => star_result
+        getRanking => ranking
+        getGradeResults => grade_results
+        getPartyResults => party_results
+        getReferendumResult => referendum_result
+        getTieBreaks => get_tie_breaks
+        getStvRounds => stv_rounds
+        getLockedPairs => locked_pairs
         results => results
+        castTieBreakingVote => cast_tie_breaking_vote
         electionList => election_list
         registerElection => register_election
+        setRatingScale => set_rating_scale
+        setPointBudget => set_point_budget
+        setVoiceCreditBudget => set_voice_credit_budget
+        setGrades => set_grades
+        setRunoffSchedule => set_runoff_schedule
+        setBallotRules => set_ballot_rules
+        setVotingToken => set_voting_token
+        setReferendumRules => set_referendum_rules
+        setNoneOfTheAbove => set_none_of_the_above
+        setRevealPeriod => set_reveal_period
+        setTrustees => set_trustees
+        registerTrusteeKey => register_trustee_key
+        setVoterRoll => set_voter_roll
+        setBlankBallotsAllowed => set_blank_ballots_allowed
+        setRevotingAllowed => set_revoting_allowed
+        issueVoterCredential => issue_voter_credential
+        setVoterCredentialRoles => set_voter_credential_roles
+        setCredentialRequired => set_credential_required
+        revokeVoterCredential => revoke_voter_credential
+        setDelegationAllowed => set_delegation_allowed
+        rerunElection => rerun_election
+        setPartyListRules => set_party_list_rules
+        registerParty => register_party
+        addCandidateToParty => add_candidate_to_party
+        setSeats => set_seats
         submitCandidancy => submit_candidancy
         registerCandidate => register_candidate
         registerSelf => register_self
         registerVoter => register_voter
         vote => vote
+        voteWithProof => vote_with_proof
+        voteRated => vote_rated
+        delegateVote => delegate_vote
+        revokeDelegation => revoke_delegation
+        commitVote => commit_vote
+        revealVote => reveal_vote
+        revealVoteRated => reveal_vote_rated
+        voteEncrypted => vote_encrypted
+        postDecryptionShares => post_decryption_shares
+        publishTally => publish_tally
         endElection => end_election
+        withdrawStake => withdraw_stake
         makeDispute => make_dispute
         resolveDispute => resolve_dispute
         getCurrentBlockTimestamp => get_block_timestamp
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { backendsc }
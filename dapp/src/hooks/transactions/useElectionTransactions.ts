@@ -369,7 +369,9 @@ export const useSendElectionTransaction = ({
       let queryResponse = await proxyNetworkProvider.queryContract(electionResults);
       let electionResultsRes = new ResultsParser().parseQueryResponse(queryResponse, smartContract.getEndpoint('results'));
 
-      const winnerCandidateId = electionResultsRes.values[0].valueOf().toNumber();
+      // `results` returns the ordering of the candidates, the winner comes first
+      const ranking = electionResultsRes.values.map((value: any) => value.items.map((item: any) => item.value.toNumber())).flat();
+      const winnerCandidateId = ranking[0];
       const candidate = await getCandidate({ electionId, candidateId: winnerCandidateId });
       return candidate;
     }, []
@@ -432,7 +434,7 @@ export const useSendElectionTransaction = ({
   );
 
   const registerElection = useCallback(
-    async ({ name, description, election_type, start_time, end_time }: any) => {
+    async ({ name, description, election_type, start_time, end_time, tie_break_policy }: any) => {
       clearAllTransactions();
 
       const electionDetails: TypedValue[] = [
@@ -442,6 +444,10 @@ export const useSendElectionTransaction = ({
         new BigUIntValue(start_time / 1000),
         new BigUIntValue(end_time / 1000)
       ];
+      // the tie-break policy is optional, the contract defaults to EarliestRegistration
+      if (tie_break_policy !== undefined) {
+        electionDetails.push(new BigUIntValue(tie_break_policy));
+      }
 
       const registerElection = smartContract.methodsExplicit
         .registerElection(electionDetails)
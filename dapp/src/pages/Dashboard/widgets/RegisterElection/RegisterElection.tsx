@@ -12,6 +12,7 @@ export const RegisterElection = ({ callbackRoute }: WidgetProps) => {
   const [startTime, setStartTime] = useState<string>('');
   const [endTime, setEndTime] = useState<string>('');
   const [electionType, setElectionType] = useState<number>(1);
+  const [tieBreakPolicy, setTieBreakPolicy] = useState<number>(0);
   const [response, setResponse] = useState<any>(null);
 
   const {
@@ -29,6 +30,7 @@ export const RegisterElection = ({ callbackRoute }: WidgetProps) => {
         election_type: electionType,
         start_time: new Date(startTime).getTime(),
         end_time: new Date(endTime).getTime(),
+        tie_break_policy: tieBreakPolicy,
       });
 
       setResponse(res);
@@ -101,6 +103,19 @@ export const RegisterElection = ({ callbackRoute }: WidgetProps) => {
             <option value='2'>Single Transferable Vote</option>
           </select>
         </div>
+        <div className='flex flex-col gap-2'>
+          <Label>Tie-Break Policy</Label>
+          <select
+            value={tieBreakPolicy}
+            onChange={(e) => setTieBreakPolicy(Number(e.target.value))}
+            className='input border border-gray-300 rounded-md p-2 focus:outline-none focus:ring-2 focus:ring-blue-500'
+            required
+          >
+            <option value='0'>Earliest Registration</option>
+            <option value='1'>Random</option>
+            <option value='2'>Admin Casting Vote</option>
+          </select>
+        </div>
         <div className='flex flex-row gap-2'>
           <Button type='button' onClick={() => setPredefinedPeriod(3)} className='bg-gray-500 text-white p-2 rounded-md hover:bg-gray-600'>
             1 Day